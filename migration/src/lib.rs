@@ -3,6 +3,18 @@ pub use sea_orm_migration::prelude::*;
 // Liste des migrations (comme le dossier migrations/ en Doctrine)
 mod m20241210_000001_create_users_table;
 mod m20241210_000002_create_posts_table;
+mod m20241211_000003_add_password_hash_to_users;
+mod m20241212_000004_add_role_to_users;
+mod m20241213_000005_create_api_keys_table;
+mod m20241214_000006_create_refresh_tokens_table;
+mod m20241215_000007_create_comments_table;
+mod m20241216_000008_create_tags_tables;
+mod m20241216_000009_backfill_post_tags;
+mod m20241217_000010_create_posts_search_index;
+mod m20241218_000011_add_slug_to_posts;
+mod m20241219_000012_add_status_to_posts;
+mod m20241220_000013_create_post_revisions_table;
+mod m20241221_000014_add_version_to_posts_and_users;
 
 pub struct Migrator;
 
@@ -13,6 +25,18 @@ impl MigratorTrait for Migrator {
         vec![
             Box::new(m20241210_000001_create_users_table::Migration),
             Box::new(m20241210_000002_create_posts_table::Migration),
+            Box::new(m20241211_000003_add_password_hash_to_users::Migration),
+            Box::new(m20241212_000004_add_role_to_users::Migration),
+            Box::new(m20241213_000005_create_api_keys_table::Migration),
+            Box::new(m20241214_000006_create_refresh_tokens_table::Migration),
+            Box::new(m20241215_000007_create_comments_table::Migration),
+            Box::new(m20241216_000008_create_tags_tables::Migration),
+            Box::new(m20241216_000009_backfill_post_tags::Migration),
+            Box::new(m20241217_000010_create_posts_search_index::Migration),
+            Box::new(m20241218_000011_add_slug_to_posts::Migration),
+            Box::new(m20241219_000012_add_status_to_posts::Migration),
+            Box::new(m20241220_000013_create_post_revisions_table::Migration),
+            Box::new(m20241221_000014_add_version_to_posts_and_users::Migration),
         ]
     }
 }
@@ -0,0 +1,65 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+/// Migration: Create api_keys table (clés d'API des clients machine)
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ApiKeys::Table)
+                    .if_not_exists()
+                    .col(pk_auto(ApiKeys::Id))
+                    .col(integer(ApiKeys::UserId))
+                    .col(string(ApiKeys::Name))
+                    // Début de la clé en clair, pour la reconnaître dans la liste
+                    .col(string_len(ApiKeys::Prefix, 16))
+                    // SHA-256 de la clé complète (la clé elle-même n'est jamais stockée)
+                    .col(string_len_uniq(ApiKeys::KeyHash, 64))
+                    // Liste JSON des scopes ("posts:read", "posts:write")
+                    .col(json(ApiKeys::Scopes))
+                    .col(timestamp_null(ApiKeys::LastUsedAt))
+                    .col(timestamp_null(ApiKeys::RevokedAt))
+                    .col(timestamp(ApiKeys::CreatedAt))
+                    // Foreign key vers users
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_api_keys_user")
+                            .from(ApiKeys::Table, ApiKeys::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ApiKeys::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ApiKeys {
+    Table,
+    Id,
+    UserId,
+    Name,
+    Prefix,
+    KeyHash,
+    Scopes,
+    LastUsedAt,
+    RevokedAt,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
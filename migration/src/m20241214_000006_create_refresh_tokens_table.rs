@@ -0,0 +1,74 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+/// Migration: Create refresh_tokens table (sessions, rotation par famille)
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RefreshTokens::Table)
+                    .if_not_exists()
+                    .col(pk_auto(RefreshTokens::Id))
+                    .col(integer(RefreshTokens::UserId))
+                    // Tous les tokens issus d'un même login partagent la même famille
+                    .col(string_len(RefreshTokens::FamilyId, 64))
+                    // SHA-256 du token (le token lui-même n'est jamais stocké)
+                    .col(string_len_uniq(RefreshTokens::TokenHash, 64))
+                    .col(timestamp(RefreshTokens::ExpiresAt))
+                    // Renseigné quand le token a été échangé contre un nouveau
+                    .col(timestamp_null(RefreshTokens::RotatedAt))
+                    .col(timestamp_null(RefreshTokens::RevokedAt))
+                    .col(timestamp(RefreshTokens::CreatedAt))
+                    // Foreign key vers users
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_refresh_tokens_user")
+                            .from(RefreshTokens::Table, RefreshTokens::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Révocation d'une famille entière (détection de réutilisation)
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_refresh_tokens_family_id")
+                    .table(RefreshTokens::Table)
+                    .col(RefreshTokens::FamilyId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RefreshTokens::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum RefreshTokens {
+    Table,
+    Id,
+    UserId,
+    FamilyId,
+    TokenHash,
+    ExpiresAt,
+    RotatedAt,
+    RevokedAt,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,90 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+/// Migration: Create comments table (threading via parent_id)
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Comments::Table)
+                    .if_not_exists()
+                    .col(pk_auto(Comments::Id))
+                    .col(integer(Comments::PostId))
+                    .col(integer(Comments::AuthorId))
+                    // Commentaire parent (réponse), NULL pour un commentaire racine
+                    .col(integer_null(Comments::ParentId))
+                    .col(text(Comments::Body))
+                    .col(timestamp(Comments::CreatedAt))
+                    .col(timestamp_null(Comments::UpdatedAt))
+                    // Foreign keys vers posts, users et comments (les réponses suivent leur parent)
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comments_post")
+                            .from(Comments::Table, Comments::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comments_author")
+                            .from(Comments::Table, Comments::AuthorId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comments_parent")
+                            .from(Comments::Table, Comments::ParentId)
+                            .to(Comments::Table, Comments::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Liste et comptage des commentaires d'un post
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_comments_post_id")
+                    .table(Comments::Table)
+                    .col(Comments::PostId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Comments::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Comments {
+    Table,
+    Id,
+    PostId,
+    AuthorId,
+    ParentId,
+    Body,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Posts {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,91 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+/// Migration: Create tags and post_tags tables (tags normalisés, jusqu'ici dans posts.metadata)
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Tags::Table)
+                    .if_not_exists()
+                    .col(pk_auto(Tags::Id))
+                    .col(string_len_uniq(Tags::Name, 50))
+                    .col(string_len_null(Tags::Color, 7))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(PostTags::Table)
+                    .if_not_exists()
+                    .col(integer(PostTags::PostId))
+                    .col(integer(PostTags::TagId))
+                    .primary_key(Index::create().col(PostTags::PostId).col(PostTags::TagId))
+                    // Les liens disparaissent avec le post ou le tag
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_post_tags_post")
+                            .from(PostTags::Table, PostTags::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_post_tags_tag")
+                            .from(PostTags::Table, PostTags::TagId)
+                            .to(Tags::Table, Tags::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Posts d'un tag (la clé primaire couvre déjà post_id)
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_post_tags_tag_id")
+                    .table(PostTags::Table)
+                    .col(PostTags::TagId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PostTags::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Tags::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Tags {
+    Table,
+    Id,
+    Name,
+    Color,
+}
+
+#[derive(DeriveIden)]
+enum PostTags {
+    Table,
+    PostId,
+    TagId,
+}
+
+#[derive(DeriveIden)]
+enum Posts {
+    Table,
+    Id,
+}
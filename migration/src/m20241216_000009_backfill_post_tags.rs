@@ -0,0 +1,42 @@
+use sea_orm_migration::prelude::*;
+
+/// Migration: Remplit tags / post_tags à partir des tags du JSON posts.metadata
+/// La première couleur rencontrée pour un tag est conservée
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            "INSERT OR IGNORE INTO tags (name, color)
+             SELECT json_extract(tag.value, '$.name'), json_extract(tag.value, '$.color')
+             FROM posts, json_each(posts.metadata, '$.tags') AS tag
+             WHERE json_extract(tag.value, '$.name') IS NOT NULL
+             ORDER BY posts.id",
+        )
+        .await?;
+
+        db.execute_unprepared(
+            "INSERT OR IGNORE INTO post_tags (post_id, tag_id)
+             SELECT posts.id, tags.id
+             FROM posts, json_each(posts.metadata, '$.tags') AS tag
+             JOIN tags ON tags.name = json_extract(tag.value, '$.name')",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Les tags restent dans posts.metadata : vider les tables suffit
+        let db = manager.get_connection();
+
+        db.execute_unprepared("DELETE FROM post_tags").await?;
+        db.execute_unprepared("DELETE FROM tags").await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use sea_orm_migration::prelude::*;
+
+/// Migration: Index plein texte FTS5 des posts (titre, contenu, tags, mots-clés SEO)
+/// Maintenu par des triggers sur posts ; rowid = posts.id
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Colonnes indexées calculées depuis une ligne de posts (`row` = new ou posts)
+fn indexed_values(row: &str) -> String {
+    format!(
+        "{row}.id, {row}.title, {row}.content, \
+         (SELECT group_concat(json_extract(tag.value, '$.name'), ' ') \
+          FROM json_each({row}.metadata, '$.tags') AS tag), \
+         (SELECT group_concat(keyword.value, ' ') \
+          FROM json_each({row}.metadata, '$.seo.keywords') AS keyword)"
+    )
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        // remove_diacritics: "generique" trouve "générique"
+        db.execute_unprepared(
+            "CREATE VIRTUAL TABLE IF NOT EXISTS posts_fts USING fts5(
+                title, content, tags, keywords,
+                tokenize = 'unicode61 remove_diacritics 2'
+            )",
+        )
+        .await?;
+
+        let insert = "INSERT INTO posts_fts (rowid, title, content, tags, keywords)";
+
+        db.execute_unprepared(&format!(
+            "CREATE TRIGGER IF NOT EXISTS posts_fts_after_insert AFTER INSERT ON posts BEGIN
+                {insert} SELECT {values};
+            END",
+            values = indexed_values("new"),
+        ))
+        .await?;
+
+        db.execute_unprepared(&format!(
+            "CREATE TRIGGER IF NOT EXISTS posts_fts_after_update AFTER UPDATE ON posts BEGIN
+                DELETE FROM posts_fts WHERE rowid = old.id;
+                {insert} SELECT {values};
+            END",
+            values = indexed_values("new"),
+        ))
+        .await?;
+
+        db.execute_unprepared(
+            "CREATE TRIGGER IF NOT EXISTS posts_fts_after_delete AFTER DELETE ON posts BEGIN
+                DELETE FROM posts_fts WHERE rowid = old.id;
+            END",
+        )
+        .await?;
+
+        // Indexer les posts existants
+        db.execute_unprepared(&format!(
+            "{insert} SELECT {values} FROM posts",
+            values = indexed_values("posts"),
+        ))
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared("DROP TRIGGER IF EXISTS posts_fts_after_delete").await?;
+        db.execute_unprepared("DROP TRIGGER IF EXISTS posts_fts_after_update").await?;
+        db.execute_unprepared("DROP TRIGGER IF EXISTS posts_fts_after_insert").await?;
+        db.execute_unprepared("DROP TABLE IF EXISTS posts_fts").await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::HashSet;
+
+use sea_orm_migration::sea_orm::{ConnectionTrait, Statement, Value};
+use sea_orm_migration::{prelude::*, schema::*};
+
+/// Migration: Add slug column to posts (URLs /blog/mon-premier-post) + table des anciens slugs
+/// Les posts existants reçoivent un slug calculé depuis leur titre
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Même règle que l'application au moment de la migration (copie figée volontaire)
+fn slugify(title: &str) -> String {
+    let slug = slug::slugify(title);
+    let slug = slug.chars().take(100).collect::<String>();
+    let slug = slug.trim_matches('-');
+    if slug.is_empty() { "post".to_string() } else { slug.to_string() }
+}
+
+/// Variante `base-n`, raccourcie pour rester dans les 100 caractères (même règle que post::suffixed_slug)
+fn suffixed_slug(base: &str, n: u32) -> String {
+    let suffix = format!("-{n}");
+    let stem: String = base.chars().take(100 - suffix.len()).collect();
+    format!("{}{suffix}", stem.trim_end_matches('-'))
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .add_column(string_len(Posts::Slug, 255).default(""))  // rempli ci-dessous
+                    .to_owned(),
+            )
+            .await?;
+
+        // Backfill: slug du titre, suffixe -2, -3... en cas de doublon
+        let db = manager.get_connection();
+        let backend = db.get_database_backend();
+        let rows = db
+            .query_all(Statement::from_string(backend, "SELECT id, title FROM posts ORDER BY id"))
+            .await?;
+
+        let mut taken = HashSet::new();
+        for row in rows {
+            let id: i32 = row.try_get("", "id")?;
+            let title: String = row.try_get("", "title")?;
+
+            let base = slugify(&title);
+            let mut slug = base.clone();
+            let mut suffix = 2;
+            while !taken.insert(slug.clone()) {
+                slug = suffixed_slug(&base, suffix);
+                suffix += 1;
+            }
+
+            db.execute(Statement::from_sql_and_values(
+                backend,
+                "UPDATE posts SET slug = ? WHERE id = ?",
+                [Value::from(slug), Value::from(id)],
+            ))
+            .await?;
+        }
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_posts_slug")
+                    .table(Posts::Table)
+                    .col(Posts::Slug)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Anciens slugs d'un post (redirigés vers le slug courant)
+        manager
+            .create_table(
+                Table::create()
+                    .table(PostSlugRedirects::Table)
+                    .if_not_exists()
+                    .col(string_len(PostSlugRedirects::Slug, 255).primary_key())
+                    .col(integer(PostSlugRedirects::PostId))
+                    .col(timestamp(PostSlugRedirects::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_post_slug_redirects_post")
+                            .from(PostSlugRedirects::Table, PostSlugRedirects::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PostSlugRedirects::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_index(Index::drop().name("idx_posts_slug").table(Posts::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .drop_column(Posts::Slug)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Posts {
+    Table,
+    Id,
+    Slug,
+}
+
+#[derive(DeriveIden)]
+enum PostSlugRedirects {
+    Table,
+    Slug,
+    PostId,
+    CreatedAt,
+}
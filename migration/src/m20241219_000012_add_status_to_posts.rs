@@ -0,0 +1,85 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+/// Migration: Add status (draft / scheduled / published / archived) and publish_at to posts
+/// Les posts publiés existants passent en "published", les autres restent "draft"
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // SQLite: une colonne par ALTER TABLE
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .add_column(string_len(Posts::Status, 20).default("draft"))  // status VARCHAR(20) NOT NULL DEFAULT 'draft'
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .add_column(timestamp_null(Posts::PublishAt))  // publish_at TIMESTAMP NULL
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "UPDATE posts SET status = 'published', publish_at = created_at WHERE published = 1",
+            )
+            .await?;
+
+        // Recherche des posts programmés arrivés à échéance
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_posts_status_publish_at")
+                    .table(Posts::Table)
+                    .col(Posts::Status)
+                    .col(Posts::PublishAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_posts_status_publish_at")
+                    .table(Posts::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .drop_column(Posts::PublishAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .drop_column(Posts::Status)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Posts {
+    Table,
+    Status,
+    PublishAt,
+}
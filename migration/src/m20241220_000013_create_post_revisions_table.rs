@@ -0,0 +1,88 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+/// Migration: Create post_revisions table (version précédente d'un post à chaque modification)
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PostRevisions::Table)
+                    .if_not_exists()
+                    .col(pk_auto(PostRevisions::Id))
+                    .col(integer(PostRevisions::PostId))
+                    // Numéro de révision, croissant par post (1, 2, 3...)
+                    .col(integer(PostRevisions::Revision))
+                    .col(string_len(PostRevisions::Title, 255))
+                    .col(text(PostRevisions::Content))
+                    .col(json(PostRevisions::Metadata))
+                    // Utilisateur dont la modification a remplacé cette version
+                    .col(integer_null(PostRevisions::EditedBy))
+                    .col(timestamp(PostRevisions::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_post_revisions_post")
+                            .from(PostRevisions::Table, PostRevisions::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    // L'historique survit à la suppression de l'utilisateur
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_post_revisions_edited_by")
+                            .from(PostRevisions::Table, PostRevisions::EditedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Une seule révision N par post (et liste par post)
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_post_revisions_post_revision")
+                    .table(PostRevisions::Table)
+                    .col(PostRevisions::PostId)
+                    .col(PostRevisions::Revision)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PostRevisions::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PostRevisions {
+    Table,
+    Id,
+    PostId,
+    Revision,
+    Title,
+    Content,
+    Metadata,
+    EditedBy,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Posts {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,61 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+/// Migration: Add version to posts and users (ETag / If-Match, verrouillage optimiste)
+/// Incrémentée à chaque modification ; les lignes existantes démarrent à 1
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .add_column(integer(Posts::Version).default(1))  // version INTEGER NOT NULL DEFAULT 1
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(integer(Users::Version).default(1))  // version INTEGER NOT NULL DEFAULT 1
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Users::Version)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .drop_column(Posts::Version)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Posts {
+    Table,
+    Version,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Version,
+}
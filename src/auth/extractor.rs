@@ -0,0 +1,209 @@
+// src/auth/extractor.rs
+// Equivalent de: Authenticator + #[CurrentUser] en Symfony
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{header::AUTHORIZATION, request::Parts, HeaderMap},
+};
+use thiserror::Error;
+use tracing::warn;
+
+use crate::config::AppState;
+use crate::entities::api_key::ApiScope;
+use crate::entities::user;
+use crate::error::{ApiError, ServiceError};
+use crate::i18n::Message;
+
+/// Header portant la clé d'API des clients machine
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+// === Erreurs d'authentification (comme AuthenticationException en Symfony) ===
+
+#[derive(Debug, Error)]
+pub enum AuthError {
+    #[error("Missing bearer token or API key")]
+    MissingToken,
+
+    #[error("Invalid or expired token")]
+    InvalidToken,
+
+    #[error("Invalid or revoked API key")]
+    InvalidApiKey,
+
+    #[error("User no longer exists")]
+    UnknownUser,
+
+    #[error("API key is not allowed to access this endpoint")]
+    InsufficientScope,
+}
+
+impl AuthError {
+    /// Message traduisible renvoyé au client
+    fn message(&self) -> Message {
+        Message::new(match self {
+            AuthError::MissingToken => "auth.missing_token",
+            AuthError::InvalidToken => "auth.invalid_token",
+            AuthError::InvalidApiKey => "auth.invalid_api_key",
+            AuthError::UnknownUser => "auth.unknown_user",
+            AuthError::InsufficientScope => "auth.insufficient_scope",
+        })
+    }
+}
+
+/// Les erreurs d'authentification deviennent des 401
+/// (sauf un scope insuffisant : la clé est valide mais n'a pas le droit, 403)
+impl From<AuthError> for ApiError {
+    fn from(err: AuthError) -> Self {
+        match err {
+            AuthError::InsufficientScope => ApiError::Forbidden(err.message()),
+            _ => ApiError::Unauthorized(err.message()),
+        }
+    }
+}
+
+// === AuthUser: Extracteur qui résout l'utilisateur courant ===
+// Equivalent de: $this->getUser() / #[CurrentUser] en Symfony
+// Login obligatoire (bearer token) : les clés d'API sont refusées
+
+pub struct AuthUser(pub user::Model);
+
+#[async_trait]
+impl FromRequestParts<Arc<AppState>> for AuthUser {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<AppState>,
+    ) -> Result<Self, Self::Rejection> {
+        authenticate(parts, state, None).await.map(AuthUser)
+    }
+}
+
+// === ScopedUser: même chose, en acceptant aussi une clé d'API ===
+// Le handler déclare le scope exigé de la clé : `ScopedUser<scope::PostsWrite>`
+// Equivalent de: #[IsGranted('ROLE_API_POSTS_WRITE')] sur un contrôleur Symfony
+
+pub struct ScopedUser<S>(pub user::Model, pub PhantomData<S>);
+
+/// Scope d'API exigé par un handler (paramètre de `ScopedUser`)
+pub trait RequiredScope {
+    const SCOPE: ApiScope;
+}
+
+/// Marqueurs des scopes, un par variante de `ApiScope`
+pub mod scope {
+    use super::RequiredScope;
+    use crate::entities::api_key::ApiScope;
+
+    pub struct PostsRead;
+    pub struct PostsWrite;
+    pub struct CommentsWrite;
+
+    impl RequiredScope for PostsRead {
+        const SCOPE: ApiScope = ApiScope::PostsRead;
+    }
+
+    impl RequiredScope for PostsWrite {
+        const SCOPE: ApiScope = ApiScope::PostsWrite;
+    }
+
+    impl RequiredScope for CommentsWrite {
+        const SCOPE: ApiScope = ApiScope::CommentsWrite;
+    }
+}
+
+#[async_trait]
+impl<S: RequiredScope> FromRequestParts<Arc<AppState>> for ScopedUser<S> {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<AppState>,
+    ) -> Result<Self, Self::Rejection> {
+        let user = authenticate(parts, state, Some(S::SCOPE)).await?;
+        Ok(ScopedUser(user, PhantomData))
+    }
+}
+
+/// Résout l'utilisateur d'un bearer token, ou d'une clé d'API si le handler en accepte une
+async fn authenticate(
+    parts: &Parts,
+    state: &Arc<AppState>,
+    scope: Option<ApiScope>,
+) -> Result<user::Model, ApiError> {
+    // Client machine : clé d'API (X-Api-Key), limitée par ses scopes
+    if bearer_token(&parts.headers).is_none()
+        && let Some(secret) = api_key(&parts.headers)
+    {
+        return authenticate_api_key(parts, state, secret, scope).await;
+    }
+
+    // 1. Récupère le token du header Authorization
+    let token = bearer_token(&parts.headers).ok_or(AuthError::MissingToken)?;
+
+    // 2. Vérifie la signature et l'expiration
+    let claims = state.jwt_service.verify(token).map_err(|err| {
+        warn!(error = %err, "Rejected access token");
+        AuthError::InvalidToken
+    })?;
+
+    // 3. Charge l'utilisateur (comme le UserProvider)
+    state
+        .user_service
+        .find_by_id(claims.sub)
+        .await
+        .map_err(|err| match err {
+            ServiceError::NotFound => AuthError::UnknownUser.into(),
+            other => ApiError::from(other),
+        })
+}
+
+/// Résout le propriétaire d'une clé d'API et vérifie le scope exigé par le handler
+/// Sans scope déclaré (compte, clés, utilisateurs), une clé n'a jamais accès
+async fn authenticate_api_key(
+    parts: &Parts,
+    state: &Arc<AppState>,
+    secret: &str,
+    scope: Option<ApiScope>,
+) -> Result<user::Model, ApiError> {
+    let (key, user) = state
+        .api_key_service
+        .authenticate(secret)
+        .await
+        .map_err(|err| match err {
+            ServiceError::InvalidCredentials => AuthError::InvalidApiKey.into(),
+            other => ApiError::from(other),
+        })?;
+
+    if !scope.is_some_and(|scope| key.has_scope(scope)) {
+        warn!(api_key_id = key.id, ?scope, path = parts.uri.path(), "Scope denied");
+        return Err(AuthError::InsufficientScope.into());
+    }
+
+    Ok(user)
+}
+
+/// Extrait le token d'un header `Authorization: Bearer <token>`
+pub(crate) fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+}
+
+/// Extrait la clé d'un header `X-Api-Key: <key>`
+fn api_key(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(API_KEY_HEADER)?
+        .to_str()
+        .ok()
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+}
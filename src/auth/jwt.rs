@@ -0,0 +1,96 @@
+// src/auth/jwt.rs
+// Equivalent de: LexikJWTAuthenticationBundle (JWTManager)
+
+use chrono::Utc;
+use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+
+use crate::entities::user;
+
+/// Claims contenus dans un access token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Claims {
+    /// ID de l'utilisateur (subject)
+    pub sub: i32,
+    /// Date d'émission (timestamp UNIX)
+    pub iat: i64,
+    /// Date d'expiration (timestamp UNIX)
+    pub exp: i64,
+}
+
+/// JwtService - Signature et vérification des access tokens (HS256)
+#[derive(Clone)]
+pub struct JwtService {
+    encoding_key: EncodingKey,
+    decoding_key: DecodingKey,
+    ttl_seconds: i64,
+}
+
+impl JwtService {
+    /// Create a new JwtService from a shared secret
+    pub fn new(secret: &str, ttl_seconds: i64) -> Self {
+        Self {
+            encoding_key: EncodingKey::from_secret(secret.as_bytes()),
+            decoding_key: DecodingKey::from_secret(secret.as_bytes()),
+            ttl_seconds,
+        }
+    }
+
+    /// Durée de validité des tokens émis (en secondes)
+    pub fn ttl_seconds(&self) -> i64 {
+        self.ttl_seconds
+    }
+
+    /// Émet un access token signé pour l'utilisateur
+    pub fn issue(&self, user: &user::Model) -> Result<String, jsonwebtoken::errors::Error> {
+        let now = Utc::now().timestamp();
+        let claims = Claims {
+            sub: user.id,
+            iat: now,
+            exp: now + self.ttl_seconds,
+        };
+
+        encode(&Header::new(Algorithm::HS256), &claims, &self.encoding_key)
+    }
+
+    /// Vérifie la signature et l'expiration d'un token
+    pub fn verify(&self, token: &str) -> Result<Claims, jsonwebtoken::errors::Error> {
+        let validation = Validation::new(Algorithm::HS256);
+        decode::<Claims>(token, &self.decoding_key, &validation).map(|data| data.claims)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{Factory, UserFactory};
+
+    #[test]
+    fn test_issue_and_verify_roundtrip() {
+        let service = JwtService::new("test-secret", 60);
+        let user = UserFactory::new().make();
+
+        let token = service.issue(&user).unwrap();
+        let claims = service.verify(&token).unwrap();
+
+        assert_eq!(claims.sub, user.id);
+        assert_eq!(claims.exp - claims.iat, 60);
+    }
+
+    #[test]
+    fn test_verify_rejects_other_secret() {
+        let user = UserFactory::new().make();
+        let token = JwtService::new("secret-a", 60).issue(&user).unwrap();
+
+        assert!(JwtService::new("secret-b", 60).verify(&token).is_err());
+    }
+
+    #[test]
+    fn test_verify_rejects_expired_token() {
+        // TTL négatif au-delà de la tolérance par défaut (60s)
+        let service = JwtService::new("test-secret", -120);
+        let token = service.issue(&UserFactory::new().make()).unwrap();
+
+        assert!(service.verify(&token).is_err());
+    }
+}
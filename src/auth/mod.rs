@@ -0,0 +1,15 @@
+// src/auth/mod.rs
+// Equivalent de: Security component en Symfony (firewall, authenticator, password hasher)
+
+mod extractor;
+mod jwt;
+mod password;
+mod permissions;
+mod secret;
+
+pub use extractor::{scope, AuthError, AuthUser, RequiredScope, ScopedUser, API_KEY_HEADER};
+pub(crate) use extractor::bearer_token;
+pub use jwt::{Claims, JwtService};
+pub use password::{hash_password, verify_password};
+pub use permissions::{ensure, Permission};
+pub use secret::{generate_secret, hash_secret};
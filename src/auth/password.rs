@@ -0,0 +1,44 @@
+// src/auth/password.rs
+// Equivalent de: UserPasswordHasherInterface en Symfony
+
+use argon2::password_hash::{rand_core::OsRng, PasswordHash, SaltString};
+use argon2::{Argon2, PasswordHasher, PasswordVerifier};
+
+/// Hash un mot de passe avec Argon2id (sel aléatoire, format PHC)
+pub fn hash_password(password: &str) -> Result<String, argon2::password_hash::Error> {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+}
+
+/// Vérifie un mot de passe contre un hash PHC
+/// Un hash vide ou invalide ne correspond jamais
+pub fn verify_password(password: &str, password_hash: &str) -> bool {
+    let Ok(parsed) = PasswordHash::new(password_hash) else {
+        return false;
+    };
+
+    Argon2::default()
+        .verify_password(password.as_bytes(), &parsed)
+        .is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_and_verify() {
+        let hash = hash_password("S3cure-password").unwrap();
+
+        assert!(hash.starts_with("$argon2id$"));
+        assert!(verify_password("S3cure-password", &hash));
+        assert!(!verify_password("wrong-password", &hash));
+    }
+
+    #[test]
+    fn test_verify_empty_hash_fails() {
+        assert!(!verify_password("anything", ""));
+    }
+}
@@ -0,0 +1,92 @@
+// src/auth/permissions.rs
+// Equivalent de: Voters + role_hierarchy en Symfony
+
+use crate::entities::user::{self, Role};
+use crate::error::ServiceError;
+use crate::i18n::Message;
+
+/// Permissions applicatives
+/// Equivalent de: les attributs testés par isGranted() en Symfony
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Permission {
+    /// Modifier/supprimer n'importe quel utilisateur
+    ManageUsers,
+    /// Modifier/supprimer les posts des autres auteurs
+    ManageAnyPost,
+    /// Créer un post au nom d'un autre auteur
+    PublishOnBehalf,
+    /// Modifier/supprimer les commentaires des autres utilisateurs
+    ModerateComments,
+}
+
+impl Role {
+    /// Indique si le rôle accorde la permission (hiérarchie admin > editor > author)
+    pub fn grants(self, permission: Permission) -> bool {
+        matches!(
+            (self, permission),
+            (Role::Admin, _)
+                | (Role::Editor, Permission::ManageAnyPost | Permission::ModerateComments)
+        )
+    }
+}
+
+impl user::Model {
+    /// Equivalent de: $this->isGranted('...')
+    pub fn can(&self, permission: Permission) -> bool {
+        self.role.grants(permission)
+    }
+
+    /// Un utilisateur peut agir sur une ressource qui lui appartient,
+    /// ou sur n'importe laquelle s'il a la permission
+    pub fn owns_or_can(&self, owner_id: i32, permission: Permission) -> bool {
+        self.id == owner_id || self.can(permission)
+    }
+}
+
+/// Retourne ServiceError::Forbidden si la permission n'est pas accordée
+/// `action` est traduite par la clé `forbidden.<action>`
+/// Equivalent de: $this->denyAccessUnlessGranted('...')
+pub fn ensure(allowed: bool, action: &str) -> Result<(), ServiceError> {
+    if allowed {
+        Ok(())
+    } else {
+        Err(ServiceError::Forbidden(Message::new(format!("forbidden.{action}"))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{Factory, UserFactory};
+
+    #[test]
+    fn test_role_hierarchy() {
+        assert!(Role::Admin.grants(Permission::ManageUsers));
+        assert!(Role::Admin.grants(Permission::ManageAnyPost));
+        assert!(!Role::Editor.grants(Permission::ManageUsers));
+        assert!(Role::Editor.grants(Permission::ManageAnyPost));
+        assert!(!Role::Author.grants(Permission::ManageUsers));
+        assert!(!Role::Author.grants(Permission::ManageAnyPost));
+        assert!(Role::Admin.grants(Permission::PublishOnBehalf));
+        assert!(!Role::Editor.grants(Permission::PublishOnBehalf));
+        assert!(Role::Editor.grants(Permission::ModerateComments));
+        assert!(!Role::Author.grants(Permission::ModerateComments));
+    }
+
+    #[test]
+    fn test_owner_is_always_allowed() {
+        let author = UserFactory::new().make();
+
+        assert!(author.owns_or_can(author.id, Permission::ManageAnyPost));
+        assert!(!author.owns_or_can(author.id + 1, Permission::ManageAnyPost));
+    }
+
+    #[test]
+    fn test_ensure_maps_to_forbidden() {
+        assert!(ensure(true, "delete_users").is_ok());
+        assert!(matches!(
+            ensure(false, "delete_users"),
+            Err(ServiceError::Forbidden(_))
+        ));
+    }
+}
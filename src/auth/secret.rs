@@ -0,0 +1,42 @@
+// src/auth/secret.rs
+// Secrets opaques (API keys) : générés aléatoirement, stockés hachés
+
+use rand::{rngs::OsRng, RngCore};
+use sha2::{Digest, Sha256};
+
+/// Génère un secret aléatoire de 256 bits, préfixé (ex: "rak_3f9c...")
+/// Le préfixe permet de reconnaître le type de secret (et aux scanners de le détecter)
+pub fn generate_secret(prefix: &str) -> String {
+    let mut bytes = [0u8; 32];
+    OsRng.fill_bytes(&mut bytes);
+    format!("{prefix}{}", hex::encode(bytes))
+}
+
+/// SHA-256 hexadécimal d'un secret
+/// Suffisant ici (contrairement aux mots de passe) : le secret a déjà 256 bits d'entropie,
+/// et un hash déterministe permet de retrouver la ligne par index.
+pub fn hash_secret(secret: &str) -> String {
+    hex::encode(Sha256::digest(secret.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_secrets_are_prefixed_and_unique() {
+        let first = generate_secret("rak_");
+        let second = generate_secret("rak_");
+
+        assert!(first.starts_with("rak_"));
+        assert_eq!(first.len(), 4 + 64);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn hash_is_deterministic() {
+        assert_eq!(hash_secret("rak_abc"), hash_secret("rak_abc"));
+        assert_ne!(hash_secret("rak_abc"), hash_secret("rak_abd"));
+        assert_eq!(hash_secret("rak_abc").len(), 64);
+    }
+}
@@ -1,7 +1,12 @@
 // src/config/app_state.rs
 // Equivalent de: Container de services Symfony
 
-use crate::services::{PostService, UserService};
+use super::settings::Settings;
+use crate::auth::JwtService;
+use crate::services::{
+    ApiKeyService, CommentService, HealthService, PostService, RefreshTokenService, SearchService,
+    TagService, UserService,
+};
 
 /// AppState - Application state containing all services
 /// Equivalent de: Service Container en Symfony
@@ -14,14 +19,51 @@ pub struct AppState {
     pub user_service: UserService,
     /// PostService instance
     pub post_service: PostService,
+    /// CommentService instance
+    pub comment_service: CommentService,
+    /// TagService instance (tags normalisés)
+    pub tag_service: TagService,
+    /// SearchService instance (recherche plein texte)
+    pub search_service: SearchService,
+    /// ApiKeyService instance (clés d'API des clients machine)
+    pub api_key_service: ApiKeyService,
+    /// RefreshTokenService instance (sessions, rotation des refresh tokens)
+    pub refresh_token_service: RefreshTokenService,
+    /// JwtService instance (signature des access tokens)
+    pub jwt_service: JwtService,
+    /// HealthService instance (sonde de readiness)
+    pub health_service: HealthService,
+    /// Configuration chargée au démarrage (comme les paramètres du container)
+    pub settings: Settings,
 }
 
 impl AppState {
     /// Create a new AppState with all services
-    pub fn new(user_service: UserService, post_service: PostService) -> Self {
+    /// (un argument par service, comme l'injection par constructeur en Symfony)
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        user_service: UserService,
+        post_service: PostService,
+        comment_service: CommentService,
+        tag_service: TagService,
+        search_service: SearchService,
+        api_key_service: ApiKeyService,
+        refresh_token_service: RefreshTokenService,
+        jwt_service: JwtService,
+        health_service: HealthService,
+        settings: Settings,
+    ) -> Self {
         Self {
             user_service,
             post_service,
+            comment_service,
+            tag_service,
+            search_service,
+            api_key_service,
+            refresh_token_service,
+            jwt_service,
+            health_service,
+            settings,
         }
     }
 }
@@ -7,15 +7,14 @@ use tracing::info;
 
 use migration::Migrator;
 
+use super::settings::DatabaseSettings;
+
 /// Initialize database connection and run migrations
 /// Equivalent de: doctrine:database:create + doctrine:migrations:migrate
-pub async fn init_database() -> DatabaseConnection {
-    // Connection URL (comme DATABASE_URL dans .env)
-    let database_url = std::env::var("DATABASE_URL")
-        .unwrap_or_else(|_| "sqlite:./database.sqlite?mode=rwc".to_string());
-
+pub async fn init_database(settings: &DatabaseSettings) -> DatabaseConnection {
+    // Connection URL (`database.url`, comme DATABASE_URL dans .env)
     info!("Connecting to database...");
-    let db = Database::connect(&database_url)
+    let db = Database::connect(&settings.url)
         .await
         .expect("Failed to connect to database");
     info!("Database connected!");
@@ -1,29 +1,64 @@
 // src/config/logging.rs
 // Equivalent de: config/packages/monolog.yaml
 
+use axum::http::HeaderMap;
+use serde::Deserialize;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
+use super::settings::LogSettings;
+
+/// Header de corrélation des requêtes (généré s'il est absent, renvoyé dans la réponse)
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Identifiant de corrélation d'une requête (header X-Request-Id)
+pub fn request_id(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+}
+
+/// Format des logs (`log.format`, LOG_FORMAT)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// Lisible par un humain (développement)
+    #[default]
+    Pretty,
+    /// Une ligne JSON par événement, avec les champs du span (comme le JsonFormatter de Monolog)
+    Json,
+}
+
 /// Initialize logging
 /// Equivalent de: monolog configuration en Symfony
-pub fn init_logging() {
-    // RUST_LOG env var (comme MONOLOG_LEVEL)
+pub fn init_logging(settings: &LogSettings) {
+    // `log.filter` (RUST_LOG, comme MONOLOG_LEVEL), déjà validé au chargement
     // Examples:
     //   RUST_LOG=debug
     //   RUST_LOG=rust_api=debug,tower_http=info
-    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| {
-        // Default: info for app, warn for dependencies
-        EnvFilter::new("rust_api=info,tower_http=info,sea_orm=warn")
+    let env_filter = EnvFilter::new(&settings.filter);
+
+    let format = settings.format;
+
+    // Un seul des deux layers est actif (un Option<Layer> vide ne fait rien)
+    let pretty = (format == LogFormat::Pretty).then(|| {
+        tracing_subscriber::fmt::layer()
+            .with_target(true)
+            .with_level(true)
+            .with_thread_ids(false)
+            .with_file(false)
+            .with_line_number(false)
+    });
+    let json = (format == LogFormat::Json).then(|| {
+        tracing_subscriber::fmt::layer()
+            .json()
+            .with_target(true)
+            .with_current_span(true)
+            .with_span_list(false)
     });
 
     tracing_subscriber::registry()
         .with(env_filter)
-        .with(
-            tracing_subscriber::fmt::layer()
-                .with_target(true)
-                .with_level(true)
-                .with_thread_ids(false)
-                .with_file(false)
-                .with_line_number(false),
-        )
+        .with(pretty)
+        .with(json)
         .init();
 }
@@ -5,8 +5,16 @@ mod app_state;
 mod database;
 mod logging;
 mod openapi;
+mod security;
+mod settings;
 
 pub use app_state::AppState;
 pub use database::init_database;
-pub use logging::init_logging;
-pub use openapi::ApiDoc;
+pub use logging::{init_logging, request_id, LogFormat, REQUEST_ID_HEADER};
+pub use openapi::{api_doc, ApiDoc};
+pub use security::init_jwt;
+pub use settings::{
+    DatabaseSettings, LogSettings, MetricsSettings, OpenApiServer, OpenApiSettings, Profile,
+    PublisherSettings, SecuritySettings, ServerSettings, Settings, SettingsError,
+    ShutdownSettings,
+};
@@ -1,23 +1,51 @@
 // src/config/openapi.rs
 // Equivalent de: config/packages/nelmio_api_doc.yaml
 
-use utoipa::OpenApi;
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::openapi::server::ServerBuilder;
+use utoipa::{Modify, OpenApi};
 
-use crate::controllers::health_controller::{__path_health, __path_index};
+use super::settings::OpenApiSettings;
+
+use crate::controllers::api_key_controller::{
+    __path_create_api_key, __path_list_api_keys, __path_revoke_api_key,
+};
+use crate::controllers::auth_controller::{
+    __path_change_password, __path_login, __path_logout, __path_logout_all, __path_me, __path_refresh,
+};
+use crate::controllers::comment_controller::{
+    __path_create_comment, __path_delete_comment, __path_get_comment, __path_list_comments,
+    __path_update_comment,
+};
+use crate::controllers::health_controller::{__path_health, __path_index, __path_live, __path_ready};
 use crate::controllers::post_controller::{
-    __path_create_post, __path_delete_post, __path_get_post, __path_list_posts, __path_update_post,
+    __path_create_post, __path_delete_post, __path_get_post, __path_get_post_by_slug,
+    __path_list_posts, __path_list_published_posts, __path_list_tag_posts, __path_list_user_posts,
+    __path_patch_post, __path_search_posts, __path_update_post,
 };
+use crate::controllers::revision_controller::{
+    __path_get_revision, __path_list_revisions, __path_restore_revision,
+};
+use crate::controllers::tag_controller::__path_list_tags;
 use crate::controllers::user_controller::{
-    __path_create_user, __path_delete_user, __path_get_user, __path_list_users, __path_update_user,
+    __path_create_user, __path_delete_user, __path_get_user, __path_list_users, __path_patch_user,
+    __path_update_user, __path_update_user_role,
 };
 use crate::dto::{
-    AuthorResponse, CreatePostDto, CreatePostMetadataDto, CreatePostSettingsDto,
-    CreateSeoMetadataDto, CreateTagDto, CreateUserDto, PaginationQuery, PostListItemResponse,
-    PostMetadataResponse, PostResponse, PostSettingsResponse, SeoMetadataResponse, TagResponse,
-    UpdatePostDto, UpdateUserDto, UserResponse,
+    ApiKeyResponse, AuthorResponse, ChangePasswordDto, CheckResponse, CommentResponse,
+    CreateApiKeyDto, CreateCommentDto, CreatePostDto, CreatePostMetadataDto, CreatePostSettingsDto,
+    CreateSeoMetadataDto, CreateTagDto, CreateUserDto, CreatedApiKeyResponse, DiffLineResponse,
+    DiffOp, HealthChecksResponse, HealthReportResponse, HealthStatus, LivenessResponse, LoginDto, PaginationQuery, PostListItemResponse, PostMetadataResponse, PostResponse,
+    PostRevisionDetailResponse, PostRevisionResponse, PostSearchResultResponse,
+    PostSettingsResponse, RefreshTokenDto, RevisionDiffResponse, SeoMetadataResponse,
+    TagListItemResponse, TagResponse, TokenResponse, UpdateCommentDto, UpdatePostDto,
+    UpdateUserDto, UpdateUserRoleDto, UserResponse,
 };
-use crate::error::ErrorResponse;
-use crate::response::PaginationMeta;
+use crate::entities::api_key::ApiScope;
+use crate::entities::post::PostStatus;
+use crate::entities::user::Role;
+use crate::error::{ErrorCode, ProblemDetails, ProblemJsonAddon, Violation};
+use crate::response::{CursorMeta, ListMeta, PaginationMeta};
 
 /// OpenAPI Documentation
 #[derive(OpenApi)]
@@ -25,7 +53,7 @@ use crate::response::PaginationMeta;
     info(
         title = "Rust API",
         version = "1.0.0",
-        description = "A REST API built with Rust, Axum and SeaORM\n\nStructure inspired by Symfony:\n- Controllers: HTTP handlers\n- Services: Business logic\n- DTOs: Data transfer objects\n- Entities: Database models\n\n## Response Format\n\nAll responses follow this structure:\n```json\n{\n  \"data\": { ... } or [...],\n  \"meta\": { ... } // optional, for pagination\n}\n```",
+        description = "A REST API built with Rust, Axum and SeaORM\n\nStructure inspired by Symfony:\n- Controllers: HTTP handlers\n- Services: Business logic\n- DTOs: Data transfer objects\n- Entities: Database models\n\n## Response Format\n\nAll responses follow this structure:\n```json\n{\n  \"data\": { ... } or [...],\n  \"meta\": { ... } // optional, for pagination\n}\n```\n\n## Authentication\n\nMutating endpoints require an `Authorization: Bearer <token>` header.\nObtain a token from `POST /auth/login`.\nAccess tokens are short-lived: exchange the refresh token for a new pair with `POST /auth/refresh`.\n\nMachine clients can instead send an `X-Api-Key: <key>` header on post, revision and comment endpoints.\nKeys are minted with `POST /auth/api-keys` and carry scopes (`posts:read`, `posts:write`, `comments:write`).\n\n## Errors\n\nErrors are `application/problem+json` documents (RFC 7807) with `type`, `title`, `status`, `detail`, `instance`, a stable `code` and, for validation errors, per-field `violations`.",
         contact(
             name = "API Support",
             email = "support@example.com"
@@ -38,30 +66,85 @@ use crate::response::PaginationMeta;
         // Health endpoints
         index,
         health,
+        live,
+        ready,
+        // Auth endpoints
+        login,
+        refresh,
+        logout,
+        logout_all,
+        me,
+        change_password,
+        list_api_keys,
+        create_api_key,
+        revoke_api_key,
         // User endpoints
         list_users,
         get_user,
         create_user,
         update_user,
+        patch_user,
+        update_user_role,
         delete_user,
         // Post endpoints
         list_posts,
+        list_published_posts,
+        search_posts,
+        list_user_posts,
         get_post,
+        get_post_by_slug,
         create_post,
         update_post,
+        patch_post,
         delete_post,
+        // Revision endpoints
+        list_revisions,
+        get_revision,
+        restore_revision,
+        // Comment endpoints
+        list_comments,
+        get_comment,
+        create_comment,
+        update_comment,
+        delete_comment,
+        // Tag endpoints
+        list_tags,
+        list_tag_posts,
     ),
     components(
         schemas(
+            // Auth DTOs
+            LoginDto,
+            TokenResponse,
+            RefreshTokenDto,
+            ChangePasswordDto,
+            CreateApiKeyDto,
+            ApiKeyResponse,
+            CreatedApiKeyResponse,
+            ApiScope,
             // User DTOs
             CreateUserDto,
             UpdateUserDto,
+            UpdateUserRoleDto,
             UserResponse,
+            Role,
             // Post DTOs
             CreatePostDto,
             UpdatePostDto,
             PostResponse,
             PostListItemResponse,
+            PostSearchResultResponse,
+            PostStatus,
+            // Revision DTOs
+            PostRevisionResponse,
+            PostRevisionDetailResponse,
+            RevisionDiffResponse,
+            DiffLineResponse,
+            DiffOp,
+            // Comment DTOs
+            CreateCommentDto,
+            UpdateCommentDto,
+            CommentResponse,
             // Nested objects - Input
             CreatePostMetadataDto,
             CreateTagDto,
@@ -71,23 +154,76 @@ use crate::response::PaginationMeta;
             AuthorResponse,
             PostMetadataResponse,
             TagResponse,
+            TagListItemResponse,
             SeoMetadataResponse,
             PostSettingsResponse,
             // Pagination
             PaginationQuery,
             PaginationMeta,
-            // Error
-            ErrorResponse,
+            CursorMeta,
+            ListMeta,
+            // Health probes
+            LivenessResponse,
+            HealthReportResponse,
+            HealthChecksResponse,
+            CheckResponse,
+            HealthStatus,
+            // Error (RFC 7807)
+            ProblemDetails,
+            ErrorCode,
+            Violation,
         )
     ),
     tags(
-        (name = "health", description = "Health check endpoints"),
+        (name = "health", description = "Liveness and readiness probes"),
+        (name = "auth", description = "Authentication (JWT bearer tokens)"),
         (name = "users", description = "User management endpoints"),
-        (name = "posts", description = "Post management with nested objects (tags, SEO, settings)")
+        (name = "posts", description = "Post management with nested objects (tags, SEO, settings)"),
+        (name = "revisions", description = "Post revision history with diff and restore"),
+        (name = "comments", description = "Threaded comments on published posts"),
+        (name = "tags", description = "Tags with post counts and tag-based browsing")
     ),
-    servers(
-        (url = "http://localhost:8080", description = "Local development server"),
-        (url = "https://api-rust.theo-corp.fr", description = "Production server"),
-    )
+    modifiers(&SecurityAddon, &ProblemJsonAddon),
 )]
 pub struct ApiDoc;
+
+/// Documentation servie par Swagger UI, avec les serveurs de `openapi.servers`
+pub fn api_doc(settings: &OpenApiSettings) -> utoipa::openapi::OpenApi {
+    let mut openapi = ApiDoc::openapi();
+    openapi.servers = Some(
+        settings
+            .servers
+            .iter()
+            .map(|server| {
+                ServerBuilder::new()
+                    .url(&server.url)
+                    .description(server.description.clone())
+                    .build()
+            })
+            .collect(),
+    );
+    openapi
+}
+
+/// Déclare les schémas de sécurité "bearer_auth" et "api_key"
+/// (bouton "Authorize" dans Swagger UI)
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearer_auth",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+        components.add_security_scheme(
+            "api_key",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("X-Api-Key"))),
+        );
+    }
+}
@@ -0,0 +1,20 @@
+// src/config/security.rs
+// Equivalent de: config/packages/security.yaml + lexik_jwt_authentication.yaml
+
+use tracing::warn;
+
+use super::settings::SecuritySettings;
+use crate::auth::JwtService;
+
+/// Secret de config/default.toml (développement uniquement, refusé en prod)
+pub(super) const DEV_JWT_SECRET: &str = "dev-secret-change-me";
+
+/// Initialize the JWT service from settings
+pub fn init_jwt(settings: &SecuritySettings) -> JwtService {
+    // JWT_SECRET (comme JWT_PASSPHRASE dans .env)
+    if settings.jwt_secret == DEV_JWT_SECRET {
+        warn!("JWT_SECRET not set, using insecure development secret");
+    }
+
+    JwtService::new(&settings.jwt_secret, settings.jwt_ttl_seconds)
+}
@@ -0,0 +1,466 @@
+// src/config/settings.rs
+// Configuration typée de l'application (comme les paramètres du container Symfony)
+// Couches, de la plus faible à la plus forte:
+//   config/default.toml < config/<APP_ENV>.toml < CONFIG_FILE < variables d'environnement
+
+use std::net::SocketAddr;
+use std::path::Path;
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use thiserror::Error;
+use toml_edit::{DocumentMut, Item};
+use tracing_subscriber::EnvFilter;
+
+use super::logging::LogFormat;
+use super::security::DEV_JWT_SECRET;
+
+const DEFAULT_TOML: &str = include_str!("../../config/default.toml");
+const DEV_TOML: &str = include_str!("../../config/dev.toml");
+const TEST_TOML: &str = include_str!("../../config/test.toml");
+const PROD_TOML: &str = include_str!("../../config/prod.toml");
+
+/// Variables d'environnement reconnues et la clé qu'elles surchargent
+const ENV_OVERRIDES: &[(&str, &str, EnvKind)] = &[
+    ("SERVER_ADDR", "server.addr", EnvKind::String),
+    ("DATABASE_URL", "database.url", EnvKind::String),
+    ("RUST_LOG", "log.filter", EnvKind::String),
+    ("LOG_FORMAT", "log.format", EnvKind::String),
+    ("JWT_SECRET", "security.jwt_secret", EnvKind::String),
+    ("JWT_TTL_SECONDS", "security.jwt_ttl_seconds", EnvKind::Integer),
+    ("REFRESH_TOKEN_TTL_SECONDS", "security.refresh_token_ttl_seconds", EnvKind::Integer),
+    ("PUBLISHER_INTERVAL_SECONDS", "publisher.interval_seconds", EnvKind::Integer),
+    ("METRICS_TOKEN", "metrics.token", EnvKind::String),
+    ("SHUTDOWN_DELAY_SECONDS", "shutdown.delay_seconds", EnvKind::Integer),
+    ("SHUTDOWN_TIMEOUT_SECONDS", "shutdown.timeout_seconds", EnvKind::Integer),
+];
+
+#[derive(Clone, Copy)]
+enum EnvKind {
+    String,
+    Integer,
+}
+
+/// Erreur de chargement de la configuration, affichée au démarrage
+#[derive(Debug, Error)]
+pub enum SettingsError {
+    #[error("unknown APP_ENV '{0}' (expected dev, test or prod)")]
+    UnknownProfile(String),
+
+    #[error("cannot read {path}: {source}")]
+    Read {
+        path: String,
+        source: std::io::Error,
+    },
+
+    #[error("invalid TOML in {path}: {message}")]
+    Syntax { path: String, message: String },
+
+    #[error("{name}: expected an integer, got '{value}'")]
+    InvalidEnv { name: String, value: String },
+
+    /// Clé inconnue ou valeur du mauvais type (`log.format: unknown variant ...`)
+    #[error("{0}")]
+    Invalid(String),
+
+    #[error("invalid configuration:\n  - {}", .0.join("\n  - "))]
+    Validation(Vec<String>),
+}
+
+/// Profil d'exécution (APP_ENV, comme l'environnement Symfony)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Profile {
+    #[default]
+    Dev,
+    Test,
+    Prod,
+}
+
+impl Profile {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Profile::Dev => "dev",
+            Profile::Test => "test",
+            Profile::Prod => "prod",
+        }
+    }
+
+    fn parse(name: &str) -> Result<Self, SettingsError> {
+        match name {
+            "dev" => Ok(Profile::Dev),
+            "test" => Ok(Profile::Test),
+            "prod" => Ok(Profile::Prod),
+            other => Err(SettingsError::UnknownProfile(other.to_string())),
+        }
+    }
+
+    /// Fichier du profil, embarqué dans le binaire
+    fn toml(&self) -> (&'static str, &'static str) {
+        match self {
+            Profile::Dev => ("config/dev.toml", DEV_TOML),
+            Profile::Test => ("config/test.toml", TEST_TOML),
+            Profile::Prod => ("config/prod.toml", PROD_TOML),
+        }
+    }
+}
+
+/// Configuration de l'application, chargée une fois au démarrage
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Settings {
+    #[serde(skip)]
+    pub profile: Profile,
+    pub server: ServerSettings,
+    pub database: DatabaseSettings,
+    pub log: LogSettings,
+    pub security: SecuritySettings,
+    pub publisher: PublisherSettings,
+    pub metrics: MetricsSettings,
+    pub shutdown: ShutdownSettings,
+    pub openapi: OpenApiSettings,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ServerSettings {
+    /// Adresse d'écoute (SERVER_ADDR)
+    pub addr: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DatabaseSettings {
+    /// DATABASE_URL
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LogSettings {
+    /// Filtre tracing, syntaxe de RUST_LOG
+    pub filter: String,
+    /// LOG_FORMAT
+    pub format: LogFormat,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SecuritySettings {
+    /// Secret de signature des JWT (JWT_SECRET)
+    pub jwt_secret: String,
+    /// Durée de vie des access tokens (JWT_TTL_SECONDS)
+    pub jwt_ttl_seconds: i64,
+    /// Durée de vie des refresh tokens (REFRESH_TOKEN_TTL_SECONDS)
+    pub refresh_token_ttl_seconds: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PublisherSettings {
+    /// Intervalle entre deux passages du publisher (PUBLISHER_INTERVAL_SECONDS)
+    pub interval_seconds: u64,
+}
+
+impl PublisherSettings {
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(self.interval_seconds)
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MetricsSettings {
+    /// Jeton d'accès à /metrics (METRICS_TOKEN) ; absent: métriques désactivées
+    pub token: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ShutdownSettings {
+    /// Délai entre le signal et l'arrêt de l'écoute, readiness en échec (SHUTDOWN_DELAY_SECONDS)
+    pub delay_seconds: u64,
+    /// Temps laissé aux requêtes en cours (SHUTDOWN_TIMEOUT_SECONDS)
+    pub timeout_seconds: u64,
+}
+
+impl ShutdownSettings {
+    pub fn delay(&self) -> Duration {
+        Duration::from_secs(self.delay_seconds)
+    }
+
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout_seconds)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OpenApiSettings {
+    /// Serveurs proposés par Swagger UI
+    pub servers: Vec<OpenApiServer>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OpenApiServer {
+    pub url: String,
+    pub description: Option<String>,
+}
+
+impl Settings {
+    /// Charge la configuration du profil APP_ENV (défaut: dev), du fichier CONFIG_FILE
+    /// et des variables d'environnement
+    pub fn load() -> Result<Self, SettingsError> {
+        let env = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+        let profile = env("APP_ENV").map_or(Ok(Profile::default()), |name| Profile::parse(&name))?;
+
+        Self::load_with(profile, env("CONFIG_FILE").as_deref().map(Path::new), env)
+    }
+
+    /// Configuration d'un profil sans fichier ni variable d'environnement (tests)
+    pub fn for_profile(profile: Profile) -> Result<Self, SettingsError> {
+        Self::load_with(profile, None, |_| None)
+    }
+
+    fn load_with(
+        profile: Profile,
+        file: Option<&Path>,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self, SettingsError> {
+        let mut layers = vec![
+            parse_toml("config/default.toml", DEFAULT_TOML)?,
+            parse_toml(profile.toml().0, profile.toml().1)?,
+        ];
+        if let Some(path) = file {
+            let content = std::fs::read_to_string(path).map_err(|source| SettingsError::Read {
+                path: path.display().to_string(),
+                source,
+            })?;
+            layers.push(parse_toml(&path.display().to_string(), &content)?);
+        }
+        layers.push(env_layer(env)?);
+
+        let mut merged = Value::Object(Map::new());
+        for layer in &layers {
+            json_patch::merge(&mut merged, layer);
+        }
+
+        let mut settings: Settings = serde_path_to_error::deserialize(merged)
+            .map_err(|err| SettingsError::Invalid(err.to_string()))?;
+        settings.profile = profile;
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    /// Vérifie la cohérence des valeurs, toutes les erreurs sont remontées ensemble
+    fn validate(&self) -> Result<(), SettingsError> {
+        let mut errors = Vec::new();
+
+        if self.server.addr.parse::<SocketAddr>().is_err() {
+            errors.push(format!("server.addr: '{}' is not a socket address", self.server.addr));
+        }
+        if self.database.url.trim().is_empty() {
+            errors.push("database.url: must not be empty".to_string());
+        }
+        if let Err(err) = EnvFilter::try_new(&self.log.filter) {
+            errors.push(format!("log.filter: {err}"));
+        }
+        if self.security.jwt_secret.is_empty() {
+            errors.push("security.jwt_secret: required (set JWT_SECRET)".to_string());
+        } else if self.profile == Profile::Prod && self.security.jwt_secret == DEV_JWT_SECRET {
+            errors.push("security.jwt_secret: the development secret is not allowed in prod".to_string());
+        }
+        if self.security.jwt_ttl_seconds <= 0 {
+            errors.push("security.jwt_ttl_seconds: must be positive".to_string());
+        }
+        if self.security.refresh_token_ttl_seconds <= 0 {
+            errors.push("security.refresh_token_ttl_seconds: must be positive".to_string());
+        }
+        if self.publisher.interval_seconds == 0 {
+            errors.push("publisher.interval_seconds: must be positive".to_string());
+        }
+        if self.metrics.token.as_deref().is_some_and(|token| token.trim().is_empty()) {
+            errors.push("metrics.token: must not be empty (remove it to disable metrics)".to_string());
+        }
+        if self.openapi.servers.iter().any(|server| server.url.trim().is_empty()) {
+            errors.push("openapi.servers: url must not be empty".to_string());
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(SettingsError::Validation(errors))
+        }
+    }
+}
+
+/// Couche des variables d'environnement (les variables vides sont ignorées)
+fn env_layer(env: impl Fn(&str) -> Option<String>) -> Result<Value, SettingsError> {
+    let mut layer = Value::Object(Map::new());
+
+    for (name, key, kind) in ENV_OVERRIDES {
+        let Some(raw) = env(name).filter(|value| !value.is_empty()) else {
+            continue;
+        };
+        let value = match kind {
+            EnvKind::String => Value::String(raw),
+            EnvKind::Integer => raw.trim().parse::<i64>().map(Value::from).map_err(|_| {
+                SettingsError::InvalidEnv {
+                    name: name.to_string(),
+                    value: raw.clone(),
+                }
+            })?,
+        };
+
+        let (section, field) = key.split_once('.').expect("keys are section.field");
+        layer[section][field] = value;
+    }
+
+    Ok(layer)
+}
+
+/// Fichier TOML converti en JSON, pour fusionner les couches (JSON Merge Patch)
+fn parse_toml(path: &str, content: &str) -> Result<Value, SettingsError> {
+    let document: DocumentMut = content.parse().map_err(|err: toml_edit::TomlError| {
+        SettingsError::Syntax {
+            path: path.to_string(),
+            message: err.message().to_string(),
+        }
+    })?;
+
+    Ok(item_to_json(document.as_item()))
+}
+
+fn item_to_json(item: &Item) -> Value {
+    match item {
+        Item::None => Value::Null,
+        Item::Value(value) => value_to_json(value),
+        Item::Table(table) => table
+            .iter()
+            .map(|(key, item)| (key.to_string(), item_to_json(item)))
+            .collect(),
+        Item::ArrayOfTables(tables) => tables
+            .iter()
+            .map(|table| {
+                table
+                    .iter()
+                    .map(|(key, item)| (key.to_string(), item_to_json(item)))
+                    .collect::<Value>()
+            })
+            .collect(),
+    }
+}
+
+fn value_to_json(value: &toml_edit::Value) -> Value {
+    use toml_edit::Value as Toml;
+
+    match value {
+        Toml::String(string) => Value::String(string.value().clone()),
+        Toml::Integer(integer) => Value::from(*integer.value()),
+        Toml::Float(float) => Value::from(*float.value()),
+        Toml::Boolean(boolean) => Value::Bool(*boolean.value()),
+        Toml::Datetime(datetime) => Value::String(datetime.value().to_string()),
+        Toml::Array(array) => array.iter().map(value_to_json).collect(),
+        Toml::InlineTable(table) => table
+            .iter()
+            .map(|(key, value)| (key.to_string(), value_to_json(value)))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn load(profile: Profile, vars: &[(&str, &str)]) -> Result<Settings, SettingsError> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        Settings::load_with(profile, None, |name| vars.get(name).cloned())
+    }
+
+    #[test]
+    fn test_profiles_are_layered_over_defaults() {
+        let dev = Settings::for_profile(Profile::Dev).unwrap();
+        assert_eq!(dev.server.addr, "0.0.0.0:8080");
+        assert_eq!(dev.log.format, LogFormat::Pretty);
+        assert_eq!(dev.openapi.servers.len(), 2);
+        assert!(dev.metrics.token.is_none());
+
+        let test = Settings::for_profile(Profile::Test).unwrap();
+        assert_eq!(test.database.url, "sqlite::memory:");
+        assert_eq!(test.security.jwt_ttl_seconds, 3600);
+
+        let prod = load(Profile::Prod, &[("JWT_SECRET", "a-real-secret")]).unwrap();
+        assert_eq!(prod.log.format, LogFormat::Json);
+        assert_eq!(prod.shutdown.delay(), Duration::from_secs(5));
+        assert_eq!(prod.openapi.servers.len(), 1);
+    }
+
+    #[test]
+    fn test_environment_overrides_files() {
+        let settings = load(
+            Profile::Dev,
+            &[
+                ("SERVER_ADDR", "127.0.0.1:3000"),
+                ("LOG_FORMAT", "json"),
+                ("JWT_TTL_SECONDS", "60"),
+                ("METRICS_TOKEN", "scrape-me"),
+                ("PUBLISHER_INTERVAL_SECONDS", ""),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(settings.server.addr, "127.0.0.1:3000");
+        assert_eq!(settings.log.format, LogFormat::Json);
+        assert_eq!(settings.security.jwt_ttl_seconds, 60);
+        assert_eq!(settings.metrics.token.as_deref(), Some("scrape-me"));
+        assert_eq!(settings.publisher.interval(), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_invalid_values_are_reported_clearly() {
+        let err = load(Profile::Dev, &[("JWT_TTL_SECONDS", "1h")]).unwrap_err();
+        assert_eq!(err.to_string(), "JWT_TTL_SECONDS: expected an integer, got '1h'");
+
+        let err = load(Profile::Dev, &[("LOG_FORMAT", "xml")]).unwrap_err();
+        assert!(err.to_string().starts_with("log.format: unknown variant `xml`"));
+
+        let err = load(
+            Profile::Dev,
+            &[("SERVER_ADDR", "localhost"), ("PUBLISHER_INTERVAL_SECONDS", "0")],
+        )
+        .unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("server.addr: 'localhost' is not a socket address"));
+        assert!(message.contains("publisher.interval_seconds: must be positive"));
+    }
+
+    #[test]
+    fn test_prod_requires_a_jwt_secret() {
+        let err = Settings::for_profile(Profile::Prod).unwrap_err();
+        assert!(err.to_string().contains("security.jwt_secret: required (set JWT_SECRET)"));
+
+        let err = load(Profile::Prod, &[("JWT_SECRET", DEV_JWT_SECRET)]).unwrap_err();
+        assert!(err.to_string().contains("not allowed in prod"));
+    }
+
+    #[test]
+    fn test_unknown_keys_are_rejected() {
+        let layer = parse_toml("custom.toml", "[database]\nurll = \"sqlite::memory:\"").unwrap();
+        let mut merged = parse_toml("config/default.toml", DEFAULT_TOML).unwrap();
+        json_patch::merge(&mut merged, &layer);
+
+        let err = serde_path_to_error::deserialize::<_, Settings>(merged).unwrap_err();
+        assert_eq!(err.path().to_string(), "database.urll");
+    }
+
+    #[test]
+    fn test_profile_names() {
+        assert_eq!(Profile::parse("prod").unwrap(), Profile::Prod);
+        assert!(matches!(Profile::parse("staging"), Err(SettingsError::UnknownProfile(_))));
+    }
+}
@@ -0,0 +1,104 @@
+// src/controllers/api_key_controller.rs
+// Gestion des clés d'API de l'utilisateur courant
+
+use axum::{
+    extract::State,
+    http::StatusCode,
+    routing::{delete, get, post},
+    Router,
+};
+use std::sync::Arc;
+
+use crate::auth::AuthUser;
+use crate::config::AppState;
+use crate::dto::{ApiKeyResponse, CreateApiKeyDto, CreatedApiKeyResponse};
+use crate::error::{ApiResult, ProblemDetails};
+use crate::response::{ApiResponse, ApiResponseBuilder};
+use crate::validation::{ValidatedJson, ValidatedPath};
+
+/// ApiKeyController - API keys of the current user
+/// Keys can't manage keys: these routes require a bearer token
+pub struct ApiKeyController;
+
+impl ApiKeyController {
+    /// Register routes for this controller
+    pub fn routes() -> Router<Arc<AppState>> {
+        Router::new()
+            .route("/auth/api-keys", get(list_api_keys))
+            .route("/auth/api-keys", post(create_api_key))
+            .route("/auth/api-keys/:id", delete(revoke_api_key))
+    }
+}
+
+/// GET /auth/api-keys - List the current user's API keys
+/// Response: { "data": [...] }
+#[utoipa::path(
+    get,
+    path = "/auth/api-keys",
+    tag = "auth",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "API keys of the current user", body = inline(ApiResponse<Vec<ApiKeyResponse>>)),
+        (status = 401, description = "Missing or invalid token", body = ProblemDetails),
+        (status = 500, description = "Internal server error", body = ProblemDetails)
+    )
+)]
+async fn list_api_keys(
+    State(state): State<Arc<AppState>>,
+    AuthUser(user): AuthUser,
+) -> ApiResult<ApiResponse<Vec<ApiKeyResponse>>> {
+    let keys = state.api_key_service.find_by_user(user.id).await?;
+    Ok(ApiResponseBuilder::list(keys.into_iter().map(Into::into).collect()))
+}
+
+/// POST /auth/api-keys - Mint a new API key
+/// The secret is returned once, in `data.key`
+#[utoipa::path(
+    post,
+    path = "/auth/api-keys",
+    tag = "auth",
+    request_body = CreateApiKeyDto,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 201, description = "API key created", body = inline(ApiResponse<CreatedApiKeyResponse>)),
+        (status = 401, description = "Missing or invalid token", body = ProblemDetails),
+        (status = 422, description = "Validation error", body = ProblemDetails),
+        (status = 500, description = "Internal server error", body = ProblemDetails)
+    )
+)]
+async fn create_api_key(
+    State(state): State<Arc<AppState>>,
+    AuthUser(user): AuthUser,
+    ValidatedJson(dto): ValidatedJson<CreateApiKeyDto>,
+) -> ApiResult<(StatusCode, ApiResponse<CreatedApiKeyResponse>)> {
+    let minted = state.api_key_service.create(&user, dto).await?;
+    Ok(ApiResponseBuilder::created(CreatedApiKeyResponse {
+        key: minted.secret,
+        api_key: minted.api_key.into(),
+    }))
+}
+
+/// DELETE /auth/api-keys/:id - Revoke an API key
+#[utoipa::path(
+    delete,
+    path = "/auth/api-keys/{id}",
+    tag = "auth",
+    params(
+        ("id" = i32, Path, description = "API key ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 204, description = "API key revoked"),
+        (status = 401, description = "Missing or invalid token", body = ProblemDetails),
+        (status = 404, description = "API key not found", body = ProblemDetails),
+        (status = 500, description = "Internal server error", body = ProblemDetails)
+    )
+)]
+async fn revoke_api_key(
+    State(state): State<Arc<AppState>>,
+    ValidatedPath(id): ValidatedPath<i32>,
+    AuthUser(user): AuthUser,
+) -> ApiResult<StatusCode> {
+    state.api_key_service.revoke(id, &user).await?;
+    Ok(ApiResponseBuilder::no_content())
+}
@@ -0,0 +1,192 @@
+// src/controllers/auth_controller.rs
+// Equivalent de: src/Controller/SecurityController.php
+
+use axum::{
+    extract::State,
+    http::StatusCode,
+    routing::{get, post, put},
+    Router,
+};
+use std::sync::Arc;
+
+use crate::auth::AuthUser;
+use crate::config::AppState;
+use crate::dto::{ChangePasswordDto, LoginDto, RefreshTokenDto, TokenResponse, UserResponse};
+use crate::entities::user;
+use crate::error::{ApiError, ApiResult, ProblemDetails};
+use crate::response::{ApiResponse, ApiResponseBuilder};
+use crate::validation::ValidatedJson;
+
+/// AuthController - Authentication endpoints
+pub struct AuthController;
+
+impl AuthController {
+    /// Register routes for this controller
+    pub fn routes() -> Router<Arc<AppState>> {
+        Router::new()
+            .route("/auth/login", post(login))
+            .route("/auth/refresh", post(refresh))
+            .route("/auth/logout", post(logout))
+            .route("/auth/logout-all", post(logout_all))
+            .route("/auth/me", get(me))
+            .route("/auth/password", put(change_password))
+    }
+}
+
+/// POST /auth/login - Exchange credentials for an access token and a refresh token
+/// Response: { "data": { "access_token": "...", "refresh_token": "...", "expires_in": 3600, ... } }
+#[utoipa::path(
+    post,
+    path = "/auth/login",
+    tag = "auth",
+    request_body = LoginDto,
+    responses(
+        (status = 200, description = "Authenticated", body = inline(ApiResponse<TokenResponse>)),
+        (status = 401, description = "Invalid credentials", body = ProblemDetails),
+        (status = 422, description = "Validation error", body = ProblemDetails),
+        (status = 500, description = "Internal server error", body = ProblemDetails)
+    )
+)]
+async fn login(
+    State(state): State<Arc<AppState>>,
+    ValidatedJson(dto): ValidatedJson<LoginDto>,
+) -> ApiResult<ApiResponse<TokenResponse>> {
+    let user = state
+        .user_service
+        .authenticate(&dto.email, &dto.password)
+        .await?;
+
+    let refresh_token = state.refresh_token_service.issue(&user).await?;
+
+    issue_tokens(&state, &user, refresh_token)
+}
+
+/// POST /auth/refresh - Rotate a refresh token and get a new access token
+/// The presented refresh token can't be used again: replaying it revokes the session
+#[utoipa::path(
+    post,
+    path = "/auth/refresh",
+    tag = "auth",
+    request_body = RefreshTokenDto,
+    responses(
+        (status = 200, description = "Tokens rotated", body = inline(ApiResponse<TokenResponse>)),
+        (status = 401, description = "Unknown, expired, revoked or reused refresh token", body = ProblemDetails),
+        (status = 422, description = "Validation error", body = ProblemDetails),
+        (status = 500, description = "Internal server error", body = ProblemDetails)
+    )
+)]
+async fn refresh(
+    State(state): State<Arc<AppState>>,
+    ValidatedJson(dto): ValidatedJson<RefreshTokenDto>,
+) -> ApiResult<ApiResponse<TokenResponse>> {
+    let (user, refresh_token) = state
+        .refresh_token_service
+        .rotate(&dto.refresh_token)
+        .await?;
+
+    issue_tokens(&state, &user, refresh_token)
+}
+
+/// POST /auth/logout - Revoke the session of a refresh token
+/// Access tokens already issued stay valid until they expire
+#[utoipa::path(
+    post,
+    path = "/auth/logout",
+    tag = "auth",
+    request_body = RefreshTokenDto,
+    responses(
+        (status = 204, description = "Session revoked"),
+        (status = 422, description = "Validation error", body = ProblemDetails),
+        (status = 500, description = "Internal server error", body = ProblemDetails)
+    )
+)]
+async fn logout(
+    State(state): State<Arc<AppState>>,
+    ValidatedJson(dto): ValidatedJson<RefreshTokenDto>,
+) -> ApiResult<StatusCode> {
+    state.refresh_token_service.revoke(&dto.refresh_token).await?;
+    Ok(ApiResponseBuilder::no_content())
+}
+
+/// POST /auth/logout-all - Revoke every session of the current user
+#[utoipa::path(
+    post,
+    path = "/auth/logout-all",
+    tag = "auth",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 204, description = "All sessions revoked"),
+        (status = 401, description = "Missing or invalid token", body = ProblemDetails),
+        (status = 500, description = "Internal server error", body = ProblemDetails)
+    )
+)]
+async fn logout_all(
+    State(state): State<Arc<AppState>>,
+    AuthUser(user): AuthUser,
+) -> ApiResult<StatusCode> {
+    state.refresh_token_service.revoke_all(user.id).await?;
+    Ok(ApiResponseBuilder::no_content())
+}
+
+/// GET /auth/me - Current authenticated user
+/// Response: { "data": { ... } }
+#[utoipa::path(
+    get,
+    path = "/auth/me",
+    tag = "auth",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Current user", body = inline(ApiResponse<UserResponse>)),
+        (status = 401, description = "Missing or invalid token", body = ProblemDetails)
+    )
+)]
+async fn me(AuthUser(user): AuthUser) -> ApiResult<ApiResponse<UserResponse>> {
+    Ok(ApiResponseBuilder::one(user.into()))
+}
+
+/// PUT /auth/password - Change the current user's password
+/// Every session (refresh token) of the user is revoked
+#[utoipa::path(
+    put,
+    path = "/auth/password",
+    tag = "auth",
+    request_body = ChangePasswordDto,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 204, description = "Password changed"),
+        (status = 401, description = "Missing token or wrong current password", body = ProblemDetails),
+        (status = 422, description = "Validation error", body = ProblemDetails),
+        (status = 500, description = "Internal server error", body = ProblemDetails)
+    )
+)]
+async fn change_password(
+    State(state): State<Arc<AppState>>,
+    AuthUser(user): AuthUser,
+    ValidatedJson(dto): ValidatedJson<ChangePasswordDto>,
+) -> ApiResult<StatusCode> {
+    let user_id = user.id;
+    state
+        .user_service
+        .change_password(user, &dto.current_password, &dto.new_password)
+        .await?;
+    state.refresh_token_service.revoke_all(user_id).await?;
+    Ok(ApiResponseBuilder::no_content())
+}
+
+/// Signe un access token et l'associe au refresh token
+fn issue_tokens(
+    state: &AppState,
+    user: &user::Model,
+    refresh_token: String,
+) -> ApiResult<ApiResponse<TokenResponse>> {
+    let access_token = state
+        .jwt_service
+        .issue(user)
+        .map_err(|err| ApiError::internal(format!("Token signing failed: {err}")))?;
+
+    Ok(ApiResponseBuilder::one(TokenResponse::bearer(
+        access_token,
+        refresh_token,
+        state.jwt_service.ttl_seconds(),
+    )))
+}
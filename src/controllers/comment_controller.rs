@@ -0,0 +1,183 @@
+// src/controllers/comment_controller.rs
+// Controller pour les commentaires d'un post
+
+use axum::{
+    extract::State,
+    http::StatusCode,
+    routing::{delete, get, post, put},
+    Router,
+};
+use std::sync::Arc;
+
+use crate::auth::{scope, ScopedUser};
+use crate::config::AppState;
+use crate::dto::{CommentResponse, CreateCommentDto, PaginationQuery, UpdateCommentDto};
+use crate::error::{ApiResult, ProblemDetails};
+use crate::response::{ApiResponse, ApiResponseBuilder, PaginatedResponse};
+use crate::services::CommentWithAuthor;
+use crate::validation::{ValidatedJson, ValidatedPath, ValidatedQuery};
+
+pub struct CommentController;
+
+impl CommentController {
+    pub fn routes() -> Router<Arc<AppState>> {
+        Router::new()
+            .route("/posts/:id/comments", get(list_comments))
+            .route("/posts/:id/comments", post(create_comment))
+            .route("/posts/:id/comments/:comment_id", get(get_comment))
+            .route("/posts/:id/comments/:comment_id", put(update_comment))
+            .route("/posts/:id/comments/:comment_id", delete(delete_comment))
+    }
+}
+
+impl From<CommentWithAuthor> for CommentResponse {
+    fn from(cwa: CommentWithAuthor) -> Self {
+        CommentResponse::from_comment_with_author(cwa.comment, cwa.author)
+    }
+}
+
+/// GET /posts/:id/comments - Liste paginée des commentaires d'un post
+#[utoipa::path(
+    get,
+    path = "/posts/{id}/comments",
+    tag = "comments",
+    params(
+        ("id" = i32, Path, description = "Post ID"),
+        PaginationQuery
+    ),
+    responses(
+        (status = 200, description = "Liste paginée des commentaires", body = inline(PaginatedResponse<CommentResponse>)),
+        (status = 404, description = "Post non trouvé", body = ProblemDetails),
+        (status = 500, description = "Erreur serveur", body = ProblemDetails)
+    )
+)]
+async fn list_comments(
+    State(state): State<Arc<AppState>>,
+    ValidatedPath(post_id): ValidatedPath<i32>,
+    ValidatedQuery(pagination): ValidatedQuery<PaginationQuery>,
+) -> ApiResult<PaginatedResponse<CommentResponse>> {
+    let result = state.comment_service.find_by_post(post_id, &pagination).await?;
+    let comments: Vec<CommentResponse> = result.comments.into_iter().map(Into::into).collect();
+
+    Ok(ApiResponseBuilder::paginated(
+        comments,
+        result.total,
+        pagination.page,
+        pagination.per_page,
+    ))
+}
+
+/// GET /posts/:id/comments/:comment_id - Détail d'un commentaire
+#[utoipa::path(
+    get,
+    path = "/posts/{id}/comments/{comment_id}",
+    tag = "comments",
+    params(
+        ("id" = i32, Path, description = "Post ID"),
+        ("comment_id" = i32, Path, description = "Comment ID")
+    ),
+    responses(
+        (status = 200, description = "Commentaire trouvé", body = inline(ApiResponse<CommentResponse>)),
+        (status = 404, description = "Commentaire non trouvé", body = ProblemDetails),
+        (status = 500, description = "Erreur serveur", body = ProblemDetails)
+    )
+)]
+async fn get_comment(
+    State(state): State<Arc<AppState>>,
+    ValidatedPath((post_id, id)): ValidatedPath<(i32, i32)>,
+) -> ApiResult<ApiResponse<CommentResponse>> {
+    let result = state.comment_service.find_by_id(post_id, id).await?;
+    Ok(ApiResponseBuilder::one(result.into()))
+}
+
+/// POST /posts/:id/comments - Commenter un post (ou répondre avec `parent_id`)
+///
+/// Le post doit être publié (sinon 409) et accepter les commentaires
+/// (`metadata.settings.allow_comments`, sinon 403).
+#[utoipa::path(
+    post,
+    path = "/posts/{id}/comments",
+    tag = "comments",
+    params(
+        ("id" = i32, Path, description = "Post ID")
+    ),
+    request_body = CreateCommentDto,
+    security(("bearer_auth" = []), ("api_key" = ["comments:write"])),
+    responses(
+        (status = 201, description = "Commentaire créé", body = inline(ApiResponse<CommentResponse>)),
+        (status = 400, description = "Parent d'un autre post", body = ProblemDetails),
+        (status = 401, description = "Token manquant ou invalide", body = ProblemDetails),
+        (status = 403, description = "Commentaires fermés sur ce post", body = ProblemDetails),
+        (status = 404, description = "Post non trouvé", body = ProblemDetails),
+        (status = 409, description = "Post non publié", body = ProblemDetails),
+        (status = 422, description = "Erreur de validation", body = ProblemDetails),
+        (status = 500, description = "Erreur serveur", body = ProblemDetails)
+    )
+)]
+async fn create_comment(
+    State(state): State<Arc<AppState>>,
+    ValidatedPath(post_id): ValidatedPath<i32>,
+    ScopedUser(actor, _): ScopedUser<scope::CommentsWrite>,
+    ValidatedJson(dto): ValidatedJson<CreateCommentDto>,
+) -> ApiResult<(StatusCode, ApiResponse<CommentResponse>)> {
+    let result = state.comment_service.create(post_id, dto, &actor).await?;
+    Ok(ApiResponseBuilder::created(result.into()))
+}
+
+/// PUT /posts/:id/comments/:comment_id - Modifier un commentaire (auteur, editor ou admin)
+#[utoipa::path(
+    put,
+    path = "/posts/{id}/comments/{comment_id}",
+    tag = "comments",
+    params(
+        ("id" = i32, Path, description = "Post ID"),
+        ("comment_id" = i32, Path, description = "Comment ID")
+    ),
+    request_body = UpdateCommentDto,
+    security(("bearer_auth" = []), ("api_key" = ["comments:write"])),
+    responses(
+        (status = 200, description = "Commentaire modifié", body = inline(ApiResponse<CommentResponse>)),
+        (status = 401, description = "Token manquant ou invalide", body = ProblemDetails),
+        (status = 403, description = "Ni l'auteur ni un modérateur, ou commentaires fermés", body = ProblemDetails),
+        (status = 404, description = "Commentaire non trouvé", body = ProblemDetails),
+        (status = 409, description = "Post non publié", body = ProblemDetails),
+        (status = 422, description = "Erreur de validation", body = ProblemDetails),
+        (status = 500, description = "Erreur serveur", body = ProblemDetails)
+    )
+)]
+async fn update_comment(
+    State(state): State<Arc<AppState>>,
+    ValidatedPath((post_id, id)): ValidatedPath<(i32, i32)>,
+    ScopedUser(actor, _): ScopedUser<scope::CommentsWrite>,
+    ValidatedJson(dto): ValidatedJson<UpdateCommentDto>,
+) -> ApiResult<ApiResponse<CommentResponse>> {
+    let result = state.comment_service.update(post_id, id, dto, &actor).await?;
+    Ok(ApiResponseBuilder::one(result.into()))
+}
+
+/// DELETE /posts/:id/comments/:comment_id - Supprimer un commentaire et ses réponses
+#[utoipa::path(
+    delete,
+    path = "/posts/{id}/comments/{comment_id}",
+    tag = "comments",
+    params(
+        ("id" = i32, Path, description = "Post ID"),
+        ("comment_id" = i32, Path, description = "Comment ID")
+    ),
+    security(("bearer_auth" = []), ("api_key" = ["comments:write"])),
+    responses(
+        (status = 204, description = "Commentaire supprimé"),
+        (status = 401, description = "Token manquant ou invalide", body = ProblemDetails),
+        (status = 403, description = "Ni l'auteur ni un modérateur", body = ProblemDetails),
+        (status = 404, description = "Commentaire non trouvé", body = ProblemDetails),
+        (status = 500, description = "Erreur serveur", body = ProblemDetails)
+    )
+)]
+async fn delete_comment(
+    State(state): State<Arc<AppState>>,
+    ValidatedPath((post_id, id)): ValidatedPath<(i32, i32)>,
+    ScopedUser(actor, _): ScopedUser<scope::CommentsWrite>,
+) -> ApiResult<StatusCode> {
+    state.comment_service.delete(post_id, id, &actor).await?;
+    Ok(ApiResponseBuilder::no_content())
+}
@@ -1,18 +1,25 @@
 // src/controllers/health_controller.rs
 // Equivalent de: src/Controller/HealthController.php
 
-use axum::{routing::get, Router};
+use axum::{extract::State, http::StatusCode, routing::get, Json, Router};
+use std::sync::Arc;
 use tracing::info;
 
+use crate::config::AppState;
+use crate::dto::{HealthReportResponse, HealthStatus, LivenessResponse};
+
 /// HealthController - Health check endpoints
+/// Les sondes répondent sans enveloppe `data`: seuls le statut HTTP et `status` comptent
 pub struct HealthController;
 
 impl HealthController {
     /// Register routes for this controller
-    pub fn routes() -> Router {
+    pub fn routes() -> Router<Arc<AppState>> {
         Router::new()
             .route("/", get(index))
             .route("/health", get(health))
+            .route("/health/live", get(live))
+            .route("/health/ready", get(ready))
     }
 }
 
@@ -31,6 +38,7 @@ async fn index() -> &'static str {
 }
 
 /// GET /health - Health check
+/// Conservé pour les sondes existantes, équivalent de /health/live
 #[utoipa::path(
     get,
     path = "/health",
@@ -43,3 +51,40 @@ async fn health() -> &'static str {
     info!("Health check endpoint called");
     "OK"
 }
+
+/// GET /health/live - Liveness probe
+/// Le processus répond: aucune dépendance n'est vérifiée (un redémarrage ne réparerait pas la base)
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    tag = "health",
+    responses(
+        (status = 200, description = "Process is alive", body = LivenessResponse)
+    )
+)]
+async fn live() -> Json<LivenessResponse> {
+    Json(LivenessResponse {
+        status: HealthStatus::Up,
+    })
+}
+
+/// GET /health/ready - Readiness probe
+/// Vérifie la base de données et les migrations ; 503 si un check échoue
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "health",
+    responses(
+        (status = 200, description = "Ready to serve traffic", body = HealthReportResponse),
+        (status = 503, description = "A dependency check failed", body = HealthReportResponse)
+    )
+)]
+async fn ready(State(state): State<Arc<AppState>>) -> (StatusCode, Json<HealthReportResponse>) {
+    let report: HealthReportResponse = state.health_service.readiness().await.into();
+    let status = match report.status {
+        HealthStatus::Up => StatusCode::OK,
+        HealthStatus::Down => StatusCode::SERVICE_UNAVAILABLE,
+    };
+
+    (status, Json(report))
+}
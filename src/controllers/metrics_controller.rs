@@ -0,0 +1,45 @@
+// src/controllers/metrics_controller.rs
+// Equivalent de: la route /metrics/prometheus de artprima/prometheus-metrics-bundle
+
+use axum::{
+    extract::State,
+    http::{header::CONTENT_TYPE, HeaderMap},
+    response::IntoResponse,
+    routing::get,
+    Router,
+};
+use std::sync::Arc;
+
+use crate::auth::{bearer_token, hash_secret};
+use crate::error::{ApiError, ApiResult};
+use crate::i18n::Message;
+use crate::metrics::{render, METRICS_CONTENT_TYPE};
+
+/// MetricsController - Prometheus scrape endpoint
+/// Protégé par un jeton dédié (METRICS_TOKEN), distinct des comptes utilisateurs
+pub struct MetricsController;
+
+impl MetricsController {
+    /// Register routes for this controller
+    pub fn routes(token: &str) -> Router {
+        Router::new()
+            .route("/metrics", get(metrics))
+            .with_state(Arc::from(hash_secret(token)))
+    }
+}
+
+/// GET /metrics - Metrics in Prometheus text format
+/// Requires `Authorization: Bearer <METRICS_TOKEN>`
+async fn metrics(
+    State(token_hash): State<Arc<str>>,
+    headers: HeaderMap,
+) -> ApiResult<impl IntoResponse> {
+    // Comparaison des hashs: la durée ne dépend pas du préfixe commun avec le jeton attendu
+    let authorized = bearer_token(&headers)
+        .is_some_and(|token| hash_secret(token) == *token_hash);
+    if !authorized {
+        return Err(ApiError::unauthorized(Message::new("metrics.invalid_token")));
+    }
+
+    Ok(([(CONTENT_TYPE, METRICS_CONTENT_TYPE)], render()))
+}
@@ -1,10 +1,22 @@
 // src/controllers/mod.rs
 // Equivalent de: src/Controller/ en Symfony
 
+pub mod api_key_controller;
+pub mod auth_controller;
+pub mod comment_controller;
 pub mod health_controller;
+pub mod metrics_controller;
 pub mod post_controller;
+pub mod revision_controller;
+pub mod tag_controller;
 pub mod user_controller;
 
+pub use api_key_controller::ApiKeyController;
+pub use auth_controller::AuthController;
+pub use comment_controller::CommentController;
 pub use health_controller::HealthController;
+pub use metrics_controller::MetricsController;
 pub use post_controller::PostController;
+pub use revision_controller::RevisionController;
+pub use tag_controller::TagController;
 pub use user_controller::UserController;
@@ -2,20 +2,27 @@
 // Controller pour les posts avec nested objects
 
 use axum::{
-    extract::{Path, Query, State},
-    http::StatusCode,
-    routing::{delete, get, post, put},
+    extract::State,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    routing::{delete, get, patch, post, put},
     Router,
 };
 use std::sync::Arc;
 
+use crate::auth::{scope, ScopedUser};
 use crate::config::AppState;
 use crate::dto::{
-    CreatePostDto, PaginationQuery, PostListItemResponse, PostResponse, UpdatePostDto,
+    CreatePostDto, CursorQuery, IfMatch, IfNoneMatch, PageRequest, PaginationQuery,
+    PostFilterQuery, PostListItemResponse, PostResponse, PostSearchQuery, PostSearchResultResponse,
+    UpdatePostDto,
 };
-use crate::error::{ApiResult, ErrorResponse};
-use crate::response::{ApiResponse, ApiResponseBuilder, PaginatedResponse};
-use crate::validation::ValidatedJson;
+use crate::error::{ApiResult, ProblemDetails, ServiceError};
+use crate::response::{
+    ApiResponse, ApiResponseBuilder, ListMeta, ListResponse, PaginatedResponse, PaginationMeta,
+};
+use crate::services::{PageInfo, PaginatedPosts, SlugLookup};
+use crate::validation::{JsonPatch, ValidatedJson, ValidatedPath, ValidatedQuery};
 
 pub struct PostController;
 
@@ -24,74 +31,285 @@ impl PostController {
         Router::new()
             .route("/posts", get(list_posts))
             .route("/posts", post(create_post))
+            .route("/posts/published", get(list_published_posts))
+            .route("/posts/search", get(search_posts))
+            .route("/posts/by-slug/:slug", get(get_post_by_slug))
             .route("/posts/:id", get(get_post))
             .route("/posts/:id", put(update_post))
+            .route("/posts/:id", patch(patch_post))
             .route("/posts/:id", delete(delete_post))
+            .route("/users/:id/posts", get(list_user_posts))
+            .route("/tags/:name/posts", get(list_tag_posts))
     }
 }
 
 /// GET /posts - Liste paginée des posts
+/// Filtres et tri: ?published=true&author_id=3&tag=rust&created_after=...&sort=-created_at,title
+/// Mode curseur: ?cursor= puis ?cursor=<meta.next_cursor> (tri fixe, incompatible avec `sort`)
 #[utoipa::path(
     get,
     path = "/posts",
     tag = "posts",
-    params(PaginationQuery),
+    params(PaginationQuery, CursorQuery, PostFilterQuery),
     responses(
-        (status = 200, description = "Liste paginée des posts", body = inline(PaginatedResponse<PostListItemResponse>)),
-        (status = 500, description = "Erreur serveur", body = ErrorResponse)
+        (status = 200, description = "Liste paginée des posts", body = inline(ListResponse<PostListItemResponse>)),
+        (status = 400, description = "Paramètre de requête ou curseur invalide", body = ProblemDetails),
+        (status = 422, description = "Filtre ou tri invalide", body = ProblemDetails),
+        (status = 500, description = "Erreur serveur", body = ProblemDetails)
     )
 )]
 async fn list_posts(
     State(state): State<Arc<AppState>>,
-    Query(pagination): Query<PaginationQuery>,
-) -> ApiResult<PaginatedResponse<PostListItemResponse>> {
-    let result = state.post_service.find_all(&pagination).await?;
+    ValidatedQuery(pagination): ValidatedQuery<PaginationQuery>,
+    ValidatedQuery(cursor): ValidatedQuery<CursorQuery>,
+    ValidatedQuery(filter): ValidatedQuery<PostFilterQuery>,
+) -> ApiResult<ListResponse<PostListItemResponse>> {
+    let page = page_request(&pagination, &cursor)?;
+    let result = state.post_service.find_all(&page, &filter).await?;
+    paginated_list(&state, result).await
+}
 
-    let posts: Vec<PostListItemResponse> = result
-        .posts
+/// GET /posts/published - Liste paginée des posts publiés uniquement
+#[utoipa::path(
+    get,
+    path = "/posts/published",
+    tag = "posts",
+    params(PaginationQuery, CursorQuery),
+    responses(
+        (status = 200, description = "Liste paginée des posts publiés", body = inline(ListResponse<PostListItemResponse>)),
+        (status = 400, description = "Curseur invalide", body = ProblemDetails),
+        (status = 500, description = "Erreur serveur", body = ProblemDetails)
+    )
+)]
+async fn list_published_posts(
+    State(state): State<Arc<AppState>>,
+    ValidatedQuery(pagination): ValidatedQuery<PaginationQuery>,
+    ValidatedQuery(cursor): ValidatedQuery<CursorQuery>,
+) -> ApiResult<ListResponse<PostListItemResponse>> {
+    let page = page_request(&pagination, &cursor)?;
+    let result = state.post_service.find_published(&page).await?;
+    paginated_list(&state, result).await
+}
+
+/// GET /posts/search?q= - Recherche plein texte (titre, contenu, tags, mots-clés SEO)
+/// Résultats triés par pertinence, avec score et extrait surligné
+#[utoipa::path(
+    get,
+    path = "/posts/search",
+    tag = "posts",
+    params(PostSearchQuery, PaginationQuery),
+    responses(
+        (status = 200, description = "Posts trouvés, les plus pertinents d'abord", body = inline(PaginatedResponse<PostSearchResultResponse>)),
+        (status = 400, description = "Paramètre q manquant ou sans mot", body = ProblemDetails),
+        (status = 422, description = "Paramètre q invalide", body = ProblemDetails),
+        (status = 500, description = "Erreur serveur", body = ProblemDetails)
+    )
+)]
+async fn search_posts(
+    State(state): State<Arc<AppState>>,
+    ValidatedQuery(search): ValidatedQuery<PostSearchQuery>,
+    ValidatedQuery(pagination): ValidatedQuery<PaginationQuery>,
+) -> ApiResult<PaginatedResponse<PostSearchResultResponse>> {
+    let result = state.search_service.search_posts(&search.q, &pagination).await?;
+
+    let post_ids: Vec<i32> = result.hits.iter().map(|hit| hit.post.post.id).collect();
+    let comment_counts = state.comment_service.count_by_posts(&post_ids).await?;
+
+    let hits: Vec<PostSearchResultResponse> = result
+        .hits
         .into_iter()
-        .map(|pwa| PostListItemResponse::from_post_with_author(pwa.post, pwa.author))
+        .map(|hit| {
+            let comment_count = comment_counts.get(&hit.post.post.id).copied().unwrap_or(0);
+            PostSearchResultResponse {
+                post: PostListItemResponse::from_post_with_author(
+                    hit.post.post,
+                    hit.post.author,
+                    comment_count,
+                ),
+                score: hit.score,
+                snippet: hit.snippet,
+            }
+        })
         .collect();
 
     Ok(ApiResponseBuilder::paginated(
-        posts,
+        hits,
         result.total,
         pagination.page,
         pagination.per_page,
     ))
 }
 
+/// GET /users/:id/posts - Liste paginée des posts d'un auteur
+#[utoipa::path(
+    get,
+    path = "/users/{id}/posts",
+    tag = "posts",
+    params(
+        ("id" = i32, Path, description = "Author (user) ID"),
+        PaginationQuery,
+        CursorQuery
+    ),
+    responses(
+        (status = 200, description = "Liste paginée des posts de l'auteur", body = inline(ListResponse<PostListItemResponse>)),
+        (status = 400, description = "Curseur invalide", body = ProblemDetails),
+        (status = 404, description = "Auteur non trouvé", body = ProblemDetails),
+        (status = 500, description = "Erreur serveur", body = ProblemDetails)
+    )
+)]
+async fn list_user_posts(
+    State(state): State<Arc<AppState>>,
+    ValidatedPath(author_id): ValidatedPath<i32>,
+    ValidatedQuery(pagination): ValidatedQuery<PaginationQuery>,
+    ValidatedQuery(cursor): ValidatedQuery<CursorQuery>,
+) -> ApiResult<ListResponse<PostListItemResponse>> {
+    let page = page_request(&pagination, &cursor)?;
+    let result = state.post_service.find_by_author(author_id, &page).await?;
+    paginated_list(&state, result).await
+}
+
+/// GET /tags/:name/posts - Liste paginée des posts portant un tag
+#[utoipa::path(
+    get,
+    path = "/tags/{name}/posts",
+    tag = "tags",
+    params(
+        ("name" = String, Path, description = "Tag name"),
+        PaginationQuery,
+        CursorQuery
+    ),
+    responses(
+        (status = 200, description = "Liste paginée des posts du tag", body = inline(ListResponse<PostListItemResponse>)),
+        (status = 400, description = "Curseur invalide", body = ProblemDetails),
+        (status = 404, description = "Tag non trouvé", body = ProblemDetails),
+        (status = 500, description = "Erreur serveur", body = ProblemDetails)
+    )
+)]
+async fn list_tag_posts(
+    State(state): State<Arc<AppState>>,
+    ValidatedPath(name): ValidatedPath<String>,
+    ValidatedQuery(pagination): ValidatedQuery<PaginationQuery>,
+    ValidatedQuery(cursor): ValidatedQuery<CursorQuery>,
+) -> ApiResult<ListResponse<PostListItemResponse>> {
+    let page = page_request(&pagination, &cursor)?;
+    let result = state.post_service.find_by_tag(&name, &page).await?;
+    paginated_list(&state, result).await
+}
+
+/// Mode offset (?page=) ou curseur (?cursor=) ; un curseur illisible donne 400
+fn page_request(pagination: &PaginationQuery, cursor: &CursorQuery) -> ApiResult<PageRequest> {
+    Ok(PageRequest::new(pagination, cursor).map_err(ServiceError::InvalidInput)?)
+}
+
+/// Transforme une page de posts en réponse liste (avec le nombre de commentaires)
+async fn paginated_list(
+    state: &AppState,
+    result: PaginatedPosts,
+) -> ApiResult<ListResponse<PostListItemResponse>> {
+    let post_ids: Vec<i32> = result.posts.iter().map(|pwa| pwa.post.id).collect();
+    let comment_counts = state.comment_service.count_by_posts(&post_ids).await?;
+
+    let posts: Vec<PostListItemResponse> = result
+        .posts
+        .into_iter()
+        .map(|pwa| {
+            let comment_count = comment_counts.get(&pwa.post.id).copied().unwrap_or(0);
+            PostListItemResponse::from_post_with_author(pwa.post, pwa.author, comment_count)
+        })
+        .collect();
+
+    let response = match result.page_info {
+        PageInfo::Offset { total, page, per_page } => {
+            let meta = PaginationMeta::new(total, page, per_page);
+            ApiResponse::with_meta(posts, ListMeta::Page(meta))
+        }
+        PageInfo::Cursor { per_page, next, prev } => ApiResponseBuilder::cursor_paginated(
+            posts,
+            per_page,
+            next.map(|cursor| cursor.encode()),
+            prev.map(|cursor| cursor.encode()),
+        ),
+    };
+
+    Ok(response)
+}
+
 /// GET /posts/:id - Détail d'un post avec nested objects
+/// Header ETag (version du post) ; 304 si If-None-Match contient la version courante
 #[utoipa::path(
     get,
     path = "/posts/{id}",
     tag = "posts",
     params(
-        ("id" = i32, Path, description = "Post ID")
+        ("id" = i32, Path, description = "Post ID"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag d'une version en cache")
     ),
     responses(
-        (status = 200, description = "Post trouvé", body = inline(ApiResponse<PostResponse>)),
-        (status = 404, description = "Post non trouvé", body = ErrorResponse),
-        (status = 500, description = "Erreur serveur", body = ErrorResponse)
+        (status = 200, description = "Post trouvé", body = inline(ApiResponse<PostResponse>),
+            headers(("ETag" = String, description = "Version du post"))),
+        (status = 304, description = "La version en cache est à jour"),
+        (status = 404, description = "Post non trouvé", body = ProblemDetails),
+        (status = 500, description = "Erreur serveur", body = ProblemDetails)
     )
 )]
 async fn get_post(
     State(state): State<Arc<AppState>>,
-    Path(id): Path<i32>,
-) -> ApiResult<ApiResponse<PostResponse>> {
+    ValidatedPath(id): ValidatedPath<i32>,
+    if_none_match: IfNoneMatch,
+) -> ApiResult<Response> {
     let result = state.post_service.find_by_id(id).await?;
+    let version = result.post.version;
+
+    if if_none_match.matches(version) {
+        return Ok(ApiResponseBuilder::not_modified(version));
+    }
     let response = PostResponse::from_post_with_author(result.post, result.author);
-    Ok(ApiResponseBuilder::one(response))
+    Ok(ApiResponseBuilder::one_with_etag(response, version))
+}
+
+/// GET /posts/by-slug/:slug - Détail d'un post par son slug
+/// Un ancien slug (titre modifié depuis) redirige (301) vers le slug courant
+#[utoipa::path(
+    get,
+    path = "/posts/by-slug/{slug}",
+    tag = "posts",
+    params(
+        ("slug" = String, Path, description = "Post slug")
+    ),
+    responses(
+        (status = 200, description = "Post trouvé", body = inline(ApiResponse<PostResponse>)),
+        (status = 301, description = "Ancien slug, voir le header Location",
+            headers(("Location" = String, description = "URL du slug courant"))),
+        (status = 404, description = "Post non trouvé", body = ProblemDetails),
+        (status = 500, description = "Erreur serveur", body = ProblemDetails)
+    )
+)]
+async fn get_post_by_slug(
+    State(state): State<Arc<AppState>>,
+    ValidatedPath(slug): ValidatedPath<String>,
+) -> ApiResult<Response> {
+    match state.post_service.find_by_slug(&slug).await? {
+        SlugLookup::Found(result) => {
+            let response = PostResponse::from_post_with_author(result.post, result.author);
+            Ok(ApiResponseBuilder::one(response).into_response())
+        }
+        SlugLookup::Moved(current) => {
+            let location = format!("/posts/by-slug/{current}");
+            Ok((StatusCode::MOVED_PERMANENTLY, [(header::LOCATION, location)]).into_response())
+        }
+    }
 }
 
 /// POST /posts - Créer un post avec nested objects
 ///
+/// L'auteur est l'utilisateur authentifié. `author_id` n'est accepté que
+/// s'il correspond à cet utilisateur, ou si celui-ci est admin.
+///
 /// # Exemple de body:
 /// ```json
 /// {
 ///     "title": "Mon article",
 ///     "content": "Contenu de l'article...",
-///     "author_id": 1,
 ///     "published": false,
 ///     "metadata": {
 ///         "tags": [
@@ -116,46 +334,133 @@ async fn get_post(
     path = "/posts",
     tag = "posts",
     request_body = CreatePostDto,
+    security(("bearer_auth" = []), ("api_key" = ["posts:write"])),
     responses(
         (status = 201, description = "Post créé", body = inline(ApiResponse<PostResponse>)),
-        (status = 404, description = "Auteur non trouvé", body = ErrorResponse),
-        (status = 422, description = "Erreur de validation", body = ErrorResponse),
-        (status = 500, description = "Erreur serveur", body = ErrorResponse)
+        (status = 401, description = "Token manquant ou invalide", body = ProblemDetails),
+        (status = 403, description = "Publication au nom d'un autre (admin)", body = ProblemDetails),
+        (status = 404, description = "Auteur non trouvé", body = ProblemDetails),
+        (status = 409, description = "Slug déjà utilisé", body = ProblemDetails),
+        (status = 422, description = "Erreur de validation", body = ProblemDetails),
+        (status = 500, description = "Erreur serveur", body = ProblemDetails)
     )
 )]
 async fn create_post(
     State(state): State<Arc<AppState>>,
+    ScopedUser(actor, _): ScopedUser<scope::PostsWrite>,
     ValidatedJson(dto): ValidatedJson<CreatePostDto>,
 ) -> ApiResult<(StatusCode, ApiResponse<PostResponse>)> {
-    let result = state.post_service.create(dto).await?;
+    let result = state.post_service.create(dto, &actor).await?;
     let response = PostResponse::from_post_with_author(result.post, result.author);
     Ok(ApiResponseBuilder::created(response))
 }
 
 /// PUT /posts/:id - Modifier un post
+/// Avec `If-Match: <ETag lu>`, 412 si le post a été modifié entre-temps (pas d'écrasement)
 #[utoipa::path(
     put,
     path = "/posts/{id}",
     tag = "posts",
     params(
-        ("id" = i32, Path, description = "Post ID")
+        ("id" = i32, Path, description = "Post ID"),
+        ("If-Match" = Option<String>, Header, description = "ETag lu par le client")
     ),
     request_body = UpdatePostDto,
+    security(("bearer_auth" = []), ("api_key" = ["posts:write"])),
     responses(
-        (status = 200, description = "Post modifié", body = inline(ApiResponse<PostResponse>)),
-        (status = 404, description = "Post non trouvé", body = ErrorResponse),
-        (status = 422, description = "Erreur de validation", body = ErrorResponse),
-        (status = 500, description = "Erreur serveur", body = ErrorResponse)
+        (status = 200, description = "Post modifié", body = inline(ApiResponse<PostResponse>),
+            headers(("ETag" = String, description = "Nouvelle version du post"))),
+        (status = 401, description = "Token manquant ou invalide", body = ProblemDetails),
+        (status = 403, description = "Ni l'auteur, ni editor/admin", body = ProblemDetails),
+        (status = 404, description = "Post non trouvé", body = ProblemDetails),
+        (status = 412, description = "Post modifié depuis sa lecture", body = ProblemDetails),
+        (status = 422, description = "Erreur de validation", body = ProblemDetails),
+        (status = 500, description = "Erreur serveur", body = ProblemDetails)
     )
 )]
 async fn update_post(
     State(state): State<Arc<AppState>>,
-    Path(id): Path<i32>,
+    ValidatedPath(id): ValidatedPath<i32>,
+    ScopedUser(actor, _): ScopedUser<scope::PostsWrite>,
+    if_match: IfMatch,
     ValidatedJson(dto): ValidatedJson<UpdatePostDto>,
-) -> ApiResult<ApiResponse<PostResponse>> {
-    let result = state.post_service.update(id, dto).await?;
+) -> ApiResult<Response> {
+    let result = state.post_service.update(id, dto, &actor, &if_match).await?;
+    let version = result.post.version;
+    let response = PostResponse::from_post_with_author(result.post, result.author);
+    Ok(ApiResponseBuilder::one_with_etag(response, version))
+}
+
+/// PATCH /posts/:id - Modifier partiellement un post
+///
+/// Body: JSON Merge Patch (`application/merge-patch+json`, `null` supprime un champ)
+/// ou JSON Patch (`application/json-patch+json`). Le patch s'applique au document
+/// `{title, content, status, publish_at, metadata}` du post, puis le résultat est
+/// validé comme le body du PUT.
+///
+/// # Exemple de body (merge patch):
+/// ```json
+/// {
+///     "metadata": {
+///         "seo": { "meta_title": null },
+///         "settings": { "featured": true }
+///     }
+/// }
+/// ```
+#[utoipa::path(
+    patch,
+    path = "/posts/{id}",
+    tag = "posts",
+    params(
+        ("id" = i32, Path, description = "Post ID"),
+        ("If-Match" = Option<String>, Header, description = "ETag lu par le client")
+    ),
+    request_body(
+        description = "Modifications du post (title, content, status, publish_at, metadata)",
+        content(
+            ("application/merge-patch+json", example = json!({
+                "metadata": { "seo": { "meta_title": null } }
+            })),
+            ("application/json-patch+json", example = json!([
+                { "op": "add", "path": "/metadata/tags/-", "value": { "name": "rust" } }
+            ]))
+        )
+    ),
+    security(("bearer_auth" = []), ("api_key" = ["posts:write"])),
+    responses(
+        (status = 200, description = "Post modifié", body = inline(ApiResponse<PostResponse>),
+            headers(("ETag" = String, description = "Nouvelle version du post"))),
+        (status = 400, description = "Patch illisible", body = ProblemDetails),
+        (status = 401, description = "Token manquant ou invalide", body = ProblemDetails),
+        (status = 403, description = "Ni l'auteur, ni editor/admin", body = ProblemDetails),
+        (status = 404, description = "Post non trouvé", body = ProblemDetails),
+        (status = 412, description = "Post modifié depuis sa lecture", body = ProblemDetails),
+        (status = 415, description = "Content-Type non supporté", body = ProblemDetails),
+        (status = 422, description = "Patch inapplicable ou erreur de validation", body = ProblemDetails),
+        (status = 500, description = "Erreur serveur", body = ProblemDetails)
+    )
+)]
+async fn patch_post(
+    State(state): State<Arc<AppState>>,
+    ValidatedPath(id): ValidatedPath<i32>,
+    ScopedUser(actor, _): ScopedUser<scope::PostsWrite>,
+    if_match: IfMatch,
+    patch: JsonPatch,
+) -> ApiResult<Response> {
+    let current = state.post_service.editable_post(id, &actor).await?;
+    if_match.ensure(current.version)?;
+
+    let document = UpdatePostDto::patch_document(&current);
+    let dto = patch
+        .apply::<UpdatePostDto>(document, &UpdatePostDto::PATCH_REQUIRED)?
+        .changes_from(&current);
+
+    // Le patch a été calculé sur cette version: une modification concurrente donne 412
+    let base = IfMatch::version(current.version);
+    let result = state.post_service.update(id, dto, &actor, &base).await?;
+    let version = result.post.version;
     let response = PostResponse::from_post_with_author(result.post, result.author);
-    Ok(ApiResponseBuilder::one(response))
+    Ok(ApiResponseBuilder::one_with_etag(response, version))
 }
 
 /// DELETE /posts/:id - Supprimer un post
@@ -164,18 +469,25 @@ async fn update_post(
     path = "/posts/{id}",
     tag = "posts",
     params(
-        ("id" = i32, Path, description = "Post ID")
+        ("id" = i32, Path, description = "Post ID"),
+        ("If-Match" = Option<String>, Header, description = "ETag lu par le client")
     ),
+    security(("bearer_auth" = []), ("api_key" = ["posts:write"])),
     responses(
         (status = 204, description = "Post supprimé"),
-        (status = 404, description = "Post non trouvé", body = ErrorResponse),
-        (status = 500, description = "Erreur serveur", body = ErrorResponse)
+        (status = 401, description = "Token manquant ou invalide", body = ProblemDetails),
+        (status = 403, description = "Ni l'auteur, ni editor/admin", body = ProblemDetails),
+        (status = 404, description = "Post non trouvé", body = ProblemDetails),
+        (status = 412, description = "Post modifié depuis sa lecture", body = ProblemDetails),
+        (status = 500, description = "Erreur serveur", body = ProblemDetails)
     )
 )]
 async fn delete_post(
     State(state): State<Arc<AppState>>,
-    Path(id): Path<i32>,
+    ValidatedPath(id): ValidatedPath<i32>,
+    ScopedUser(actor, _): ScopedUser<scope::PostsWrite>,
+    if_match: IfMatch,
 ) -> ApiResult<StatusCode> {
-    state.post_service.delete(id).await?;
+    state.post_service.delete(id, &actor, &if_match).await?;
     Ok(ApiResponseBuilder::no_content())
 }
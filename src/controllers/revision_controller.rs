@@ -0,0 +1,132 @@
+// src/controllers/revision_controller.rs
+// Controller pour l'historique des révisions d'un post
+
+use axum::{
+    extract::State,
+    response::Response,
+    routing::{get, post},
+    Router,
+};
+use std::sync::Arc;
+
+use crate::auth::{scope, ScopedUser};
+use crate::config::AppState;
+use crate::dto::{
+    IfMatch, PaginationQuery, PostResponse, PostRevisionDetailResponse, PostRevisionResponse,
+};
+use crate::error::{ApiResult, ProblemDetails};
+use crate::response::{ApiResponse, ApiResponseBuilder, PaginatedResponse};
+use crate::validation::{ValidatedPath, ValidatedQuery};
+
+pub struct RevisionController;
+
+impl RevisionController {
+    pub fn routes() -> Router<Arc<AppState>> {
+        Router::new()
+            .route("/posts/:id/revisions", get(list_revisions))
+            .route("/posts/:id/revisions/:rev", get(get_revision))
+            .route("/posts/:id/revisions/:rev/restore", post(restore_revision))
+    }
+}
+
+/// GET /posts/:id/revisions - Historique paginé des révisions, la plus récente d'abord
+/// Une révision est la version d'un post remplacée par une modification
+#[utoipa::path(
+    get,
+    path = "/posts/{id}/revisions",
+    tag = "revisions",
+    params(
+        ("id" = i32, Path, description = "Post ID"),
+        PaginationQuery
+    ),
+    security(("bearer_auth" = []), ("api_key" = ["posts:read"])),
+    responses(
+        (status = 200, description = "Liste paginée des révisions", body = inline(PaginatedResponse<PostRevisionResponse>)),
+        (status = 401, description = "Token manquant ou invalide", body = ProblemDetails),
+        (status = 403, description = "Ni l'auteur, ni editor/admin", body = ProblemDetails),
+        (status = 404, description = "Post non trouvé", body = ProblemDetails),
+        (status = 500, description = "Erreur serveur", body = ProblemDetails)
+    )
+)]
+async fn list_revisions(
+    State(state): State<Arc<AppState>>,
+    ValidatedPath(post_id): ValidatedPath<i32>,
+    ScopedUser(actor, _): ScopedUser<scope::PostsRead>,
+    ValidatedQuery(pagination): ValidatedQuery<PaginationQuery>,
+) -> ApiResult<PaginatedResponse<PostRevisionResponse>> {
+    let result = state.post_service.find_revisions(post_id, &pagination, &actor).await?;
+    let revisions: Vec<PostRevisionResponse> =
+        result.revisions.into_iter().map(Into::into).collect();
+
+    Ok(ApiResponseBuilder::paginated(
+        revisions,
+        result.total,
+        pagination.page,
+        pagination.per_page,
+    ))
+}
+
+/// GET /posts/:id/revisions/:rev - Détail d'une révision et diff avec la version courante
+#[utoipa::path(
+    get,
+    path = "/posts/{id}/revisions/{rev}",
+    tag = "revisions",
+    params(
+        ("id" = i32, Path, description = "Post ID"),
+        ("rev" = i32, Path, description = "Revision number")
+    ),
+    security(("bearer_auth" = []), ("api_key" = ["posts:read"])),
+    responses(
+        (status = 200, description = "Révision et diff ligne à ligne", body = inline(ApiResponse<PostRevisionDetailResponse>)),
+        (status = 401, description = "Token manquant ou invalide", body = ProblemDetails),
+        (status = 403, description = "Ni l'auteur, ni editor/admin", body = ProblemDetails),
+        (status = 404, description = "Post ou révision non trouvé", body = ProblemDetails),
+        (status = 500, description = "Erreur serveur", body = ProblemDetails)
+    )
+)]
+async fn get_revision(
+    State(state): State<Arc<AppState>>,
+    ValidatedPath((post_id, revision)): ValidatedPath<(i32, i32)>,
+    ScopedUser(actor, _): ScopedUser<scope::PostsRead>,
+) -> ApiResult<ApiResponse<PostRevisionDetailResponse>> {
+    let result = state.post_service.find_revision(post_id, revision, &actor).await?;
+    Ok(ApiResponseBuilder::one(result.into()))
+}
+
+/// POST /posts/:id/revisions/:rev/restore - Restaurer le titre, le contenu et les metadata
+/// La version remplacée est elle-même conservée comme nouvelle révision
+/// Avec `If-Match: <ETag lu>`, 412 si le post a été modifié entre-temps
+#[utoipa::path(
+    post,
+    path = "/posts/{id}/revisions/{rev}/restore",
+    tag = "revisions",
+    params(
+        ("id" = i32, Path, description = "Post ID"),
+        ("rev" = i32, Path, description = "Revision number"),
+        ("If-Match" = Option<String>, Header, description = "ETag lu par le client")
+    ),
+    security(("bearer_auth" = []), ("api_key" = ["posts:write"])),
+    responses(
+        (status = 200, description = "Post restauré", body = inline(ApiResponse<PostResponse>),
+            headers(("ETag" = String, description = "Nouvelle version du post"))),
+        (status = 401, description = "Token manquant ou invalide", body = ProblemDetails),
+        (status = 403, description = "Ni l'auteur, ni editor/admin", body = ProblemDetails),
+        (status = 404, description = "Post ou révision non trouvé", body = ProblemDetails),
+        (status = 412, description = "Post modifié depuis sa lecture", body = ProblemDetails),
+        (status = 500, description = "Erreur serveur", body = ProblemDetails)
+    )
+)]
+async fn restore_revision(
+    State(state): State<Arc<AppState>>,
+    ValidatedPath((post_id, revision)): ValidatedPath<(i32, i32)>,
+    ScopedUser(actor, _): ScopedUser<scope::PostsWrite>,
+    if_match: IfMatch,
+) -> ApiResult<Response> {
+    let result = state
+        .post_service
+        .restore_revision(post_id, revision, &actor, &if_match)
+        .await?;
+    let version = result.post.version;
+    let response = PostResponse::from_post_with_author(result.post, result.author);
+    Ok(ApiResponseBuilder::one_with_etag(response, version))
+}
@@ -0,0 +1,35 @@
+// src/controllers/tag_controller.rs
+// Controller pour les tags (les posts d'un tag sont dans PostController)
+
+use axum::{extract::State, routing::get, Router};
+use std::sync::Arc;
+
+use crate::config::AppState;
+use crate::dto::TagListItemResponse;
+use crate::error::{ApiResult, ProblemDetails};
+use crate::response::{ApiResponse, ApiResponseBuilder};
+
+pub struct TagController;
+
+impl TagController {
+    pub fn routes() -> Router<Arc<AppState>> {
+        Router::new().route("/tags", get(list_tags))
+    }
+}
+
+/// GET /tags - Tags utilisés, avec couleur et nombre de posts
+#[utoipa::path(
+    get,
+    path = "/tags",
+    tag = "tags",
+    responses(
+        (status = 200, description = "Liste des tags (les plus utilisés d'abord)", body = inline(ApiResponse<Vec<TagListItemResponse>>)),
+        (status = 500, description = "Erreur serveur", body = ProblemDetails)
+    )
+)]
+async fn list_tags(
+    State(state): State<Arc<AppState>>,
+) -> ApiResult<ApiResponse<Vec<TagListItemResponse>>> {
+    let tags = state.tag_service.find_all().await?;
+    Ok(ApiResponseBuilder::list(tags.into_iter().map(Into::into).collect()))
+}
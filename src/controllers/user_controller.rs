@@ -2,18 +2,23 @@
 // Equivalent de: src/Controller/UserController.php
 
 use axum::{
-    extract::{Path, Query, State},
+    extract::State,
     http::StatusCode,
-    routing::{get, post, put, delete},
+    response::Response,
+    routing::{get, patch, post, put, delete},
     Router,
 };
 use std::sync::Arc;
 
+use crate::auth::AuthUser;
 use crate::config::AppState;
-use crate::dto::{CreateUserDto, PaginationQuery, UpdateUserDto, UserResponse};
-use crate::error::{ApiResult, ErrorResponse};
+use crate::dto::{
+    CreateUserDto, IfMatch, IfNoneMatch, PaginationQuery, UpdateUserDto, UpdateUserRoleDto,
+    UserFilterQuery, UserResponse,
+};
+use crate::error::{ApiResult, ProblemDetails};
 use crate::response::{ApiResponse, ApiResponseBuilder, PaginatedResponse};
-use crate::validation::ValidatedJson;
+use crate::validation::{JsonPatch, ValidatedJson, ValidatedPath, ValidatedQuery};
 
 /// UserController - User management endpoints
 pub struct UserController;
@@ -26,27 +31,33 @@ impl UserController {
             .route("/users", post(create_user))
             .route("/users/:id", get(get_user))
             .route("/users/:id", put(update_user))
+            .route("/users/:id", patch(patch_user))
             .route("/users/:id", delete(delete_user))
+            .route("/users/:id/role", put(update_user_role))
     }
 }
 
-/// GET /users - List all users with pagination
+/// GET /users - List all users with pagination, filters and sorting
+/// Filters: ?role=editor&username=john&created_after=...&sort=-created_at
 /// Response: { "data": [...], "meta": { "total": 100, "page": 1, ... } }
 #[utoipa::path(
     get,
     path = "/users",
     tag = "users",
-    params(PaginationQuery),
+    params(PaginationQuery, UserFilterQuery),
     responses(
         (status = 200, description = "Paginated list of users", body = inline(PaginatedResponse<UserResponse>)),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Malformed query parameter", body = ProblemDetails),
+        (status = 422, description = "Invalid filter or sort", body = ProblemDetails),
+        (status = 500, description = "Internal server error", body = ProblemDetails)
     )
 )]
 async fn list_users(
     State(state): State<Arc<AppState>>,
-    Query(pagination): Query<PaginationQuery>,
+    ValidatedQuery(pagination): ValidatedQuery<PaginationQuery>,
+    ValidatedQuery(filter): ValidatedQuery<UserFilterQuery>,
 ) -> ApiResult<PaginatedResponse<UserResponse>> {
-    let result = state.user_service.find_all(&pagination).await?;
+    let result = state.user_service.find_all(&pagination, &filter).await?;
 
     // Transform entities to DTOs
     let users: Vec<UserResponse> = result.users.into_iter().map(Into::into).collect();
@@ -60,26 +71,35 @@ async fn list_users(
 }
 
 /// GET /users/:id - Get user by ID
-/// Response: { "data": { ... } }
+/// Response: ETag + { "data": { ... } }, or 304 if If-None-Match has the current ETag
 #[utoipa::path(
     get,
     path = "/users/{id}",
     tag = "users",
     params(
-        ("id" = i32, Path, description = "User ID")
+        ("id" = i32, Path, description = "User ID"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag of a cached version")
     ),
     responses(
-        (status = 200, description = "User found", body = inline(ApiResponse<UserResponse>)),
-        (status = 404, description = "User not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 200, description = "User found", body = inline(ApiResponse<UserResponse>),
+            headers(("ETag" = String, description = "Version of the user"))),
+        (status = 304, description = "Cached version is still current"),
+        (status = 404, description = "User not found", body = ProblemDetails),
+        (status = 500, description = "Internal server error", body = ProblemDetails)
     )
 )]
 async fn get_user(
     State(state): State<Arc<AppState>>,
-    Path(id): Path<i32>,
-) -> ApiResult<ApiResponse<UserResponse>> {
+    ValidatedPath(id): ValidatedPath<i32>,
+    if_none_match: IfNoneMatch,
+) -> ApiResult<Response> {
     let user = state.user_service.find_by_id(id).await?;
-    Ok(ApiResponseBuilder::one(user.into()))
+
+    if if_none_match.matches(user.version) {
+        return Ok(ApiResponseBuilder::not_modified(user.version));
+    }
+    let version = user.version;
+    Ok(ApiResponseBuilder::one_with_etag(UserResponse::from(user), version))
 }
 
 /// POST /users - Create a new user
@@ -91,9 +111,9 @@ async fn get_user(
     request_body = CreateUserDto,
     responses(
         (status = 201, description = "User created successfully", body = inline(ApiResponse<UserResponse>)),
-        (status = 409, description = "Email already exists", body = ErrorResponse),
-        (status = 422, description = "Validation error", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 409, description = "Email already exists", body = ProblemDetails),
+        (status = 422, description = "Validation error", body = ProblemDetails),
+        (status = 500, description = "Internal server error", body = ProblemDetails)
     )
 )]
 async fn create_user(
@@ -111,24 +131,125 @@ async fn create_user(
     path = "/users/{id}",
     tag = "users",
     params(
-        ("id" = i32, Path, description = "User ID")
+        ("id" = i32, Path, description = "User ID"),
+        ("If-Match" = Option<String>, Header, description = "ETag read by the client")
     ),
     request_body = UpdateUserDto,
+    security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "User updated successfully", body = inline(ApiResponse<UserResponse>)),
-        (status = 404, description = "User not found", body = ErrorResponse),
-        (status = 409, description = "Email already exists", body = ErrorResponse),
-        (status = 422, description = "Validation error", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 200, description = "User updated successfully", body = inline(ApiResponse<UserResponse>),
+            headers(("ETag" = String, description = "New version of the user"))),
+        (status = 401, description = "Missing or invalid token", body = ProblemDetails),
+        (status = 403, description = "Not the user themselves nor an admin", body = ProblemDetails),
+        (status = 404, description = "User not found", body = ProblemDetails),
+        (status = 409, description = "Email already exists", body = ProblemDetails),
+        (status = 412, description = "User modified since it was read", body = ProblemDetails),
+        (status = 422, description = "Validation error", body = ProblemDetails),
+        (status = 500, description = "Internal server error", body = ProblemDetails)
     )
 )]
 async fn update_user(
     State(state): State<Arc<AppState>>,
-    Path(id): Path<i32>,
+    ValidatedPath(id): ValidatedPath<i32>,
+    AuthUser(actor): AuthUser,
+    if_match: IfMatch,
     ValidatedJson(dto): ValidatedJson<UpdateUserDto>,
-) -> ApiResult<ApiResponse<UserResponse>> {
-    let user = state.user_service.update(id, dto).await?;
-    Ok(ApiResponseBuilder::one(user.into()))
+) -> ApiResult<Response> {
+    let user = state.user_service.update(id, dto, &actor, &if_match).await?;
+    let version = user.version;
+    Ok(ApiResponseBuilder::one_with_etag(UserResponse::from(user), version))
+}
+
+/// PATCH /users/:id - Partially update a user
+/// Body: JSON Merge Patch (`application/merge-patch+json`) or JSON Patch
+/// (`application/json-patch+json`), validated like the PUT once applied
+#[utoipa::path(
+    patch,
+    path = "/users/{id}",
+    tag = "users",
+    params(
+        ("id" = i32, Path, description = "User ID"),
+        ("If-Match" = Option<String>, Header, description = "ETag read by the client")
+    ),
+    request_body(
+        description = "Changes to apply to the user (username, email)",
+        content(
+            ("application/merge-patch+json", example = json!({ "email": "john.new@example.com" })),
+            ("application/json-patch+json", example = json!([
+                { "op": "replace", "path": "/username", "value": "johndoe_updated" }
+            ]))
+        )
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "User updated successfully", body = inline(ApiResponse<UserResponse>),
+            headers(("ETag" = String, description = "New version of the user"))),
+        (status = 400, description = "Malformed patch", body = ProblemDetails),
+        (status = 401, description = "Missing or invalid token", body = ProblemDetails),
+        (status = 403, description = "Not the user themselves nor an admin", body = ProblemDetails),
+        (status = 404, description = "User not found", body = ProblemDetails),
+        (status = 409, description = "Email already exists", body = ProblemDetails),
+        (status = 412, description = "User modified since it was read", body = ProblemDetails),
+        (status = 415, description = "Unsupported Content-Type", body = ProblemDetails),
+        (status = 422, description = "Patch not applicable or validation error", body = ProblemDetails),
+        (status = 500, description = "Internal server error", body = ProblemDetails)
+    )
+)]
+async fn patch_user(
+    State(state): State<Arc<AppState>>,
+    ValidatedPath(id): ValidatedPath<i32>,
+    AuthUser(actor): AuthUser,
+    if_match: IfMatch,
+    patch: JsonPatch,
+) -> ApiResult<Response> {
+    let current = state.user_service.editable_user(id, &actor).await?;
+    if_match.ensure(current.version)?;
+
+    let document = UpdateUserDto::patch_document(&current);
+    let dto = patch
+        .apply::<UpdateUserDto>(document, &UpdateUserDto::PATCH_REQUIRED)?
+        .changes_from(&current);
+
+    // The patch was computed on this version: a concurrent update gives 412
+    let base = IfMatch::version(current.version);
+    let user = state.user_service.update(id, dto, &actor, &base).await?;
+    let version = user.version;
+    Ok(ApiResponseBuilder::one_with_etag(UserResponse::from(user), version))
+}
+
+/// PUT /users/:id/role - Change a user's role (admin only)
+/// Response: { "data": { ... } }
+#[utoipa::path(
+    put,
+    path = "/users/{id}/role",
+    tag = "users",
+    params(
+        ("id" = i32, Path, description = "User ID"),
+        ("If-Match" = Option<String>, Header, description = "ETag read by the client")
+    ),
+    request_body = UpdateUserRoleDto,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Role updated successfully", body = inline(ApiResponse<UserResponse>),
+            headers(("ETag" = String, description = "New version of the user"))),
+        (status = 401, description = "Missing or invalid token", body = ProblemDetails),
+        (status = 403, description = "Admin role required", body = ProblemDetails),
+        (status = 404, description = "User not found", body = ProblemDetails),
+        (status = 412, description = "User modified since it was read", body = ProblemDetails),
+        (status = 422, description = "Validation error", body = ProblemDetails),
+        (status = 500, description = "Internal server error", body = ProblemDetails)
+    )
+)]
+async fn update_user_role(
+    State(state): State<Arc<AppState>>,
+    ValidatedPath(id): ValidatedPath<i32>,
+    AuthUser(actor): AuthUser,
+    if_match: IfMatch,
+    ValidatedJson(dto): ValidatedJson<UpdateUserRoleDto>,
+) -> ApiResult<Response> {
+    let user = state.user_service.change_role(id, dto.role, &actor, &if_match).await?;
+    let version = user.version;
+    Ok(ApiResponseBuilder::one_with_etag(UserResponse::from(user), version))
 }
 
 /// DELETE /users/:id - Delete a user
@@ -137,18 +258,25 @@ async fn update_user(
     path = "/users/{id}",
     tag = "users",
     params(
-        ("id" = i32, Path, description = "User ID to delete")
+        ("id" = i32, Path, description = "User ID to delete"),
+        ("If-Match" = Option<String>, Header, description = "ETag read by the client")
     ),
+    security(("bearer_auth" = [])),
     responses(
         (status = 204, description = "User deleted successfully"),
-        (status = 404, description = "User not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 401, description = "Missing or invalid token", body = ProblemDetails),
+        (status = 403, description = "Admin role required", body = ProblemDetails),
+        (status = 404, description = "User not found", body = ProblemDetails),
+        (status = 412, description = "User modified since it was read", body = ProblemDetails),
+        (status = 500, description = "Internal server error", body = ProblemDetails)
     )
 )]
 async fn delete_user(
     State(state): State<Arc<AppState>>,
-    Path(id): Path<i32>,
+    ValidatedPath(id): ValidatedPath<i32>,
+    AuthUser(actor): AuthUser,
+    if_match: IfMatch,
 ) -> ApiResult<StatusCode> {
-    state.user_service.delete(id).await?;
+    state.user_service.delete(id, &actor, &if_match).await?;
     Ok(ApiResponseBuilder::no_content())
 }
@@ -0,0 +1,70 @@
+// src/dto/api_key.rs
+// DTOs des clés d'API (clients machine)
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::entities::api_key::{self, ApiScope};
+
+/// Request DTO for minting an API key
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[schema(example = json!({
+    "name": "Nightly import",
+    "scopes": ["posts:read", "posts:write"]
+}))]
+pub struct CreateApiKeyDto {
+    /// Human readable name (1-100 characters)
+    #[validate(length(min = 1, max = 100, code = "api_key.name.length"))]
+    #[schema(min_length = 1, max_length = 100)]
+    pub name: String,
+
+    /// Granted scopes (at least one)
+    #[validate(length(min = 1, code = "api_key.scopes.required"))]
+    pub scopes: Vec<ApiScope>,
+}
+
+/// Response DTO for an API key (never contains the secret)
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(example = json!({
+    "id": 1,
+    "name": "Nightly import",
+    "prefix": "rak_3f9c2a1b",
+    "scopes": ["posts:read", "posts:write"],
+    "last_used_at": "2024-01-16T02:00:00",
+    "revoked_at": null,
+    "created_at": "2024-01-15T10:30:00"
+}))]
+pub struct ApiKeyResponse {
+    pub id: i32,
+    pub name: String,
+    /// First characters of the key, to recognize it
+    pub prefix: String,
+    pub scopes: Vec<ApiScope>,
+    pub last_used_at: Option<chrono::NaiveDateTime>,
+    pub revoked_at: Option<chrono::NaiveDateTime>,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+impl From<api_key::Model> for ApiKeyResponse {
+    fn from(key: api_key::Model) -> Self {
+        Self {
+            scopes: key.get_scopes(),
+            id: key.id,
+            name: key.name,
+            prefix: key.prefix,
+            last_used_at: key.last_used_at,
+            revoked_at: key.revoked_at,
+            created_at: key.created_at,
+        }
+    }
+}
+
+/// Response DTO for a freshly minted API key
+/// The secret is shown only once: it cannot be retrieved afterwards
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CreatedApiKeyResponse {
+    /// Secret to send as `X-Api-Key: <key>`
+    pub key: String,
+    pub api_key: ApiKeyResponse,
+}
@@ -0,0 +1,64 @@
+// src/dto/auth.rs
+// DTOs d'authentification (login, tokens)
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// Request DTO for login
+/// Equivalent de: json_login en Symfony
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[schema(example = json!({
+    "email": "john@example.com",
+    "password": "S3cure-password"
+}))]
+pub struct LoginDto {
+    /// Email used at registration
+    #[validate(email(code = "user.email.format"))]
+    #[schema(format = "email")]
+    pub email: String,
+
+    /// Plain password
+    #[validate(length(min = 1, code = "auth.password.required"))]
+    #[schema(format = "password")]
+    pub password: String,
+}
+
+/// Request DTO carrying a refresh token (refresh, logout)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[schema(example = json!({ "refresh_token": "rrt_3f9c2a1b..." }))]
+pub struct RefreshTokenDto {
+    /// Refresh token obtained from login or a previous refresh
+    #[validate(length(min = 1, code = "auth.refresh_token.required"))]
+    pub refresh_token: String,
+}
+
+/// Response DTO for an issued access token
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(example = json!({
+    "access_token": "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9...",
+    "refresh_token": "rrt_3f9c2a1b...",
+    "token_type": "Bearer",
+    "expires_in": 3600
+}))]
+pub struct TokenResponse {
+    /// Signed JWT to send as `Authorization: Bearer <token>`
+    pub access_token: String,
+    /// Single-use token for `POST /auth/refresh` (replaced on every refresh)
+    pub refresh_token: String,
+    /// Always "Bearer"
+    pub token_type: String,
+    /// Access token lifetime in seconds
+    pub expires_in: i64,
+}
+
+impl TokenResponse {
+    pub fn bearer(access_token: String, refresh_token: String, expires_in: i64) -> Self {
+        Self {
+            access_token,
+            refresh_token,
+            token_type: "Bearer".to_string(),
+            expires_in,
+        }
+    }
+}
@@ -0,0 +1,59 @@
+// src/dto/comment.rs
+// DTOs pour les commentaires
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::dto::AuthorResponse;
+use crate::entities::{comment, user};
+
+/// DTO pour créer un commentaire (ou répondre à un commentaire)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[schema(example = json!({
+    "body": "Merci pour cet article !",
+    "parent_id": null
+}))]
+pub struct CreateCommentDto {
+    #[validate(length(min = 1, max = 5000, code = "comment.content.length"))]
+    pub body: String,
+
+    /// Commentaire auquel on répond (doit appartenir au même post)
+    #[validate(range(min = 1, code = "comment.parent_id.range"))]
+    pub parent_id: Option<i32>,
+}
+
+/// DTO pour modifier un commentaire
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[schema(example = json!({ "body": "Merci pour cet article (modifié) !" }))]
+pub struct UpdateCommentDto {
+    #[validate(length(min = 1, max = 5000, code = "comment.content.length"))]
+    pub body: String,
+}
+
+/// Response DTO pour un commentaire
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CommentResponse {
+    pub id: i32,
+    pub post_id: i32,
+    pub parent_id: Option<i32>,
+    pub body: String,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: Option<chrono::NaiveDateTime>,
+    pub author: AuthorResponse,
+}
+
+impl CommentResponse {
+    /// Crée une réponse à partir du commentaire et de son auteur
+    pub fn from_comment_with_author(comment: comment::Model, author: user::Model) -> Self {
+        Self {
+            id: comment.id,
+            post_id: comment.post_id,
+            parent_id: comment.parent_id,
+            body: comment.body,
+            created_at: comment.created_at,
+            updated_at: comment.updated_at,
+            author: author.into(),
+        }
+    }
+}
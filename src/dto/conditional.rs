@@ -0,0 +1,158 @@
+// src/dto/conditional.rs
+// Requêtes conditionnelles (ETag): If-Match et If-None-Match
+// Equivalent de: Request::getETags() / Response::isNotModified() en Symfony
+
+use std::convert::Infallible;
+
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{
+        header::{IF_MATCH, IF_NONE_MATCH},
+        request::Parts,
+        HeaderMap, HeaderName,
+    },
+};
+use sea_orm::DbErr;
+use tracing::warn;
+
+use crate::error::ServiceError;
+use crate::i18n::Message;
+
+/// ETag fort d'une ressource versionnée (post, utilisateur): `"<version>"`
+pub fn etag(version: i32) -> String {
+    format!("\"{version}\"")
+}
+
+/// Liste d'ETags d'un header conditionnel
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EntityTags {
+    /// `*`: n'importe quelle version
+    Any,
+    /// Versions citées ; les ETags qui ne viennent pas de cette API sont ignorés
+    Versions(Vec<i32>),
+}
+
+impl EntityTags {
+    /// Parse `"3", "4"` ou `*` ; `weak` accepte aussi `W/"3"` (comparaison faible)
+    pub fn parse(value: &str, weak: bool) -> Self {
+        if value.trim() == "*" {
+            return Self::Any;
+        }
+
+        let versions = value
+            .split(',')
+            .map(str::trim)
+            .filter_map(|tag| match tag.strip_prefix("W/") {
+                Some(weak_tag) if weak => Some(weak_tag),
+                Some(_) => None,
+                None => Some(tag),
+            })
+            .filter_map(|tag| tag.strip_prefix('"')?.strip_suffix('"')?.parse().ok())
+            .collect();
+        Self::Versions(versions)
+    }
+
+    fn contains(&self, version: i32) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Versions(versions) => versions.contains(&version),
+        }
+    }
+}
+
+/// Précondition `If-Match` d'un PUT / DELETE (verrouillage optimiste)
+/// Sans header, la modification est inconditionnelle
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IfMatch(pub Option<EntityTags>);
+
+impl IfMatch {
+    /// Précondition sur une version précise (ex: celle sur laquelle un PATCH a été calculé)
+    pub fn version(version: i32) -> Self {
+        Self(Some(EntityTags::Versions(vec![version])))
+    }
+
+    /// 412 si la version courante n'est pas celle que le client a lue
+    pub fn ensure(&self, version: i32) -> Result<(), ServiceError> {
+        match &self.0 {
+            Some(tags) if !tags.contains(version) => {
+                warn!(version, expected = ?tags, "If-Match precondition failed");
+                Err(ServiceError::PreconditionFailed(
+                    Message::new("precondition.stale_etag").param("etag", etag(version)),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Erreur d'une écriture conditionnée sur la version lue (`WHERE version = ?`)
+/// Aucune ligne modifiée: un autre client a écrit entre la lecture et l'écriture (412)
+pub fn version_conflict(err: DbErr) -> ServiceError {
+    match err {
+        DbErr::RecordNotUpdated => stale_version(),
+        err => err.into(),
+    }
+}
+
+/// 412 d'une écriture concurrente (la version a changé depuis la lecture)
+pub fn stale_version() -> ServiceError {
+    warn!("Concurrent modification detected");
+    ServiceError::PreconditionFailed(Message::new("precondition.concurrent_write"))
+}
+
+/// `If-None-Match` d'un GET: le client a déjà une version en cache
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IfNoneMatch(pub Option<EntityTags>);
+
+impl IfNoneMatch {
+    /// Vrai si la version du client est à jour (réponse 304)
+    pub fn matches(&self, version: i32) -> bool {
+        self.0.as_ref().is_some_and(|tags| tags.contains(version))
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for IfMatch {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        // If-Match utilise la comparaison forte (RFC 9110)
+        Ok(Self(entity_tags(&parts.headers, IF_MATCH, false)))
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for IfNoneMatch {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self(entity_tags(&parts.headers, IF_NONE_MATCH, true)))
+    }
+}
+
+fn entity_tags(headers: &HeaderMap, name: HeaderName, weak: bool) -> Option<EntityTags> {
+    let value = headers.get(name)?.to_str().ok()?;
+    Some(EntityTags::parse(value, weak))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_entity_tags() {
+        assert_eq!(EntityTags::parse("*", false), EntityTags::Any);
+        assert_eq!(EntityTags::parse("\"3\", \"4\"", false), EntityTags::Versions(vec![3, 4]));
+        assert_eq!(EntityTags::parse("W/\"3\", \"x\"", false), EntityTags::Versions(vec![]));
+        assert_eq!(EntityTags::parse("W/\"3\"", true), EntityTags::Versions(vec![3]));
+    }
+
+    #[test]
+    fn test_if_match_ensure() {
+        assert!(IfMatch(None).ensure(2).is_ok());
+        assert!(IfMatch(Some(EntityTags::Any)).ensure(2).is_ok());
+        assert!(IfMatch(Some(EntityTags::Versions(vec![2]))).ensure(2).is_ok());
+        assert!(IfMatch(Some(EntityTags::Versions(vec![1]))).ensure(2).is_err());
+    }
+}
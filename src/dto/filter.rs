@@ -0,0 +1,270 @@
+// src/dto/filter.rs
+// Filtres et tri des listes (?published=true&sort=-created_at,title)
+// Equivalent de: ApiPlatform SearchFilter / OrderFilter
+
+use std::borrow::Cow;
+
+use chrono::NaiveDateTime;
+use sea_orm::{ColumnTrait, Condition, Order};
+use serde::Deserialize;
+use utoipa::IntoParams;
+use validator::{Validate, ValidationError};
+
+use crate::entities::post::PostStatus;
+use crate::entities::user::Role;
+use crate::entities::{post, post_tag, user};
+
+// ============================================================================
+// SORT
+// ============================================================================
+
+/// Parse une spécification de tri `champ1,-champ2` (`-` = décroissant)
+/// Chaque champ doit être résolu par `column` (liste blanche), sinon renvoie le champ inconnu
+fn parse_sort<C>(raw: &str, column: fn(&str) -> Option<C>) -> Result<Vec<(C, Order)>, String> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|field| !field.is_empty())
+        .map(|field| {
+            let (name, order) = match field.strip_prefix('-') {
+                Some(name) => (name, Order::Desc),
+                None => (field, Order::Asc),
+            };
+            column(name)
+                .map(|column| (column, order))
+                .ok_or_else(|| name.to_string())
+        })
+        .collect()
+}
+
+/// Construit la ValidationError d'un tri invalide
+fn sort_error(field: String, allowed: &'static str) -> ValidationError {
+    let mut error = ValidationError::new("filter.sort.unknown");
+    error.add_param(Cow::Borrowed("field"), &field);
+    error.add_param(Cow::Borrowed("allowed"), &allowed);
+    error
+}
+
+// ============================================================================
+// POSTS
+// ============================================================================
+
+/// Colonnes triables des posts
+const POST_SORT_FIELDS: &str = "id, title, published, publish_at, created_at, updated_at";
+
+fn post_sort_column(name: &str) -> Option<post::Column> {
+    match name {
+        "id" => Some(post::Column::Id),
+        "title" => Some(post::Column::Title),
+        "published" => Some(post::Column::Published),
+        "publish_at" => Some(post::Column::PublishAt),
+        "created_at" => Some(post::Column::CreatedAt),
+        "updated_at" => Some(post::Column::UpdatedAt),
+        _ => None,
+    }
+}
+
+fn validate_post_sort(sort: &str) -> Result<(), ValidationError> {
+    parse_sort(sort, post_sort_column)
+        .map(|_| ())
+        .map_err(|field| sort_error(field, POST_SORT_FIELDS))
+}
+
+/// Filtres et tri de la liste des posts
+#[derive(Debug, Default, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PostFilterQuery {
+    /// Only published (true) or draft (false) posts
+    pub published: Option<bool>,
+
+    /// Only posts with this status
+    #[param(inline)]
+    pub status: Option<PostStatus>,
+
+    /// Only posts of this author
+    #[validate(range(min = 1, code = "filter.author_id.range"))]
+    pub author_id: Option<i32>,
+
+    /// Only posts having this tag (exact name)
+    #[validate(length(min = 1, max = 50, code = "filter.tag.length"))]
+    pub tag: Option<String>,
+
+    /// Only posts created at or after this date (e.g. 2024-01-15T00:00:00)
+    pub created_after: Option<NaiveDateTime>,
+
+    /// Only posts created at or before this date
+    pub created_before: Option<NaiveDateTime>,
+
+    /// Comma separated fields, `-` prefix for descending order
+    /// (id, title, published, publish_at, created_at, updated_at). Default: `-created_at`
+    #[validate(custom(function = "validate_post_sort"))]
+    #[param(example = "-created_at,title")]
+    pub sort: Option<String>,
+}
+
+impl PostFilterQuery {
+    /// Traduit les filtres en condition SeaORM
+    pub fn condition(&self) -> Condition {
+        let mut condition = Condition::all();
+
+        if let Some(published) = self.published {
+            condition = condition.add(post::Column::Published.eq(published));
+        }
+        if let Some(status) = self.status {
+            condition = condition.add(post::Column::Status.eq(status));
+        }
+        if let Some(author_id) = self.author_id {
+            condition = condition.add(post::Column::AuthorId.eq(author_id));
+        }
+        if let Some(tag) = &self.tag {
+            let tagged = post_tag::post_ids_with_tag(tag);
+            condition = condition.add(post::Column::Id.in_subquery(tagged));
+        }
+        if let Some(created_after) = self.created_after {
+            condition = condition.add(post::Column::CreatedAt.gte(created_after));
+        }
+        if let Some(created_before) = self.created_before {
+            condition = condition.add(post::Column::CreatedAt.lte(created_before));
+        }
+
+        condition
+    }
+
+    /// Ordre de tri ; `id` est toujours ajouté en dernier critère (ordre stable)
+    pub fn order_by(&self) -> Vec<(post::Column, Order)> {
+        let mut orders = self
+            .sort
+            .as_deref()
+            .and_then(|sort| parse_sort(sort, post_sort_column).ok())
+            .filter(|orders| !orders.is_empty())
+            .unwrap_or_else(|| vec![(post::Column::CreatedAt, Order::Desc)]);
+
+        if !orders.iter().any(|(column, _)| matches!(column, post::Column::Id)) {
+            let tie_break = orders.first().map(|(_, order)| order.clone()).unwrap_or(Order::Desc);
+            orders.push((post::Column::Id, tie_break));
+        }
+
+        orders
+    }
+}
+
+// ============================================================================
+// USERS
+// ============================================================================
+
+/// Colonnes triables des utilisateurs
+const USER_SORT_FIELDS: &str = "id, username, email, role, created_at";
+
+fn user_sort_column(name: &str) -> Option<user::Column> {
+    match name {
+        "id" => Some(user::Column::Id),
+        "username" => Some(user::Column::Username),
+        "email" => Some(user::Column::Email),
+        "role" => Some(user::Column::Role),
+        "created_at" => Some(user::Column::CreatedAt),
+        _ => None,
+    }
+}
+
+fn validate_user_sort(sort: &str) -> Result<(), ValidationError> {
+    parse_sort(sort, user_sort_column)
+        .map(|_| ())
+        .map_err(|field| sort_error(field, USER_SORT_FIELDS))
+}
+
+/// Filtres et tri de la liste des utilisateurs
+#[derive(Debug, Default, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct UserFilterQuery {
+    /// Only users with this role
+    #[param(inline)]
+    pub role: Option<Role>,
+
+    /// Only users whose username contains this text
+    #[validate(length(min = 1, max = 50, code = "filter.username.length"))]
+    pub username: Option<String>,
+
+    /// Only users created at or after this date (e.g. 2024-01-15T00:00:00)
+    pub created_after: Option<NaiveDateTime>,
+
+    /// Only users created at or before this date
+    pub created_before: Option<NaiveDateTime>,
+
+    /// Comma separated fields, `-` prefix for descending order
+    /// (id, username, email, role, created_at). Default: `id`
+    #[validate(custom(function = "validate_user_sort"))]
+    #[param(example = "-created_at")]
+    pub sort: Option<String>,
+}
+
+impl UserFilterQuery {
+    /// Traduit les filtres en condition SeaORM
+    pub fn condition(&self) -> Condition {
+        let mut condition = Condition::all();
+
+        if let Some(role) = self.role {
+            condition = condition.add(user::Column::Role.eq(role));
+        }
+        if let Some(username) = &self.username {
+            condition = condition.add(user::Column::Username.contains(username));
+        }
+        if let Some(created_after) = self.created_after {
+            condition = condition.add(user::Column::CreatedAt.gte(created_after));
+        }
+        if let Some(created_before) = self.created_before {
+            condition = condition.add(user::Column::CreatedAt.lte(created_before));
+        }
+
+        condition
+    }
+
+    /// Ordre de tri ; `id` est toujours ajouté en dernier critère (ordre stable)
+    pub fn order_by(&self) -> Vec<(user::Column, Order)> {
+        let mut orders = self
+            .sort
+            .as_deref()
+            .and_then(|sort| parse_sort(sort, user_sort_column).ok())
+            .unwrap_or_default();
+
+        if !orders.iter().any(|(column, _)| matches!(column, user::Column::Id)) {
+            orders.push((user::Column::Id, Order::Asc));
+        }
+
+        orders
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sort_directions() {
+        let orders = parse_sort("-created_at, title", post_sort_column).unwrap();
+
+        assert_eq!(orders.len(), 2);
+        assert!(matches!(orders[0], (post::Column::CreatedAt, Order::Desc)));
+        assert!(matches!(orders[1], (post::Column::Title, Order::Asc)));
+    }
+
+    #[test]
+    fn test_parse_sort_rejects_unknown_columns() {
+        assert!(parse_sort("content", post_sort_column).is_err());
+        assert!(parse_sort("password_hash", user_sort_column).is_err());
+        assert!(validate_user_sort("-email").is_ok());
+    }
+
+    #[test]
+    fn test_order_by_appends_id_tie_break() {
+        let filter = PostFilterQuery {
+            sort: Some("title".to_string()),
+            ..Default::default()
+        };
+        let orders = filter.order_by();
+
+        assert!(matches!(orders.last(), Some((post::Column::Id, Order::Asc))));
+        assert!(matches!(
+            PostFilterQuery::default().order_by()[..],
+            [(post::Column::CreatedAt, Order::Desc), (post::Column::Id, Order::Desc)]
+        ));
+    }
+}
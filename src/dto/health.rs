@@ -0,0 +1,83 @@
+// src/dto/health.rs
+// DTOs des sondes de santé (liveness / readiness)
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::services::{CheckOutcome, Readiness};
+
+/// Etat d'un check ou de l'API
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Up,
+    Down,
+}
+
+/// Résultat d'un check
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CheckResponse {
+    pub status: HealthStatus,
+    /// Durée du check en millisecondes
+    pub latency_ms: f64,
+    /// Cause de l'échec: `timeout`, `unavailable` ou `pending_migrations`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<&'static str>,
+}
+
+impl From<CheckOutcome> for CheckResponse {
+    fn from(outcome: CheckOutcome) -> Self {
+        Self {
+            status: if outcome.is_up() { HealthStatus::Up } else { HealthStatus::Down },
+            latency_ms: outcome.latency.as_secs_f64() * 1000.0,
+            reason: outcome.failure.map(|failure| failure.as_str()),
+        }
+    }
+}
+
+/// Checks de readiness
+#[derive(Debug, Serialize, ToSchema)]
+pub struct HealthChecksResponse {
+    /// Ping de la base de données
+    pub database: CheckResponse,
+    /// Aucune migration en attente
+    pub migrations: CheckResponse,
+}
+
+/// Rapport de GET /health/ready (`down` dès qu'un check échoue ou pendant l'arrêt)
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(example = json!({
+    "status": "up",
+    "draining": false,
+    "checks": {
+        "database": { "status": "up", "latency_ms": 0.42 },
+        "migrations": { "status": "up", "latency_ms": 1.3 }
+    }
+}))]
+pub struct HealthReportResponse {
+    pub status: HealthStatus,
+    /// Arrêt en cours: l'instance termine ses requêtes et ne doit plus en recevoir
+    pub draining: bool,
+    pub checks: HealthChecksResponse,
+}
+
+impl From<Readiness> for HealthReportResponse {
+    fn from(readiness: Readiness) -> Self {
+        let status = if readiness.is_ready() { HealthStatus::Up } else { HealthStatus::Down };
+        Self {
+            status,
+            draining: readiness.draining,
+            checks: HealthChecksResponse {
+                database: readiness.database.into(),
+                migrations: readiness.migrations.into(),
+            },
+        }
+    }
+}
+
+/// Réponse de GET /health/live
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(example = json!({ "status": "up" }))]
+pub struct LivenessResponse {
+    pub status: HealthStatus,
+}
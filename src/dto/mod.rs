@@ -1,10 +1,28 @@
 // src/dto/mod.rs
 // Equivalent de: src/Dto/ en Symfony
 
+mod api_key;
+mod auth;
+mod comment;
+mod conditional;
+mod filter;
+mod health;
 mod pagination;
 mod post;
+mod revision;
+mod search;
+mod tag;
 mod user;
 
+pub use api_key::*;
+pub use auth::*;
+pub use comment::*;
+pub use conditional::*;
+pub use filter::*;
+pub use health::*;
 pub use pagination::*;
 pub use post::*;
+pub use revision::*;
+pub use search::*;
+pub use tag::*;
 pub use user::*;
@@ -1,21 +1,29 @@
 // src/dto/pagination.rs
 // Pagination query parameters
 
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, NaiveDateTime};
 use serde::Deserialize;
 use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::i18n::Message;
 
 /// Pagination query parameters
 /// Equivalent de: PaginationRequest en Symfony
-#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+#[derive(Debug, Deserialize, Validate, IntoParams, ToSchema)]
 pub struct PaginationQuery {
     /// Page number (1-based)
     #[serde(default = "default_page")]
     #[param(minimum = 1, default = 1)]
+    #[validate(range(min = 1, code = "pagination.page.range"))]
     pub page: u64,
 
     /// Items per page
     #[serde(default = "default_per_page")]
     #[param(minimum = 1, maximum = 100, default = 10)]
+    #[validate(range(min = 1, max = 100, code = "pagination.per_page.range"))]
     pub per_page: u64,
 }
 
@@ -47,3 +55,121 @@ impl PaginationQuery {
         self.per_page
     }
 }
+
+/// Paramètre de pagination par curseur (keyset) des listes de posts
+/// `?cursor=` (vide) demande la première page, puis on suit `meta.next_cursor`/`meta.prev_cursor`
+#[derive(Debug, Default, Deserialize, Validate, IntoParams)]
+pub struct CursorQuery {
+    /// Opaque cursor; enables cursor mode instead of page numbers (empty = first page)
+    pub cursor: Option<String>,
+}
+
+/// Sens de parcours d'un curseur
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorDirection {
+    /// Éléments plus anciens que la position (page suivante)
+    After,
+    /// Éléments plus récents que la position (page précédente)
+    Before,
+}
+
+/// Position dans une liste triée par (created_at DESC, id DESC)
+/// Encodé en base64url pour rester opaque côté client
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cursor {
+    pub direction: CursorDirection,
+    pub created_at: NaiveDateTime,
+    pub id: i32,
+}
+
+impl Cursor {
+    pub fn after(created_at: NaiveDateTime, id: i32) -> Self {
+        Self { direction: CursorDirection::After, created_at, id }
+    }
+
+    pub fn before(created_at: NaiveDateTime, id: i32) -> Self {
+        Self { direction: CursorDirection::Before, created_at, id }
+    }
+
+    /// Encode le curseur: base64url("a|<timestamp ns>|<id>")
+    pub fn encode(&self) -> String {
+        let direction = match self.direction {
+            CursorDirection::After => 'a',
+            CursorDirection::Before => 'b',
+        };
+        let nanos = self.created_at.and_utc().timestamp_nanos_opt().unwrap_or_default();
+        URL_SAFE_NO_PAD.encode(format!("{direction}|{nanos}|{}", self.id))
+    }
+
+    /// Décode un curseur reçu du client (None si invalide)
+    pub fn decode(raw: &str) -> Option<Self> {
+        let bytes = URL_SAFE_NO_PAD.decode(raw).ok()?;
+        let text = String::from_utf8(bytes).ok()?;
+        let mut parts = text.split('|');
+
+        let direction = match parts.next()? {
+            "a" => CursorDirection::After,
+            "b" => CursorDirection::Before,
+            _ => return None,
+        };
+        let nanos = parts.next()?.parse().ok()?;
+        let id = parts.next()?.parse().ok()?;
+        if parts.next().is_some() {
+            return None;
+        }
+
+        let created_at = DateTime::from_timestamp_nanos(nanos).naive_utc();
+        Some(Self { direction, created_at, id })
+    }
+}
+
+/// Mode de pagination demandé pour une liste
+#[derive(Debug, Clone, Copy)]
+pub enum PageRequest {
+    /// Numéro de page + total (mode historique)
+    Offset { page: u64, per_page: u64 },
+    /// Curseur keyset sur (created_at, id), sans count()
+    Cursor { cursor: Option<Cursor>, per_page: u64 },
+}
+
+impl PageRequest {
+    /// Choisit le mode selon la présence de `?cursor=`
+    pub fn new(pagination: &PaginationQuery, cursor: &CursorQuery) -> Result<Self, Message> {
+        let per_page = pagination.per_page;
+
+        match cursor.cursor.as_deref() {
+            None => Ok(Self::Offset { page: pagination.page, per_page }),
+            Some("") => Ok(Self::Cursor { cursor: None, per_page }),
+            Some(raw) => Cursor::decode(raw)
+                .map(|cursor| Self::Cursor { cursor: Some(cursor), per_page })
+                .ok_or_else(|| Message::new("pagination.cursor.invalid")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_roundtrip() {
+        let created_at = DateTime::from_timestamp_nanos(1_734_000_000_123_456_789).naive_utc();
+        let cursor = Cursor::before(created_at, 42);
+
+        assert_eq!(Cursor::decode(&cursor.encode()), Some(cursor));
+    }
+
+    #[test]
+    fn test_page_request_modes() {
+        let pagination = PaginationQuery::default();
+        let offset = PageRequest::new(&pagination, &CursorQuery::default()).unwrap();
+        assert!(matches!(offset, PageRequest::Offset { page: 1, per_page: 10 }));
+
+        let first = CursorQuery { cursor: Some(String::new()) };
+        let cursor = PageRequest::new(&pagination, &first).unwrap();
+        assert!(matches!(cursor, PageRequest::Cursor { cursor: None, .. }));
+
+        let garbage = CursorQuery { cursor: Some("not-a-cursor".to_string()) };
+        assert!(PageRequest::new(&pagination, &garbage).is_err());
+    }
+}
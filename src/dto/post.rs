@@ -5,7 +5,8 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use validator::Validate;
 
-use crate::entities::post::{PostMetadata, PostSettings, SeoMetadata, Tag};
+use crate::entities::post::{self, PostMetadata, PostSettings, PostStatus, SeoMetadata, Tag};
+use crate::validation::validate_slug;
 
 // ============================================================================
 // INPUT DTOs (Request Bodies)
@@ -14,23 +15,23 @@ use crate::entities::post::{PostMetadata, PostSettings, SeoMetadata, Tag};
 /// DTO pour créer un tag (nested input)
 #[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateTagDto {
-    #[validate(length(min = 1, max = 50, message = "Le nom du tag doit faire entre 1 et 50 caractères"))]
+    #[validate(length(min = 1, max = 50, code = "tag.name.length"))]
     pub name: String,
 
-    #[validate(length(max = 7, message = "La couleur doit être un code hex (ex: #FF0000)"))]
+    #[validate(length(max = 7, code = "tag.color.format"))]
     pub color: Option<String>,
 }
 
 /// DTO pour les métadonnées SEO (nested input)
 #[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateSeoMetadataDto {
-    #[validate(length(max = 70, message = "Le meta title ne doit pas dépasser 70 caractères"))]
+    #[validate(length(max = 70, code = "seo.meta_title.length"))]
     pub meta_title: Option<String>,
 
-    #[validate(length(max = 160, message = "La meta description ne doit pas dépasser 160 caractères"))]
+    #[validate(length(max = 160, code = "seo.meta_description.length"))]
     pub meta_description: Option<String>,
 
-    #[validate(length(max = 10, message = "Maximum 10 keywords autorisés"))]
+    #[validate(length(max = 10, code = "seo.keywords.count"))]
     pub keywords: Option<Vec<String>>,
 }
 
@@ -43,15 +44,15 @@ pub struct CreatePostSettingsDto {
     #[serde(default)]
     pub featured: bool,
 
-    #[validate(range(min = 1, max = 60, message = "Le temps de lecture doit être entre 1 et 60 minutes"))]
+    #[validate(range(min = 1, max = 60, code = "settings.reading_time.range"))]
     pub reading_time_minutes: Option<i32>,
 }
 
 /// DTO pour les metadata complètes (nested input)
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreatePostMetadataDto {
     /// Liste des tags (validation nested)
-    #[validate(length(max = 10, message = "Maximum 10 tags autorisés"))]
+    #[validate(length(max = 10, code = "post.tags.count"))]
     #[validate(nested)]
     pub tags: Option<Vec<CreateTagDto>>,
 
@@ -67,14 +68,21 @@ pub struct CreatePostMetadataDto {
 /// DTO pour créer un post (INPUT principal)
 #[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct CreatePostDto {
-    #[validate(length(min = 3, max = 255, message = "Le titre doit faire entre 3 et 255 caractères"))]
+    #[validate(length(min = 3, max = 255, code = "post.title.length"))]
     pub title: String,
 
-    #[validate(length(min = 10, message = "Le contenu doit faire au moins 10 caractères"))]
+    #[validate(length(min = 10, code = "post.content.length"))]
     pub content: String,
 
-    #[validate(range(min = 1, message = "L'ID auteur doit être positif"))]
-    pub author_id: i32,
+    /// Slug d'URL (optionnel) : par défaut calculé depuis le titre, avec suffixe -2, -3...
+    /// s'il est déjà pris. Un slug explicite déjà utilisé renvoie 409.
+    #[validate(custom(function = "validate_slug"))]
+    pub slug: Option<String>,
+
+    /// Auteur du post (optionnel) : par défaut l'utilisateur authentifié.
+    /// Un ID différent n'est accepté que pour un admin.
+    #[validate(range(min = 1, code = "post.author_id.range"))]
+    pub author_id: Option<i32>,
 
     /// Metadata avec objets imbriqués (tags, seo, settings)
     #[validate(nested)]
@@ -82,15 +90,21 @@ pub struct CreatePostDto {
 
     #[serde(default)]
     pub published: bool,
+
+    /// Statut explicite (prioritaire sur `published`)
+    pub status: Option<PostStatus>,
+
+    /// Publication programmée (UTC) : une date future rend le post "scheduled"
+    pub publish_at: Option<chrono::NaiveDateTime>,
 }
 
 /// DTO pour modifier un post (INPUT)
 #[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct UpdatePostDto {
-    #[validate(length(min = 3, max = 255, message = "Le titre doit faire entre 3 et 255 caractères"))]
+    #[validate(length(min = 3, max = 255, code = "post.title.length"))]
     pub title: Option<String>,
 
-    #[validate(length(min = 10, message = "Le contenu doit faire au moins 10 caractères"))]
+    #[validate(length(min = 10, code = "post.content.length"))]
     pub content: Option<String>,
 
     /// Metadata avec objets imbriqués
@@ -98,6 +112,12 @@ pub struct UpdatePostDto {
     pub metadata: Option<CreatePostMetadataDto>,
 
     pub published: Option<bool>,
+
+    /// Statut explicite (prioritaire sur `published` et `publish_at`)
+    pub status: Option<PostStatus>,
+
+    /// Publication programmée (UTC) : une date future rend le post "scheduled"
+    pub publish_at: Option<chrono::NaiveDateTime>,
 }
 
 // ============================================================================
@@ -148,8 +168,11 @@ pub struct AuthorResponse {
 pub struct PostResponse {
     pub id: i32,
     pub title: String,
+    pub slug: String,
     pub content: String,
     pub published: bool,
+    pub status: PostStatus,
+    pub publish_at: Option<chrono::NaiveDateTime>,
     pub created_at: chrono::NaiveDateTime,
     pub updated_at: Option<chrono::NaiveDateTime>,
 
@@ -165,12 +188,17 @@ pub struct PostResponse {
 pub struct PostListItemResponse {
     pub id: i32,
     pub title: String,
+    pub slug: String,
     /// Extrait du contenu (100 premiers caractères)
     pub excerpt: String,
     pub published: bool,
+    pub status: PostStatus,
+    pub publish_at: Option<chrono::NaiveDateTime>,
     pub created_at: chrono::NaiveDateTime,
     pub author: AuthorResponse,
     pub tags: Vec<TagResponse>,
+    /// Nombre de commentaires (réponses incluses)
+    pub comment_count: u64,
 }
 
 // ============================================================================
@@ -237,8 +265,11 @@ impl PostResponse {
         Self {
             id: post.id,
             title: post.title,
+            slug: post.slug,
             content: post.content,
             published: post.published,
+            status: post.status,
+            publish_at: post.publish_at,
             created_at: post.created_at,
             updated_at: post.updated_at,
             author: author.into(),
@@ -248,10 +279,11 @@ impl PostResponse {
 }
 
 impl PostListItemResponse {
-    /// Crée une réponse liste à partir du post et de son auteur
+    /// Crée une réponse liste à partir du post, de son auteur et de son nombre de commentaires
     pub fn from_post_with_author(
         post: crate::entities::post::Model,
         author: crate::entities::user::Model,
+        comment_count: u64,
     ) -> Self {
         let metadata = post.get_metadata();
         let excerpt = if post.content.len() > 100 {
@@ -263,11 +295,15 @@ impl PostListItemResponse {
         Self {
             id: post.id,
             title: post.title,
+            slug: post.slug,
             excerpt,
             published: post.published,
+            status: post.status,
+            publish_at: post.publish_at,
             created_at: post.created_at,
             author: author.into(),
             tags: metadata.tags.into_iter().map(Into::into).collect(),
+            comment_count,
         }
     }
 }
@@ -328,12 +364,47 @@ impl CreatePostMetadataDto {
     }
 }
 
-impl Default for CreatePostMetadataDto {
-    fn default() -> Self {
+// ============================================================================
+// PATCH
+// ============================================================================
+
+impl UpdatePostDto {
+    /// Champs qu'un PATCH ne peut pas supprimer
+    pub const PATCH_REQUIRED: [&'static str; 3] = ["title", "content", "status"];
+
+    /// Document JSON d'un post sur lequel s'applique un PATCH (mêmes champs que le PUT)
+    pub fn patch_document(post: &post::Model) -> serde_json::Value {
+        serde_json::json!({
+            "title": post.title,
+            "content": post.content,
+            "status": post.status,
+            "publish_at": post.publish_at,
+            "metadata": post.get_metadata(),
+        })
+    }
+
+    /// Ne garde du document patché que ce qui diffère du post courant
+    ///
+    /// Supprimer `publish_at` d'un post programmé sans changer son statut
+    /// le repasse en brouillon.
+    pub fn changes_from(self, post: &post::Model) -> Self {
+        let current_metadata = serde_json::to_value(post.get_metadata()).ok();
+        let metadata = self.metadata.unwrap_or_default();
+        let status = match self.status {
+            Some(status) if status != post.status => Some(status),
+            _ if post.status == PostStatus::Scheduled && self.publish_at.is_none() => {
+                Some(PostStatus::Draft)
+            }
+            _ => None,
+        };
+
         Self {
-            tags: None,
-            seo: None,
-            settings: None,
+            title: self.title.filter(|title| *title != post.title),
+            content: self.content.filter(|content| *content != post.content),
+            metadata: (Some(metadata.to_json()) != current_metadata).then_some(metadata),
+            published: None,
+            status,
+            publish_at: self.publish_at.filter(|at| Some(*at) != post.publish_at),
         }
     }
 }
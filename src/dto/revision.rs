@@ -0,0 +1,112 @@
+// src/dto/revision.rs
+// DTOs pour l'historique des révisions d'un post
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::dto::{AuthorResponse, PostMetadataResponse};
+use crate::entities::post::PostMetadata;
+use crate::services::{LineChange, RevisionComparison, RevisionWithEditor};
+
+/// Nature d'une ligne de diff
+#[derive(Debug, Clone, Copy, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffOp {
+    /// Ligne identique dans la révision et la version courante
+    Equal,
+    /// Ligne présente uniquement dans la révision
+    Removed,
+    /// Ligne présente uniquement dans la version courante
+    Added,
+}
+
+/// Une ligne de diff (révision -> version courante)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DiffLineResponse {
+    pub op: DiffOp,
+    pub line: String,
+}
+
+/// Diffs ligne à ligne entre une révision et la version courante
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RevisionDiffResponse {
+    pub title: Vec<DiffLineResponse>,
+    pub content: Vec<DiffLineResponse>,
+    /// Diff du JSON metadata indenté
+    pub metadata: Vec<DiffLineResponse>,
+}
+
+/// Response DTO pour une révision de la liste GET /posts/:id/revisions
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(example = json!({
+    "revision": 2,
+    "post_id": 1,
+    "title": "Mon article",
+    "created_at": "2024-12-20T10:30:00",
+    "edited_by": { "id": 1, "username": "john", "email": "john@example.com" }
+}))]
+pub struct PostRevisionResponse {
+    /// Numéro de révision (croissant par post)
+    pub revision: i32,
+    pub post_id: i32,
+    pub title: String,
+    /// Date à laquelle cette version a été remplacée
+    pub created_at: chrono::NaiveDateTime,
+    /// Utilisateur dont la modification a remplacé cette version (null s'il a été supprimé)
+    pub edited_by: Option<AuthorResponse>,
+}
+
+/// Response DTO pour le détail d'une révision, avec son diff
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PostRevisionDetailResponse {
+    #[serde(flatten)]
+    pub summary: PostRevisionResponse,
+    pub content: String,
+    pub metadata: PostMetadataResponse,
+    /// Diff de cette révision vers la version courante du post
+    pub diff: RevisionDiffResponse,
+}
+
+impl From<LineChange> for DiffLineResponse {
+    fn from(change: LineChange) -> Self {
+        let (op, line) = match change {
+            LineChange::Equal(line) => (DiffOp::Equal, line),
+            LineChange::Removed(line) => (DiffOp::Removed, line),
+            LineChange::Added(line) => (DiffOp::Added, line),
+        };
+        Self { op, line }
+    }
+}
+
+impl From<RevisionWithEditor> for PostRevisionResponse {
+    fn from(rwe: RevisionWithEditor) -> Self {
+        Self {
+            revision: rwe.revision.revision,
+            post_id: rwe.revision.post_id,
+            title: rwe.revision.title,
+            created_at: rwe.revision.created_at,
+            edited_by: rwe.editor.map(Into::into),
+        }
+    }
+}
+
+impl From<RevisionComparison> for PostRevisionDetailResponse {
+    fn from(comparison: RevisionComparison) -> Self {
+        let content = comparison.revision.revision.content.clone();
+        let metadata: PostMetadata =
+            serde_json::from_value(comparison.revision.revision.metadata.clone())
+                .unwrap_or_default();
+        let lines = |changes: Vec<LineChange>| changes.into_iter().map(Into::into).collect();
+
+        Self {
+            summary: comparison.revision.into(),
+            content,
+            metadata: metadata.into(),
+            diff: RevisionDiffResponse {
+                title: lines(comparison.title),
+                content: lines(comparison.content),
+                metadata: lines(comparison.metadata),
+            },
+        }
+    }
+}
@@ -0,0 +1,30 @@
+// src/dto/search.rs
+// DTOs de la recherche plein texte (GET /posts/search)
+
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::dto::PostListItemResponse;
+
+/// Paramètres de recherche
+#[derive(Debug, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PostSearchQuery {
+    /// Words searched in title, content, tags and SEO keywords (all words must match,
+    /// each word also matches as a prefix)
+    #[validate(length(min = 1, max = 200, code = "search.q.length"))]
+    #[param(example = "rust api")]
+    pub q: String,
+}
+
+/// Résultat de recherche: le post (format liste) + pertinence et extrait
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PostSearchResultResponse {
+    #[serde(flatten)]
+    pub post: PostListItemResponse,
+    /// Pertinence (BM25, plus élevé = plus pertinent)
+    pub score: f64,
+    /// Extrait HTML autour des termes trouvés: texte du post échappé, termes entourés de `<mark>`...`</mark>`
+    pub snippet: String,
+}
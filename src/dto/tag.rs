@@ -0,0 +1,31 @@
+// src/dto/tag.rs
+// DTOs pour les tags normalisés
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::services::TagWithCount;
+
+/// Response DTO pour un tag de la liste GET /tags
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(example = json!({
+    "name": "rust",
+    "color": "#DEA584",
+    "post_count": 12
+}))]
+pub struct TagListItemResponse {
+    pub name: String,
+    pub color: Option<String>,
+    /// Nombre de posts portant ce tag
+    pub post_count: u64,
+}
+
+impl From<TagWithCount> for TagListItemResponse {
+    fn from(tag: TagWithCount) -> Self {
+        Self {
+            name: tag.name,
+            color: tag.color,
+            post_count: tag.post_count,
+        }
+    }
+}
@@ -5,24 +5,34 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use validator::Validate;
 
+use crate::entities::user::Role;
+use crate::validation::validate_password_strength;
+
 /// Request DTO for creating a new user
 /// Equivalent de: CreateUserRequest en Symfony
 #[derive(Debug, Deserialize, Validate, ToSchema)]
 #[schema(example = json!({
     "username": "johndoe",
-    "email": "john@example.com"
+    "email": "john@example.com",
+    "password": "S3cure-password"
 }))]
 pub struct CreateUserDto {
     /// Username (3-50 characters)
-    #[validate(length(min = 3, max = 50, message = "Username must be between 3 and 50 characters"))]
+    #[validate(length(min = 3, max = 50, code = "user.username.length"))]
     #[schema(min_length = 3, max_length = 50)]
     pub username: String,
 
     /// Valid email address
-    #[validate(email(message = "Invalid email format"))]
-    #[validate(length(max = 255, message = "Email must not exceed 255 characters"))]
+    #[validate(email(code = "user.email.format"))]
+    #[validate(length(max = 255, code = "user.email.length"))]
     #[schema(format = "email", max_length = 255)]
     pub email: String,
+
+    /// Plain password (8-128 characters, upper/lower case, digit and symbol), stored hashed
+    #[validate(length(min = 8, max = 128, code = "user.password.length"))]
+    #[validate(custom(function = "validate_password_strength"))]
+    #[schema(format = "password", min_length = 8, max_length = 128)]
+    pub password: String,
 }
 
 /// Request DTO for updating a user
@@ -34,29 +44,60 @@ pub struct CreateUserDto {
 }))]
 pub struct UpdateUserDto {
     /// Username (3-50 characters) - optional
-    #[validate(length(min = 3, max = 50, message = "Username must be between 3 and 50 characters"))]
+    #[validate(length(min = 3, max = 50, code = "user.username.length"))]
     #[schema(min_length = 3, max_length = 50)]
     pub username: Option<String>,
 
     /// Valid email address - optional
-    #[validate(email(message = "Invalid email format"))]
+    #[validate(email(code = "user.email.format"))]
     #[schema(format = "email", max_length = 255)]
     pub email: Option<String>,
 }
 
+/// Request DTO for changing a user's role (admin only)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[schema(example = json!({ "role": "editor" }))]
+pub struct UpdateUserRoleDto {
+    /// New role
+    pub role: Role,
+}
+
+/// Request DTO for changing the current user's password
+/// Equivalent de: ChangePasswordFormType en Symfony
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[schema(example = json!({
+    "current_password": "S3cure-password",
+    "new_password": "N3w-S3cure-password"
+}))]
+pub struct ChangePasswordDto {
+    /// Current password (re-authentication)
+    #[validate(length(min = 1, code = "auth.current_password.required"))]
+    #[schema(format = "password")]
+    pub current_password: String,
+
+    /// New password (same rules as registration)
+    #[validate(length(min = 8, max = 128, code = "user.password.length"))]
+    #[validate(custom(function = "validate_password_strength"))]
+    #[schema(format = "password", min_length = 8, max_length = 128)]
+    pub new_password: String,
+}
+
 /// Response DTO for user data
 /// Equivalent de: UserResponse en Symfony
+/// Never contains the password hash
 #[derive(Debug, Serialize, ToSchema)]
 #[schema(example = json!({
     "id": 1,
     "username": "johndoe",
     "email": "john@example.com",
+    "role": "author",
     "created_at": "2024-01-15T10:30:00"
 }))]
 pub struct UserResponse {
     pub id: i32,
     pub username: String,
     pub email: String,
+    pub role: Role,
     pub created_at: chrono::NaiveDateTime,
 }
 
@@ -66,7 +107,29 @@ impl From<crate::entities::user::Model> for UserResponse {
             id: user.id,
             username: user.username,
             email: user.email,
+            role: user.role,
             created_at: user.created_at,
         }
     }
 }
+
+impl UpdateUserDto {
+    /// Fields a PATCH cannot remove
+    pub const PATCH_REQUIRED: [&'static str; 2] = ["username", "email"];
+
+    /// JSON document of a user that a PATCH applies to (same fields as the PUT)
+    pub fn patch_document(user: &crate::entities::user::Model) -> serde_json::Value {
+        serde_json::json!({
+            "username": user.username,
+            "email": user.email,
+        })
+    }
+
+    /// Keeps only the fields of the patched document that differ from the current user
+    pub fn changes_from(self, user: &crate::entities::user::Model) -> Self {
+        Self {
+            username: self.username.filter(|username| *username != user.username),
+            email: self.email.filter(|email| *email != user.email),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+// src/entities/api_key.rs
+// Entity ApiKey : clés d'API des clients machine (jobs d'intégration)
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Scope accordé à une clé d'API (stocké en JSON dans `scopes`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum ApiScope {
+    /// Lecture des posts
+    #[serde(rename = "posts:read")]
+    PostsRead,
+    /// Création / modification / suppression des posts
+    #[serde(rename = "posts:write")]
+    PostsWrite,
+    /// Création / modification / suppression de ses commentaires
+    #[serde(rename = "comments:write")]
+    CommentsWrite,
+}
+
+/// ApiKey Entity
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "api_keys")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+
+    /// Foreign key vers User (propriétaire de la clé)
+    pub user_id: i32,
+
+    /// Nom donné par l'utilisateur (ex: "CI import")
+    #[sea_orm(column_type = "String(StringLen::N(255))")]
+    pub name: String,
+
+    /// Début de la clé en clair, pour l'identifier
+    #[sea_orm(column_type = "String(StringLen::N(16))")]
+    pub prefix: String,
+
+    /// SHA-256 de la clé (jamais sérialisé)
+    #[sea_orm(column_type = "String(StringLen::N(64))", unique)]
+    #[serde(skip_serializing)]
+    pub key_hash: String,
+
+    /// Liste JSON des scopes
+    #[sea_orm(column_type = "Json")]
+    pub scopes: serde_json::Value,
+
+    pub last_used_at: Option<DateTime>,
+
+    pub revoked_at: Option<DateTime>,
+
+    pub created_at: DateTime,
+}
+
+impl Model {
+    /// Parse le JSON scopes en liste typée
+    pub fn get_scopes(&self) -> Vec<ApiScope> {
+        serde_json::from_value(self.scopes.clone()).unwrap_or_default()
+    }
+
+    /// La clé accorde-t-elle ce scope ?
+    pub fn has_scope(&self, scope: ApiScope) -> bool {
+        self.get_scopes().contains(&scope)
+    }
+}
+
+/// Relations
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::user::Entity",
+        from = "Column::UserId",
+        to = "super::user::Column::Id"
+    )]
+    User,
+}
+
+/// Relation: ApiKey belongs to User
+impl Related<super::user::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
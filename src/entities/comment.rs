@@ -0,0 +1,64 @@
+// src/entities/comment.rs
+// Entity Comment : commentaires d'un post, avec réponses (parent_id)
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Comment Entity
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "comments")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+
+    /// Foreign key vers Post
+    pub post_id: i32,
+
+    /// Foreign key vers User
+    pub author_id: i32,
+
+    /// Commentaire auquel celui-ci répond (None = commentaire racine)
+    pub parent_id: Option<i32>,
+
+    #[sea_orm(column_type = "Text")]
+    pub body: String,
+
+    pub created_at: DateTime,
+
+    pub updated_at: Option<DateTime>,
+}
+
+/// Relations
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::post::Entity",
+        from = "Column::PostId",
+        to = "super::post::Column::Id"
+    )]
+    Post,
+    #[sea_orm(
+        belongs_to = "super::user::Entity",
+        from = "Column::AuthorId",
+        to = "super::user::Column::Id"
+    )]
+    Author,
+    #[sea_orm(belongs_to = "Entity", from = "Column::ParentId", to = "Column::Id")]
+    Parent,
+}
+
+/// Relation: Comment appartient à Post
+impl Related<super::post::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Post.def()
+    }
+}
+
+/// Relation: Comment appartient à User (auteur)
+impl Related<super::user::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Author.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -1,5 +1,12 @@
 // src/entities/mod.rs
 // Equivalent de: src/Entity/ en Symfony
 
+pub mod api_key;
+pub mod comment;
 pub mod post;
+pub mod post_revision;
+pub mod post_slug_redirect;
+pub mod post_tag;
+pub mod refresh_token;
+pub mod tag;
 pub mod user;
@@ -3,10 +3,33 @@
 
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Statut de publication d'un post
+/// `published` (booléen historique) vaut true uniquement pour Published
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(20))")]
+#[serde(rename_all = "lowercase")]
+pub enum PostStatus {
+    /// Brouillon, non visible dans les posts publiés
+    #[default]
+    #[sea_orm(string_value = "draft")]
+    Draft,
+    /// Sera publié automatiquement à `publish_at`
+    #[sea_orm(string_value = "scheduled")]
+    Scheduled,
+    #[sea_orm(string_value = "published")]
+    Published,
+    /// Retiré de la publication
+    #[sea_orm(string_value = "archived")]
+    Archived,
+}
 
 /// Metadata imbriquée stockée en JSON
 /// Contient les tags et autres métadonnées du post
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PostMetadata {
     /// Liste des tags
     pub tags: Vec<Tag>,
@@ -39,16 +62,6 @@ pub struct PostSettings {
     pub reading_time_minutes: Option<i32>,
 }
 
-impl Default for PostMetadata {
-    fn default() -> Self {
-        Self {
-            tags: vec![],
-            seo: None,
-            settings: None,
-        }
-    }
-}
-
 /// Post Entity
 #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "posts")]
@@ -59,6 +72,10 @@ pub struct Model {
     #[sea_orm(column_type = "String(StringLen::N(255))")]
     pub title: String,
 
+    /// Identifiant d'URL unique (ex: mon-premier-post)
+    #[sea_orm(unique)]
+    pub slug: String,
+
     #[sea_orm(column_type = "Text")]
     pub content: String,
 
@@ -71,9 +88,18 @@ pub struct Model {
 
     pub published: bool,
 
+    /// Statut de publication (draft, scheduled, published, archived)
+    pub status: PostStatus,
+
+    /// Date de publication programmée (scheduled) ou effective (published)
+    pub publish_at: Option<DateTime>,
+
     pub created_at: DateTime,
 
     pub updated_at: Option<DateTime>,
+
+    /// Incrémentée à chaque modification (ETag / If-Match)
+    pub version: i32,
 }
 
 impl Model {
@@ -86,6 +112,42 @@ impl Model {
     pub fn get_tags(&self) -> Vec<Tag> {
         self.get_metadata().tags
     }
+
+    /// Les commentaires sont fermés par défaut (comme `CreatePostSettingsDto`)
+    pub fn allows_comments(&self) -> bool {
+        self.get_metadata()
+            .settings
+            .is_some_and(|settings| settings.allow_comments)
+    }
+}
+
+/// Longueur maximale d'un slug
+pub const SLUG_MAX_LENGTH: usize = 100;
+
+/// Slug d'URL à partir d'un titre: minuscules, accents translittérés, mots séparés par `-`
+/// Ex: "Découvrir Rust !" -> "decouvrir-rust"
+pub fn slugify(title: &str) -> String {
+    let slug: String = slug::slugify(title).chars().take(SLUG_MAX_LENGTH).collect();
+    let slug = slug.trim_matches('-');
+
+    if slug.is_empty() {
+        "post".to_string()
+    } else {
+        slug.to_string()
+    }
+}
+
+/// Variante `base-n` d'un slug déjà pris, raccourcie pour ne pas dépasser SLUG_MAX_LENGTH
+/// Ex: ("mon-post", 2) -> "mon-post-2"
+pub fn suffixed_slug(base: &str, n: u32) -> String {
+    let suffix = format!("-{n}");
+    let stem: String = base.chars().take(SLUG_MAX_LENGTH - suffix.len()).collect();
+    format!("{}{suffix}", stem.trim_end_matches('-'))
+}
+
+/// Un slug fourni par le client doit déjà être sous forme normalisée
+pub fn is_valid_slug(slug: &str) -> bool {
+    !slug.is_empty() && slug.len() <= SLUG_MAX_LENGTH && slugify(slug) == slug
 }
 
 /// Relations
@@ -97,6 +159,12 @@ pub enum Relation {
         to = "super::user::Column::Id"
     )]
     Author,
+    #[sea_orm(has_many = "super::post_tag::Entity")]
+    PostTag,
+    #[sea_orm(has_many = "super::post_slug_redirect::Entity")]
+    SlugRedirect,
+    #[sea_orm(has_many = "super::post_revision::Entity")]
+    Revision,
 }
 
 /// Relation inverse: Post appartient à User
@@ -106,4 +174,71 @@ impl Related<super::user::Entity> for Entity {
     }
 }
 
-impl ActiveModelBehavior for ActiveModel {}
+impl Related<super::post_tag::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PostTag.def()
+    }
+}
+
+/// Relation many-to-many: Post <-> Tag via post_tags
+impl Related<super::tag::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::post_tag::Relation::Tag.def()
+    }
+
+    fn via() -> Option<RelationDef> {
+        Some(super::post_tag::Relation::Post.def().rev())
+    }
+}
+
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    /// Chaque modification incrémente la version (verrouillage optimiste)
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        if !insert && let sea_orm::ActiveValue::Unchanged(version) = self.version {
+            self.version = sea_orm::ActiveValue::Set(version + 1);
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify_transliterates_and_trims() {
+        assert_eq!(slugify("Découvrir Rust !"), "decouvrir-rust");
+        assert_eq!(slugify("  Ça   marche -- bien  "), "ca-marche-bien");
+        assert_eq!(slugify("!!!"), "post");
+        assert_eq!(slugify(&"a".repeat(300)).len(), SLUG_MAX_LENGTH);
+    }
+
+    #[test]
+    fn test_suffixed_slug_fits_max_length() {
+        assert_eq!(suffixed_slug("mon-post", 2), "mon-post-2");
+
+        let long = slugify(&"a".repeat(300));
+        let suffixed = suffixed_slug(&long, 12);
+        assert_eq!(suffixed.len(), SLUG_MAX_LENGTH);
+        assert!(suffixed.ends_with("a-12"));
+        assert!(is_valid_slug(&suffixed));
+
+        // Pas de double tiret quand la coupure tombe sur un séparateur
+        let base = format!("{}-fin", "a".repeat(SLUG_MAX_LENGTH - 3));
+        assert_eq!(suffixed_slug(&base, 2), format!("{}-2", "a".repeat(SLUG_MAX_LENGTH - 3)));
+    }
+
+    #[test]
+    fn test_is_valid_slug() {
+        assert!(is_valid_slug("mon-premier-post"));
+        assert!(is_valid_slug("post-2"));
+        assert!(!is_valid_slug("Mon-Post"));
+        assert!(!is_valid_slug("mon--post"));
+        assert!(!is_valid_slug("-post"));
+        assert!(!is_valid_slug(""));
+    }
+}
@@ -0,0 +1,67 @@
+// src/entities/post_revision.rs
+// Entity PostRevision : version précédente d'un post, enregistrée à chaque modification
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// PostRevision Entity
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "post_revisions")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+
+    /// Foreign key vers Post
+    pub post_id: i32,
+
+    /// Numéro de révision, croissant par post
+    pub revision: i32,
+
+    #[sea_orm(column_type = "String(StringLen::N(255))")]
+    pub title: String,
+
+    #[sea_orm(column_type = "Text")]
+    pub content: String,
+
+    #[sea_orm(column_type = "Json")]
+    pub metadata: serde_json::Value,
+
+    /// Utilisateur dont la modification a remplacé cette version (None si supprimé)
+    pub edited_by: Option<i32>,
+
+    pub created_at: DateTime,
+}
+
+/// Relations
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::post::Entity",
+        from = "Column::PostId",
+        to = "super::post::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Post,
+    #[sea_orm(
+        belongs_to = "super::user::Entity",
+        from = "Column::EditedBy",
+        to = "super::user::Column::Id",
+        on_delete = "SetNull"
+    )]
+    Editor,
+}
+
+impl Related<super::post::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Post.def()
+    }
+}
+
+/// Relation: auteur de la modification
+impl Related<super::user::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Editor.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
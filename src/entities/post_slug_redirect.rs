@@ -0,0 +1,39 @@
+// src/entities/post_slug_redirect.rs
+// Entity PostSlugRedirect : anciens slugs d'un post (redirigés vers le slug courant)
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// PostSlugRedirect Entity
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "post_slug_redirects")]
+pub struct Model {
+    /// Ancien slug (unique parmi les anciens slugs)
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub slug: String,
+
+    /// Foreign key vers Post
+    pub post_id: i32,
+
+    pub created_at: DateTime,
+}
+
+/// Relations
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::post::Entity",
+        from = "Column::PostId",
+        to = "super::post::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Post,
+}
+
+impl Related<super::post::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Post.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
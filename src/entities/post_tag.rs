@@ -0,0 +1,65 @@
+// src/entities/post_tag.rs
+// Entity PostTag : table de liaison posts <-> tags
+
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::{Query, SelectStatement};
+use serde::{Deserialize, Serialize};
+
+/// PostTag Entity
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "post_tags")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub post_id: i32,
+
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub tag_id: i32,
+}
+
+/// Relations
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::post::Entity",
+        from = "Column::PostId",
+        to = "super::post::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Post,
+    #[sea_orm(
+        belongs_to = "super::tag::Entity",
+        from = "Column::TagId",
+        to = "super::tag::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Tag,
+}
+
+impl Related<super::post::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Post.def()
+    }
+}
+
+impl Related<super::tag::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Tag.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// Sous-requête des IDs de posts portant le tag `name`
+/// Usage: `post::Column::Id.in_subquery(post_tag::post_ids_with_tag("rust"))`
+pub fn post_ids_with_tag(name: &str) -> SelectStatement {
+    Query::select()
+        .column((Entity, Column::PostId))
+        .from(Entity)
+        .inner_join(
+            super::tag::Entity,
+            Expr::col((super::tag::Entity, super::tag::Column::Id))
+                .equals((Entity, Column::TagId)),
+        )
+        .and_where(Expr::col((super::tag::Entity, super::tag::Column::Name)).eq(name))
+        .to_owned()
+}
@@ -0,0 +1,54 @@
+// src/entities/refresh_token.rs
+// Entity RefreshToken : une ligne par refresh token émis (sessions utilisateur)
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// RefreshToken Entity
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "refresh_tokens")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+
+    /// Foreign key vers User
+    pub user_id: i32,
+
+    /// Famille de rotation : tous les tokens issus d'un même login
+    #[sea_orm(column_type = "String(StringLen::N(64))")]
+    pub family_id: String,
+
+    /// SHA-256 du token (jamais sérialisé)
+    #[sea_orm(column_type = "String(StringLen::N(64))", unique)]
+    #[serde(skip_serializing)]
+    pub token_hash: String,
+
+    pub expires_at: DateTime,
+
+    /// Date à laquelle le token a été échangé (il ne doit plus jamais être présenté)
+    pub rotated_at: Option<DateTime>,
+
+    pub revoked_at: Option<DateTime>,
+
+    pub created_at: DateTime,
+}
+
+/// Relations
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::user::Entity",
+        from = "Column::UserId",
+        to = "super::user::Column::Id"
+    )]
+    User,
+}
+
+/// Relation: RefreshToken belongs to User
+impl Related<super::user::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -3,6 +3,27 @@
 
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Rôle d'un utilisateur (stocké en string)
+/// Equivalent de: ROLE_ADMIN / ROLE_EDITOR / ROLE_AUTHOR en Symfony
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(20))")]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    /// Gère tous les utilisateurs et tous les posts
+    #[sea_orm(string_value = "admin")]
+    Admin,
+    /// Gère tous les posts
+    #[sea_orm(string_value = "editor")]
+    Editor,
+    /// Gère uniquement ses propres posts
+    #[default]
+    #[sea_orm(string_value = "author")]
+    Author,
+}
 
 /// User Entity
 /// Equivalent de: #[ORM\Entity] class User en Symfony/Doctrine
@@ -24,9 +45,22 @@ pub struct Model {
     #[sea_orm(column_type = "String(StringLen::N(255))", unique)]
     pub email: String,
 
+    /// Hash Argon2 du mot de passe (jamais sérialisé)
+    /// Equivalent de: #[ORM\Column] private ?string $password
+    #[sea_orm(column_type = "String(StringLen::N(255))")]
+    #[serde(skip_serializing)]
+    pub password_hash: String,
+
+    /// Rôle (admin, editor, author)
+    pub role: Role,
+
     /// Creation timestamp
     /// Equivalent de: #[ORM\Column]
     pub created_at: DateTime,
+
+    /// Incrémentée à chaque modification (ETag / If-Match)
+    /// Equivalent de: #[ORM\Version]
+    pub version: i32,
 }
 
 /// Relations
@@ -45,4 +79,17 @@ impl Related<super::post::Entity> for Entity {
 }
 
 /// Active Model Behavior
-impl ActiveModelBehavior for ActiveModel {}
+/// Equivalent de: lifecycle callback #[ORM\PreUpdate]
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    /// Chaque modification incrémente la version (verrouillage optimiste)
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        if !insert && let sea_orm::ActiveValue::Unchanged(version) = self.version {
+            self.version = sea_orm::ActiveValue::Set(version + 1);
+        }
+        Ok(self)
+    }
+}
@@ -1,15 +1,21 @@
 // src/error/mod.rs
 // Equivalent de: src/Exception/ en Symfony
 
+mod problem;
+
+pub use problem::{
+    problem_context, ErrorCode, ProblemDetails, ProblemJsonAddon, Violation,
+    PROBLEM_JSON_CONTENT_TYPE,
+};
+
 use axum::{
-    http::StatusCode,
+    http::{header, StatusCode},
     response::{IntoResponse, Response},
-    Json,
 };
-use serde::Serialize;
 use thiserror::Error;
 use tracing::error;
-use utoipa::ToSchema;
+
+use crate::i18n::Message;
 
 // ============================================================
 // Service Errors - Business logic errors (no HTTP concepts)
@@ -17,13 +23,35 @@ use utoipa::ToSchema;
 
 /// Service layer errors - pure business logic errors
 /// Equivalent de: Domain exceptions en Symfony (sans HTTP)
+/// Les messages destinés au client sont des clés de translations/messages.<locale>.json
 #[derive(Debug, Error)]
 pub enum ServiceError {
     #[error("Entity not found")]
     NotFound,
 
     #[error("Entity already exists: {0}")]
-    AlreadyExists(String),
+    AlreadyExists(Message),
+
+    #[error("Invalid credentials")]
+    InvalidCredentials,
+
+    #[error("Forbidden: {0}")]
+    Forbidden(Message),
+
+    /// L'état de la ressource ne permet pas l'opération (ex: post non publié)
+    #[error("Conflict: {0}")]
+    Conflict(Message),
+
+    /// Donnée incohérente avec l'état existant (ex: parent d'un autre post)
+    #[error("Invalid input: {0}")]
+    InvalidInput(Message),
+
+    /// La ressource a changé depuis que le client l'a lue (If-Match)
+    #[error("Precondition failed: {0}")]
+    PreconditionFailed(Message),
+
+    #[error("Internal error: {0}")]
+    Internal(String),
 
     #[error("Database error: {0}")]
     Database(#[from] sea_orm::DbErr),
@@ -33,16 +61,6 @@ pub enum ServiceError {
 // API Errors - HTTP layer errors
 // ============================================================
 
-/// Error response format
-/// Equivalent de: normalisation des erreurs en Symfony
-#[derive(Debug, Serialize, ToSchema)]
-#[schema(example = json!({ "error": "Resource not found" }))]
-pub struct ErrorResponse {
-    pub error: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub details: Option<String>,
-}
-
 /// API Error types - HTTP layer errors
 /// Equivalent de: HttpException, NotFoundHttpException, etc. en Symfony
 #[derive(Debug, Error)]
@@ -50,20 +68,36 @@ pub enum ApiError {
     #[error("Resource not found")]
     NotFound,
 
+    #[error("Method not allowed")]
+    MethodNotAllowed,
+
     #[error("Bad request: {0}")]
-    BadRequest(String),
+    BadRequest(Message),
 
     #[error("Validation error")]
-    ValidationError(String),
+    ValidationError(Message),
+
+    #[error("Unauthorized: {0}")]
+    Unauthorized(Message),
+
+    #[error("Forbidden: {0}")]
+    Forbidden(Message),
 
     #[error("Conflict: {0}")]
-    Conflict(String),
+    Conflict(Message),
+
+    #[error("Precondition failed: {0}")]
+    PreconditionFailed(Message),
 
     #[error("Internal server error")]
     InternalError(String),
 
     #[error("Database error")]
     DatabaseError(#[from] sea_orm::DbErr),
+
+    /// Violations de validation (ex: document PATCH invalide une fois appliqué)
+    #[error(transparent)]
+    Violations(#[from] crate::validation::ValidationError),
 }
 
 /// Convert ServiceError to ApiError
@@ -73,6 +107,14 @@ impl From<ServiceError> for ApiError {
         match err {
             ServiceError::NotFound => ApiError::NotFound,
             ServiceError::AlreadyExists(msg) => ApiError::Conflict(msg),
+            ServiceError::InvalidCredentials => {
+                ApiError::Unauthorized(Message::new("auth.invalid_credentials"))
+            }
+            ServiceError::Forbidden(msg) => ApiError::Forbidden(msg),
+            ServiceError::Conflict(msg) => ApiError::Conflict(msg),
+            ServiceError::InvalidInput(msg) => ApiError::BadRequest(msg),
+            ServiceError::PreconditionFailed(msg) => ApiError::PreconditionFailed(msg),
+            ServiceError::Internal(msg) => ApiError::InternalError(msg),
             ServiceError::Database(db_err) => ApiError::DatabaseError(db_err),
         }
     }
@@ -83,8 +125,16 @@ impl ApiError {
         Self::NotFound
     }
 
-    pub fn bad_request(msg: impl Into<String>) -> Self {
-        Self::BadRequest(msg.into())
+    pub fn bad_request(message: Message) -> Self {
+        Self::BadRequest(message)
+    }
+
+    pub fn unauthorized(message: Message) -> Self {
+        Self::Unauthorized(message)
+    }
+
+    pub fn forbidden(message: Message) -> Self {
+        Self::Forbidden(message)
     }
 
     pub fn internal(msg: impl Into<String>) -> Self {
@@ -92,62 +142,104 @@ impl ApiError {
     }
 }
 
+impl ApiError {
+    /// Statut HTTP et code stable du problème (le titre se déduit du code)
+    fn problem(&self) -> (StatusCode, ErrorCode) {
+        match self {
+            ApiError::NotFound => (StatusCode::NOT_FOUND, ErrorCode::NotFound),
+            ApiError::MethodNotAllowed => {
+                (StatusCode::METHOD_NOT_ALLOWED, ErrorCode::MethodNotAllowed)
+            }
+            ApiError::BadRequest(_) => (StatusCode::BAD_REQUEST, ErrorCode::BadRequest),
+            ApiError::ValidationError(_) => {
+                (StatusCode::UNPROCESSABLE_ENTITY, ErrorCode::ValidationFailed)
+            }
+            ApiError::Unauthorized(_) => (StatusCode::UNAUTHORIZED, ErrorCode::Unauthorized),
+            ApiError::Forbidden(_) => (StatusCode::FORBIDDEN, ErrorCode::Forbidden),
+            ApiError::Conflict(_) => (StatusCode::CONFLICT, ErrorCode::Conflict),
+            ApiError::PreconditionFailed(_) => {
+                (StatusCode::PRECONDITION_FAILED, ErrorCode::PreconditionFailed)
+            }
+            ApiError::InternalError(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, ErrorCode::InternalError)
+            }
+            ApiError::DatabaseError(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, ErrorCode::DatabaseError)
+            }
+            // Rendu par ValidationError (statut et code propres à chaque rejet) ; 422 par défaut
+            ApiError::Violations(_) => {
+                (StatusCode::UNPROCESSABLE_ENTITY, ErrorCode::ValidationFailed)
+            }
+        }
+    }
+}
+
 impl IntoResponse for ApiError {
     fn into_response(self) -> Response {
-        let (status, error_response) = match &self {
-            ApiError::NotFound => (
-                StatusCode::NOT_FOUND,
-                ErrorResponse {
-                    error: "Resource not found".to_string(),
-                    details: None,
-                },
-            ),
-            ApiError::BadRequest(msg) => (
-                StatusCode::BAD_REQUEST,
-                ErrorResponse {
-                    error: "Bad request".to_string(),
-                    details: Some(msg.clone()),
-                },
-            ),
-            ApiError::ValidationError(msg) => (
-                StatusCode::UNPROCESSABLE_ENTITY,
-                ErrorResponse {
-                    error: "Validation error".to_string(),
-                    details: Some(msg.clone()),
-                },
-            ),
-            ApiError::Conflict(msg) => (
-                StatusCode::CONFLICT,
-                ErrorResponse {
-                    error: "Conflict".to_string(),
-                    details: Some(msg.clone()),
-                },
-            ),
+        // Même format que les erreurs des extracteurs ValidatedJson / ValidatedQuery
+        if let ApiError::Violations(err) = self {
+            return err.into_response();
+        }
+
+        let (status, code) = self.problem();
+        let problem = ProblemDetails::new(status, code);
+
+        let problem = match self {
+            ApiError::BadRequest(msg)
+            | ApiError::ValidationError(msg)
+            | ApiError::Unauthorized(msg)
+            | ApiError::Forbidden(msg)
+            | ApiError::Conflict(msg)
+            | ApiError::PreconditionFailed(msg) => problem.detail(msg),
+            // Don't expose internal details
             ApiError::InternalError(msg) => {
                 error!(error = %msg, "Internal server error");
-                (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    ErrorResponse {
-                        error: "Internal server error".to_string(),
-                        details: None, // Don't expose internal details
-                    },
-                )
+                problem
             }
             ApiError::DatabaseError(err) => {
                 error!(error = %err, "Database error");
-                (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    ErrorResponse {
-                        error: "Database error".to_string(),
-                        details: None,
-                    },
-                )
+                problem
             }
+            ApiError::NotFound | ApiError::MethodNotAllowed | ApiError::Violations(_) => problem,
         };
 
-        (status, Json(error_response)).into_response()
+        let mut response = problem.into_response();
+        if status == StatusCode::UNAUTHORIZED {
+            // Indique au client le schéma attendu (RFC 6750)
+            response
+                .headers_mut()
+                .insert(header::WWW_AUTHENTICATE, header::HeaderValue::from_static("Bearer"));
+        }
+        response
     }
 }
 
 /// Result type alias for API handlers
 pub type ApiResult<T> = Result<T, ApiError>;
+
+/// Fallback du routeur: route inconnue (comme NotFoundHttpException du Router Symfony)
+pub async fn route_not_found() -> ApiError {
+    ApiError::NotFound
+}
+
+/// Fallback des routes connues appelées avec une autre méthode HTTP
+/// Equivalent de: MethodNotAllowedHttpException en Symfony
+pub async fn method_not_allowed() -> ApiError {
+    ApiError::MethodNotAllowed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::ValidationError;
+
+    #[test]
+    fn test_every_variant_maps_to_a_problem() {
+        let err = ApiError::Violations(ValidationError::PatchFailed("x".to_string()));
+
+        assert_eq!(
+            err.problem(),
+            (StatusCode::UNPROCESSABLE_ENTITY, ErrorCode::ValidationFailed)
+        );
+    }
+}
@@ -0,0 +1,293 @@
+// src/error/problem.rs
+// Réponses d'erreur au format RFC 7807 (application/problem+json)
+// Equivalent de: le normalizer ProblemNormalizer de Symfony Serializer
+
+use axum::{
+    body::Body,
+    extract::Request,
+    http::{
+        header::{ACCEPT_LANGUAGE, CONTENT_LANGUAGE, CONTENT_LENGTH, CONTENT_TYPE},
+        HeaderValue, StatusCode,
+    },
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use utoipa::openapi::{path::Operation, RefOr};
+use utoipa::{Modify, ToSchema};
+
+use crate::config::request_id;
+use crate::i18n::{Locale, Message};
+
+/// Content-Type des réponses d'erreur
+pub const PROBLEM_JSON_CONTENT_TYPE: &str = "application/problem+json";
+
+/// Code d'erreur stable, à utiliser par les clients plutôt que `title` / `detail`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    NotFound,
+    MethodNotAllowed,
+    BadRequest,
+    InvalidJson,
+    InvalidQuery,
+    InvalidPath,
+    InvalidPatch,
+    PatchFailed,
+    ValidationFailed,
+    Unauthorized,
+    Forbidden,
+    Conflict,
+    PreconditionFailed,
+    InternalError,
+    DatabaseError,
+}
+
+impl ErrorCode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::NotFound => "not_found",
+            ErrorCode::MethodNotAllowed => "method_not_allowed",
+            ErrorCode::BadRequest => "bad_request",
+            ErrorCode::InvalidJson => "invalid_json",
+            ErrorCode::InvalidQuery => "invalid_query",
+            ErrorCode::InvalidPath => "invalid_path",
+            ErrorCode::InvalidPatch => "invalid_patch",
+            ErrorCode::PatchFailed => "patch_failed",
+            ErrorCode::ValidationFailed => "validation_failed",
+            ErrorCode::Unauthorized => "unauthorized",
+            ErrorCode::Forbidden => "forbidden",
+            ErrorCode::Conflict => "conflict",
+            ErrorCode::PreconditionFailed => "precondition_failed",
+            ErrorCode::InternalError => "internal_error",
+            ErrorCode::DatabaseError => "database_error",
+        }
+    }
+
+    /// URI du type de problème (`type` de la RFC 7807), relative à l'API
+    pub fn type_uri(&self) -> String {
+        format!("/problems/{}", self.as_str())
+    }
+
+    /// Clé de traduction du titre
+    fn title(&self) -> Message {
+        Message::new(format!("error.{}", self.as_str()))
+    }
+}
+
+/// Violation d'une contrainte sur un champ (comme ConstraintViolation en Symfony)
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Violation {
+    /// Champ en erreur (`_json`, `_query` ou `_patch` pour le corps entier)
+    pub field: String,
+    #[schema(value_type = Vec<String>)]
+    pub messages: Vec<Message>,
+}
+
+impl Violation {
+    /// Violation portant sur le corps ou la query string entière
+    pub fn body(field: &str, message: Message) -> Self {
+        Self {
+            field: field.to_string(),
+            messages: vec![message],
+        }
+    }
+}
+
+/// Error response format (RFC 7807)
+/// Equivalent de: normalisation des erreurs en Symfony
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(example = json!({
+    "type": "/problems/not_found",
+    "title": "Resource not found",
+    "status": 404,
+    "instance": "/posts/42",
+    "code": "not_found",
+    "request_id": "0b7f3a8e-5c1d-4e2f-9a6b-3c8d7e1f2a4b"
+}))]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    /// Traduit selon Accept-Language
+    #[schema(value_type = String)]
+    pub title: Message,
+    pub status: u16,
+    /// Traduit selon Accept-Language
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<String>)]
+    pub detail: Option<Message>,
+    /// Chemin de la requête en erreur
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    pub code: ErrorCode,
+    /// X-Request-Id de la requête, à communiquer au support (corrélation avec les logs)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub violations: Vec<Violation>,
+}
+
+impl ProblemDetails {
+    pub fn new(status: StatusCode, code: ErrorCode) -> Self {
+        Self {
+            problem_type: code.type_uri(),
+            title: code.title(),
+            status: status.as_u16(),
+            detail: None,
+            instance: None,
+            code,
+            request_id: None,
+            violations: Vec::new(),
+        }
+    }
+
+    pub fn detail(mut self, detail: Message) -> Self {
+        self.detail = Some(detail);
+        self
+    }
+
+    pub fn violations(mut self, violations: Vec<Violation>) -> Self {
+        self.violations = violations;
+        self
+    }
+
+    /// Traduit le titre, le détail et les violations
+    pub fn localize(&mut self, locale: Locale) {
+        self.title.localize(locale);
+        if let Some(detail) = &mut self.detail {
+            detail.localize(locale);
+        }
+        for violation in &mut self.violations {
+            violation.messages.iter_mut().for_each(|message| message.localize(locale));
+        }
+    }
+
+    fn body(&self) -> Body {
+        Body::from(serde_json::to_vec(self).unwrap_or_default())
+    }
+}
+
+/// Le problème est aussi gardé dans les extensions de la réponse,
+/// pour que `problem_context` puisse le compléter avec la requête
+impl IntoResponse for ProblemDetails {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let mut response = (status, self.body()).into_response();
+        response
+            .headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static(PROBLEM_JSON_CONTENT_TYPE));
+        response.extensions_mut().insert(self);
+        response
+    }
+}
+
+/// Middleware: renseigne `instance` avec le chemin de la requête, `request_id`
+/// avec son X-Request-Id, et traduit le problème dans la langue négociée (Accept-Language)
+pub async fn problem_context(request: Request, next: Next) -> Response {
+    let path = request.uri().path().to_string();
+    let request_id = request_id(request.headers()).map(str::to_string);
+    let locale = request
+        .headers()
+        .get(ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .map(Locale::negotiate)
+        .unwrap_or_default();
+    let response = next.run(request).await;
+
+    let (mut parts, body) = response.into_parts();
+    let Some(mut problem) = parts.extensions.remove::<ProblemDetails>() else {
+        return Response::from_parts(parts, body);
+    };
+
+    problem.instance = Some(path);
+    problem.request_id = request_id;
+    problem.localize(locale);
+    parts.headers.remove(CONTENT_LENGTH);
+    parts
+        .headers
+        .insert(CONTENT_LANGUAGE, HeaderValue::from_static(locale.as_str()));
+    Response::from_parts(parts, problem.body())
+}
+
+/// Déclare les réponses d'erreur (schéma ProblemDetails) en `application/problem+json`
+pub struct ProblemJsonAddon;
+
+impl Modify for ProblemJsonAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        for item in openapi.paths.paths.values_mut() {
+            let operations = [
+                &mut item.get,
+                &mut item.put,
+                &mut item.post,
+                &mut item.delete,
+                &mut item.patch,
+            ];
+            for operation in operations.into_iter().flatten() {
+                problem_json_responses(operation);
+            }
+        }
+    }
+}
+
+fn problem_json_responses(operation: &mut Operation) {
+    for response in operation.responses.responses.values_mut() {
+        let RefOr::T(response) = response else {
+            continue;
+        };
+        let is_problem = response.content.get("application/json").is_some_and(|content| {
+            matches!(&content.schema, Some(RefOr::Ref(schema))
+                if schema.ref_location.ends_with("/ProblemDetails"))
+        });
+        if is_problem && let Some(content) = response.content.shift_remove("application/json") {
+            response.content.insert(PROBLEM_JSON_CONTENT_TYPE.to_string(), content);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn problem_is_serialized_with_rfc_7807_members() {
+        let problem = ProblemDetails::new(StatusCode::CONFLICT, ErrorCode::Conflict)
+            .detail(Message::new("user.email.taken"));
+
+        let json = serde_json::to_value(&problem).unwrap();
+
+        assert_eq!(json["type"], "/problems/conflict");
+        assert_eq!(json["title"], "Conflict");
+        assert_eq!(json["status"], 409);
+        assert_eq!(json["detail"], "Email already exists");
+        assert_eq!(json["code"], "conflict");
+        assert!(json.get("instance").is_none());
+        assert!(json.get("violations").is_none());
+    }
+
+    #[test]
+    fn problem_response_uses_problem_json_content_type() {
+        let response = ProblemDetails::new(StatusCode::NOT_FOUND, ErrorCode::NotFound).into_response();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.headers()[CONTENT_TYPE], PROBLEM_JSON_CONTENT_TYPE);
+        assert!(response.extensions().get::<ProblemDetails>().is_some());
+    }
+
+    #[test]
+    fn openapi_error_responses_are_problem_json() {
+        use utoipa::OpenApi;
+
+        let openapi = crate::config::ApiDoc::openapi();
+        let operation = openapi.paths.paths["/users/{id}"].get.as_ref().unwrap();
+        let RefOr::T(not_found) = &operation.responses.responses["404"] else {
+            panic!("inline response expected");
+        };
+        let RefOr::T(ok) = &operation.responses.responses["200"] else {
+            panic!("inline response expected");
+        };
+
+        assert!(not_found.content.contains_key(PROBLEM_JSON_CONTENT_TYPE));
+        assert!(!not_found.content.contains_key("application/json"));
+        assert!(ok.content.contains_key("application/json"));
+    }
+}
@@ -5,12 +5,14 @@
 mod factory;
 mod user_factory;
 
-pub use factory::Factory;
-pub use user_factory::UserFactory;
+pub use factory::{reset_sequence, Factory, FactoryWithCallback};
+pub use user_factory::{UserFactory, DEFAULT_PASSWORD};
 
 use sea_orm::DatabaseConnection;
 use tracing::info;
 
+use crate::entities::user::Role;
+
 /// Load default fixtures into database
 /// Equivalent de: bin/console doctrine:fixtures:load
 pub async fn load_fixtures(db: &DatabaseConnection) -> Result<(), sea_orm::DbErr> {
@@ -29,6 +31,7 @@ pub async fn load_fixtures(db: &DatabaseConnection) -> Result<(), sea_orm::DbErr
     let admin = UserFactory::new()
         .with_username("admin")
         .with_email("admin@example.com")
+        .with_role(Role::Admin)
         .create(db)
         .await?;
 
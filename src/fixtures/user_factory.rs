@@ -5,10 +5,14 @@ use async_trait::async_trait;
 use chrono::Utc;
 use sea_orm::{ActiveModelTrait, DatabaseConnection, DbErr, Set};
 
-use crate::entities::user;
+use crate::auth::hash_password;
+use crate::entities::user::{self, Role};
 
 use super::factory::{next_sequence, Factory};
 
+/// Mot de passe par défaut des utilisateurs générés
+pub const DEFAULT_PASSWORD: &str = "password";
+
 /// UserFactory - Factory pour créer des utilisateurs de test
 ///
 /// # Exemples
@@ -38,6 +42,8 @@ use super::factory::{next_sequence, Factory};
 pub struct UserFactory {
     username: Option<String>,
     email: Option<String>,
+    password: Option<String>,
+    role: Option<Role>,
     created_at: Option<chrono::NaiveDateTime>,
 }
 
@@ -68,6 +74,32 @@ impl UserFactory {
         self
     }
 
+    /// Définit le mot de passe (stocké hashé)
+    ///
+    /// ```ignore
+    /// let user = UserFactory::new()
+    ///     .with_password("S3cure-password")
+    ///     .create(&db)
+    ///     .await?;
+    /// ```
+    pub fn with_password(mut self, password: impl Into<String>) -> Self {
+        self.password = Some(password.into());
+        self
+    }
+
+    /// Définit le rôle (author par défaut)
+    ///
+    /// ```ignore
+    /// let admin = UserFactory::new()
+    ///     .with_role(Role::Admin)
+    ///     .create(&db)
+    ///     .await?;
+    /// ```
+    pub fn with_role(mut self, role: Role) -> Self {
+        self.role = Some(role);
+        self
+    }
+
     /// Définit la date de création
     ///
     /// ```ignore
@@ -91,13 +123,22 @@ impl UserFactory {
         format!("user_{}@example.com", next_sequence())
     }
 
+    /// Hash le mot de passe configuré (ou DEFAULT_PASSWORD)
+    fn password_hash(&self) -> String {
+        let password = self.password.as_deref().unwrap_or(DEFAULT_PASSWORD);
+        hash_password(password).expect("Failed to hash fixture password")
+    }
+
     /// Construit l'ActiveModel pour SeaORM
     fn build_active_model(&self) -> user::ActiveModel {
         user::ActiveModel {
             id: sea_orm::ActiveValue::NotSet,
             username: Set(self.username.clone().unwrap_or_else(Self::default_username)),
             email: Set(self.email.clone().unwrap_or_else(Self::default_email)),
+            password_hash: Set(self.password_hash()),
+            role: Set(self.role.unwrap_or_default()),
             created_at: Set(self.created_at.unwrap_or_else(|| Utc::now().naive_utc())),
+            version: Set(1),
         }
     }
 }
@@ -111,6 +152,8 @@ impl Factory for UserFactory {
         Self {
             username: None,
             email: None,
+            password: None,
+            role: None,
             created_at: None,
         }
     }
@@ -126,7 +169,10 @@ impl Factory for UserFactory {
             id: seq as i32,
             username: self.username.clone().unwrap_or_else(|| format!("user_{}", seq)),
             email: self.email.clone().unwrap_or_else(|| format!("user_{}@example.com", seq)),
+            password_hash: self.password_hash(),
+            role: self.role.unwrap_or_default(),
             created_at: self.created_at.unwrap_or_else(|| Utc::now().naive_utc()),
+            version: 1,
         }
     }
 }
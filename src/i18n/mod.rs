@@ -0,0 +1,234 @@
+// src/i18n/mod.rs
+// Traduction des messages d'erreur (comme le composant Translation de Symfony)
+// Catalogues: translations/messages.<locale>.json (clé -> message avec {paramètres})
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::LazyLock;
+
+use serde::{Serialize, Serializer};
+
+/// Langues disponibles, l'anglais sert de repli
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    #[default]
+    En,
+    Fr,
+}
+
+impl Locale {
+    /// Code de langue (header Content-Language)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Fr => "fr",
+        }
+    }
+
+    /// Langue d'un tag BCP 47 (`fr`, `fr-CA`, `en-US`...)
+    fn from_tag(tag: &str) -> Option<Self> {
+        let primary = tag.split('-').next().unwrap_or_default();
+        match primary.to_ascii_lowercase().as_str() {
+            "en" => Some(Locale::En),
+            "fr" => Some(Locale::Fr),
+            _ => None,
+        }
+    }
+
+    /// Négocie la langue depuis un header Accept-Language (`fr-CH, fr;q=0.9, en;q=0.8`)
+    /// Langue disponible de plus haut `q`, sinon anglais
+    pub fn negotiate(accept_language: &str) -> Self {
+        let mut ranges: Vec<(&str, f32)> = accept_language
+            .split(',')
+            .filter_map(|range| {
+                let mut parts = range.split(';').map(str::trim);
+                let tag = parts.next().filter(|tag| !tag.is_empty())?;
+                let quality = parts
+                    .find_map(|param| param.strip_prefix("q="))
+                    .map_or(Some(1.0), |q| q.parse().ok())?;
+                Some((tag, quality))
+            })
+            .filter(|(_, quality)| *quality > 0.0)
+            .collect();
+        // Tri stable: à qualité égale, l'ordre du header est conservé
+        ranges.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        ranges
+            .into_iter()
+            .find_map(|(tag, _)| Self::from_tag(tag))
+            .unwrap_or_default()
+    }
+
+    fn catalog(&self) -> &'static HashMap<String, String> {
+        match self {
+            Locale::En => &EN,
+            Locale::Fr => &FR,
+        }
+    }
+}
+
+static EN: LazyLock<HashMap<String, String>> =
+    LazyLock::new(|| load(include_str!("../../translations/messages.en.json")));
+static FR: LazyLock<HashMap<String, String>> =
+    LazyLock::new(|| load(include_str!("../../translations/messages.fr.json")));
+
+fn load(catalog: &str) -> HashMap<String, String> {
+    serde_json::from_str(catalog).expect("translation catalog must be a flat JSON object")
+}
+
+/// Traduit une clé et remplace ses `{paramètres}`
+/// Clé absente du catalogue: repli sur l'anglais, puis sur la clé elle-même
+pub fn translate(locale: Locale, key: &str, params: &[(Cow<'static, str>, String)]) -> String {
+    let template = locale
+        .catalog()
+        .get(key)
+        .or_else(|| Locale::En.catalog().get(key))
+        .map_or(key, String::as_str);
+
+    params.iter().fold(template.to_string(), |message, (name, value)| {
+        message.replace(&format!("{{{name}}}"), value)
+    })
+}
+
+/// Message traduisible: clé du catalogue et paramètres
+/// Sérialisé dans la langue choisie par `localize` (anglais par défaut)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    key: Cow<'static, str>,
+    params: Vec<(Cow<'static, str>, String)>,
+    locale: Locale,
+}
+
+impl Message {
+    pub fn new(key: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            key: key.into(),
+            params: Vec::new(),
+            locale: Locale::default(),
+        }
+    }
+
+    pub fn param(mut self, name: impl Into<Cow<'static, str>>, value: impl ToString) -> Self {
+        self.params.push((name.into(), value.to_string()));
+        self
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn localize(&mut self, locale: Locale) {
+        self.locale = locale;
+    }
+
+    pub fn render(&self) -> String {
+        translate(self.locale, &self.key, &self.params)
+    }
+}
+
+/// Message d'une contrainte du validator: le `code` de la contrainte est la clé,
+/// ses paramètres (min, max...) sont repris; un code hors catalogue garde son `message`
+impl From<&validator::ValidationError> for Message {
+    fn from(error: &validator::ValidationError) -> Self {
+        let key = match &error.message {
+            Some(message) if !EN.contains_key(error.code.as_ref()) => message.clone(),
+            _ => error.code.clone(),
+        };
+
+        let mut params: Vec<_> = error
+            .params
+            .iter()
+            .filter(|(name, _)| name.as_ref() != "value")
+            .map(|(name, value)| (name.clone(), param_value(value)))
+            .collect();
+        params.sort();
+
+        Self {
+            key,
+            params,
+            locale: Locale::default(),
+        }
+    }
+}
+
+/// Paramètre affichable (`3` plutôt que `3.0`, chaînes sans guillemets)
+fn param_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(value) => value.clone(),
+        serde_json::Value::Number(number) => match number.as_f64() {
+            Some(float) if float.fract() == 0.0 => format!("{}", float as i64),
+            _ => number.to_string(),
+        },
+        other => other.to_string(),
+    }
+}
+
+/// Rendu dans la langue courante (anglais tant que `localize` n'a pas été appelé), pour les logs
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render())
+    }
+}
+
+impl Serialize for Message {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.render())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_negotiate_picks_highest_quality_supported_language() {
+        assert_eq!(Locale::negotiate("fr-CH, fr;q=0.9, en;q=0.8"), Locale::Fr);
+        assert_eq!(Locale::negotiate("de, en;q=0.5, fr;q=0.7"), Locale::Fr);
+        assert_eq!(Locale::negotiate("en-US,en;q=0.9,fr;q=0.8"), Locale::En);
+        assert_eq!(Locale::negotiate("fr;q=0, en;q=0.1"), Locale::En);
+    }
+
+    #[test]
+    fn test_negotiate_falls_back_to_english() {
+        assert_eq!(Locale::negotiate("de-DE, es;q=0.8"), Locale::En);
+        assert_eq!(Locale::negotiate("*"), Locale::En);
+        assert_eq!(Locale::negotiate(""), Locale::En);
+    }
+
+    #[test]
+    fn test_catalogs_have_the_same_keys() {
+        let mut en: Vec<_> = EN.keys().collect();
+        let mut fr: Vec<_> = FR.keys().collect();
+        en.sort();
+        fr.sort();
+
+        assert_eq!(en, fr);
+    }
+
+    #[test]
+    fn test_message_is_rendered_with_params() {
+        let mut message = Message::new("post.title.length").param("min", 3).param("max", 255);
+        assert_eq!(message.render(), "Title must be between 3 and 255 characters");
+
+        message.localize(Locale::Fr);
+        assert_eq!(message.render(), "Le titre doit faire entre 3 et 255 caractères");
+    }
+
+    #[test]
+    fn test_unknown_key_is_rendered_as_is() {
+        assert_eq!(translate(Locale::Fr, "Some literal text", &[]), "Some literal text");
+    }
+
+    #[test]
+    fn test_message_from_validator_error() {
+        let mut error = validator::ValidationError::new("comment.content.length");
+        error.add_param("min".into(), &1);
+        error.add_param("max".into(), &5000);
+        error.add_param("value".into(), &"");
+        let mut message = Message::from(&error);
+        message.localize(Locale::Fr);
+
+        assert_eq!(message.render(), "Le commentaire doit faire entre 1 et 5000 caractères");
+    }
+}
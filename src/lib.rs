@@ -1,12 +1,16 @@
 // src/lib.rs
 // Export modules for testing and external use
 
+pub mod auth;
 pub mod config;
 pub mod controllers;
 pub mod dto;
 pub mod entities;
 pub mod error;
 pub mod fixtures;
+pub mod i18n;
+pub mod metrics;
 pub mod response;
 pub mod services;
+pub mod shutdown;
 pub mod validation;
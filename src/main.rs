@@ -1,48 +1,77 @@
 // src/main.rs
 // Equivalent de: public/index.php + bin/console server:start
 
-// === Module declarations ===
-mod config;
-mod controllers;
-mod dto;
-mod entities;
-mod error;
-mod response;
-mod services;
-mod validation;
-
 // === Imports ===
+// Les modules sont déclarés dans src/lib.rs (partagés avec les tests)
 use std::sync::Arc;
 
-use axum::Router;
+use axum::{middleware, Router};
+use tower_http::request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer};
 use tower_http::trace::TraceLayer;
-use tracing::info;
-use utoipa::OpenApi;
+use tracing::{info, warn};
 use utoipa_swagger_ui::SwaggerUi;
 
-use config::{init_database, init_logging, ApiDoc, AppState};
-use controllers::{HealthController, PostController, UserController};
-use services::{PostService, UserService};
+use rust_api::config::{
+    api_doc, init_database, init_jwt, init_logging, request_id, AppState, Settings,
+};
+use rust_api::controllers::{
+    ApiKeyController, AuthController, CommentController, HealthController, MetricsController,
+    PostController, RevisionController, TagController, UserController,
+};
+use rust_api::error::{method_not_allowed, problem_context, route_not_found};
+use rust_api::metrics::{instrument_database, track_http};
+use rust_api::services::{
+    ApiKeyService, CommentService, HealthService, PostService, RefreshTokenService,
+    ScheduledPublisher, SearchService, SharedClock, SystemClock, TagService, UserService,
+};
+use rust_api::shutdown::{trigger_on_signal, Shutdown};
 
 /// Build the application router
+/// /metrics et le suivi des requêtes ne sont montés que si `metrics.token` est défini
 fn build_router(state: Arc<AppState>) -> Router {
+    let settings = state.settings.clone();
+
     // Routes with state
+    let auth_routes = AuthController::routes();
+    let api_key_routes = ApiKeyController::routes();
     let user_routes = UserController::routes();
     let post_routes = PostController::routes();
-
-    // Health routes (no state needed)
+    let revision_routes = RevisionController::routes();
+    let comment_routes = CommentController::routes();
+    let tag_routes = TagController::routes();
     let health_routes = HealthController::routes();
 
-    Router::new()
+    let mut app = Router::new()
         // Merge routes that need state
+        .merge(auth_routes)
+        .merge(api_key_routes)
         .merge(user_routes)
         .merge(post_routes)
+        .merge(revision_routes)
+        .merge(comment_routes)
+        .merge(tag_routes)
+        .merge(health_routes)
         // Then apply state
         .with_state(state)
-        // Then merge stateless routes
-        .merge(health_routes)
         // Swagger UI
-        .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()))
+        .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", api_doc(&settings.openapi)));
+
+    // Métriques Prometheus: compteurs et latences par route (MatchedPath)
+    if let Some(token) = &settings.metrics.token {
+        app = app
+            .merge(MetricsController::routes(token))
+            .layer(middleware::from_fn(track_http));
+    }
+
+    app
+        // Route inconnue (404) ou méthode non supportée (405), en problem+json
+        // Déclarés après tous les merge: le fallback 405 ne s'applique qu'aux routes existantes
+        .fallback(route_not_found)
+        .method_not_allowed_fallback(method_not_allowed)
+        // Erreurs problem+json: chemin de la requête (`instance`) et langue (Accept-Language)
+        .layer(middleware::from_fn(problem_context))
+        // Renvoie le X-Request-Id dans la réponse
+        .layer(PropagateRequestIdLayer::x_request_id())
         // HTTP request logging middleware
         .layer(
             TraceLayer::new_for_http()
@@ -51,6 +80,7 @@ fn build_router(state: Arc<AppState>) -> Router {
                         "http_request",
                         method = %request.method(),
                         uri = %request.uri(),
+                        request_id = request_id(request.headers()).unwrap_or_default(),
                     )
                 })
                 .on_response(
@@ -65,38 +95,117 @@ fn build_router(state: Arc<AppState>) -> Router {
                     },
                 ),
         )
+        // X-Request-Id: repris du client ou généré (UUID v4), avant le span de log
+        .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
 }
 
 /// Application entry point
 #[tokio::main]
 async fn main() {
+    // Configuration: fichiers TOML du profil APP_ENV + variables d'environnement
+    // Les logs ne sont pas encore initialisés: l'erreur part sur stderr
+    let settings = Settings::load().unwrap_or_else(|err| {
+        eprintln!("Configuration error: {err}");
+        std::process::exit(1);
+    });
+
     // Initialize logging
-    init_logging();
+    init_logging(&settings.log);
 
-    info!("Starting Rust API...");
+    info!(profile = settings.profile.as_str(), "Starting Rust API...");
 
     // Initialize database
-    let db = init_database().await;
+    let mut db = init_database(&settings.database).await;
+
+    // Durée des requêtes SQL, avant que la connexion soit clonée dans les services
+    if settings.metrics.token.is_some() {
+        instrument_database(&mut db);
+    } else {
+        warn!("METRICS_TOKEN not set, /metrics endpoint disabled");
+    }
 
     // Create services
     let user_service = UserService::new(db.clone());
-    let post_service = PostService::new(db);
+    let clock: SharedClock = Arc::new(SystemClock);
+    let post_service = PostService::new(db.clone(), clock.clone());
+    let comment_service = CommentService::new(db.clone());
+    let tag_service = TagService::new(db.clone());
+    let search_service = SearchService::new(db.clone());
+    let api_key_service = ApiKeyService::new(db.clone());
+    let refresh_token_service =
+        RefreshTokenService::new(db.clone(), settings.security.refresh_token_ttl_seconds);
+    let jwt_service = init_jwt(&settings.security);
+    // SIGTERM / SIGINT: la readiness échoue, puis le serveur et les workers s'arrêtent
+    let shutdown = Shutdown::new();
+    tokio::spawn(trigger_on_signal(shutdown.clone()));
+    let health_service = HealthService::new(db.clone(), shutdown.clone());
+
+    // Publication des posts programmés en tâche de fond (comme un worker Messenger)
+    let publisher =
+        ScheduledPublisher::new(db.clone(), clock).spawn(settings.publisher.interval(), shutdown.clone());
 
     // Create application state
-    let state = Arc::new(AppState::new(user_service, post_service));
+    let state = Arc::new(AppState::new(
+        user_service,
+        post_service,
+        comment_service,
+        tag_service,
+        search_service,
+        api_key_service,
+        refresh_token_service,
+        jwt_service,
+        health_service,
+        settings.clone(),
+    ));
 
     // Build router with all routes
     let app = build_router(state);
 
     // Start server
-    let addr = std::env::var("SERVER_ADDR").unwrap_or_else(|_| "0.0.0.0:8080".to_string());
+    let addr = &settings.server.addr;
 
     info!(address = %addr, "Server starting...");
-    info!("Swagger UI: http://localhost:8080/swagger-ui/");
-    info!("OpenAPI JSON: http://localhost:8080/api-docs/openapi.json");
+    info!(address = %addr, "Swagger UI: /swagger-ui/, OpenAPI JSON: /api-docs/openapi.json");
 
-    let listener = tokio::net::TcpListener::bind(&addr).await.unwrap();
+    let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
     info!(address = %addr, "Server running!");
 
-    axum::serve(listener, app).await.unwrap();
+    // Après le signal (et SHUTDOWN_DELAY_SECONDS), plus de nouvelle connexion:
+    // les requêtes en cours ont SHUTDOWN_TIMEOUT_SECONDS pour se terminer
+    let delay = settings.shutdown.delay();
+    let timeout = settings.shutdown.timeout();
+    let stop_accepting = {
+        let shutdown = shutdown.clone();
+        async move {
+            shutdown.wait().await;
+            info!(delay_seconds = delay.as_secs(), "Shutting down, readiness now failing");
+            tokio::time::sleep(delay).await;
+            info!(timeout_seconds = timeout.as_secs(), "Draining in-flight requests");
+        }
+    };
+    let drain_deadline = {
+        let shutdown = shutdown.clone();
+        async move {
+            shutdown.wait().await;
+            tokio::time::sleep(delay + timeout).await;
+        }
+    };
+
+    tokio::select! {
+        result = axum::serve(listener, app).with_graceful_shutdown(stop_accepting) => {
+            result.unwrap();
+            info!("In-flight requests drained");
+        }
+        _ = drain_deadline => warn!("Shutdown timeout reached, closing remaining connections"),
+    }
+
+    // Le publisher s'est arrêté au signal: on attend la fin de son passage en cours
+    if tokio::time::timeout(timeout, publisher).await.is_err() {
+        warn!("Scheduled publisher did not stop in time");
+    }
+
+    if let Err(err) = db.close().await {
+        warn!(error = %err, "Failed to close database pool");
+    }
+    info!("Server stopped");
 }
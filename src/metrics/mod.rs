@@ -0,0 +1,202 @@
+// src/metrics/mod.rs
+// Métriques Prometheus (comme artprima/prometheus-metrics-bundle en Symfony)
+// Registre global, comme le subscriber tracing: les services enregistrent sans injection
+
+use std::sync::LazyLock;
+use std::time::Instant;
+
+use axum::{
+    extract::{MatchedPath, Request},
+    middleware::Next,
+    response::Response,
+};
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge, Opts, Registry,
+    TextEncoder,
+};
+use sea_orm::DatabaseConnection;
+
+/// Content-Type du format texte Prometheus
+pub const METRICS_CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+/// Métriques exposées sur /metrics
+struct Metrics {
+    registry: Registry,
+    http_requests: IntCounterVec,
+    http_duration: HistogramVec,
+    http_in_flight: IntGauge,
+    db_duration: HistogramVec,
+    users_created: IntCounter,
+    posts_published: IntCounter,
+}
+
+static METRICS: LazyLock<Metrics> = LazyLock::new(Metrics::new);
+
+impl Metrics {
+    fn new() -> Self {
+        let registry = Registry::new();
+
+        let http_requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "HTTP requests by route and status"),
+            &["method", "route", "status"],
+        )
+        .expect("valid metric");
+        let http_duration = HistogramVec::new(
+            HistogramOpts::new("http_request_duration_seconds", "HTTP request latency"),
+            &["method", "route", "status"],
+        )
+        .expect("valid metric");
+        let http_in_flight =
+            IntGauge::new("http_requests_in_flight", "HTTP requests being processed")
+                .expect("valid metric");
+        let db_duration = HistogramVec::new(
+            HistogramOpts::new("db_query_duration_seconds", "Database query latency")
+                .buckets(vec![0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 1.0]),
+            &["operation", "outcome"],
+        )
+        .expect("valid metric");
+        let users_created = IntCounter::new("users_created_total", "Users created")
+            .expect("valid metric");
+        let posts_published = IntCounter::new("posts_published_total", "Posts published")
+            .expect("valid metric");
+
+        registry.register(Box::new(http_requests.clone())).expect("unique metric");
+        registry.register(Box::new(http_duration.clone())).expect("unique metric");
+        registry.register(Box::new(http_in_flight.clone())).expect("unique metric");
+        registry.register(Box::new(db_duration.clone())).expect("unique metric");
+        registry.register(Box::new(users_created.clone())).expect("unique metric");
+        registry.register(Box::new(posts_published.clone())).expect("unique metric");
+
+        Self {
+            registry,
+            http_requests,
+            http_duration,
+            http_in_flight,
+            db_duration,
+            users_created,
+            posts_published,
+        }
+    }
+}
+
+/// Métriques au format texte Prometheus
+pub fn render() -> String {
+    let mut buffer = Vec::new();
+    TextEncoder::new()
+        .encode(&METRICS.registry.gather(), &mut buffer)
+        .expect("text encoding cannot fail");
+    String::from_utf8(buffer).unwrap_or_default()
+}
+
+// ============================================================================
+// BUSINESS
+// ============================================================================
+
+pub fn record_user_created() {
+    METRICS.users_created.inc();
+}
+
+pub fn record_posts_published(count: u64) {
+    METRICS.posts_published.inc_by(count);
+}
+
+// ============================================================================
+// DATABASE
+// ============================================================================
+
+/// Mesure la durée de chaque requête SQL de la connexion (et donc de tous les services)
+/// À appeler avant de cloner la connexion dans les services
+pub fn instrument_database(db: &mut DatabaseConnection) {
+    db.set_metric_callback(|info| {
+        let outcome = if info.failed { "error" } else { "ok" };
+        METRICS
+            .db_duration
+            .with_label_values(&[query_operation(&info.statement.sql), outcome])
+            .observe(info.elapsed.as_secs_f64());
+    });
+}
+
+/// Type de requête (premier mot-clé SQL) ; label à cardinalité bornée
+fn query_operation(sql: &str) -> &'static str {
+    let keyword = sql.split_whitespace().next().unwrap_or_default();
+    match keyword.to_ascii_uppercase().as_str() {
+        "SELECT" | "WITH" => "select",
+        "INSERT" => "insert",
+        "UPDATE" => "update",
+        "DELETE" => "delete",
+        "BEGIN" | "COMMIT" | "ROLLBACK" | "SAVEPOINT" | "RELEASE" => "transaction",
+        _ => "other",
+    }
+}
+
+// ============================================================================
+// HTTP
+// ============================================================================
+
+/// Décrémente le gauge même si la requête est abandonnée
+struct InFlight;
+
+impl InFlight {
+    fn start() -> Self {
+        METRICS.http_in_flight.inc();
+        Self
+    }
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        METRICS.http_in_flight.dec();
+    }
+}
+
+/// Middleware: compte et chronomètre les requêtes par route (`/posts/:id`, pas `/posts/42`)
+pub async fn track_http(request: Request, next: Next) -> Response {
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or_else(|| "unmatched".to_string(), |path| path.as_str().to_string());
+    let method = request.method().to_string();
+
+    let _in_flight = InFlight::start();
+    let started = Instant::now();
+    let response = next.run(request).await;
+
+    let status = response.status().as_u16().to_string();
+    let labels = [method.as_str(), route.as_str(), status.as_str()];
+    METRICS.http_requests.with_label_values(&labels).inc();
+    METRICS
+        .http_duration
+        .with_label_values(&labels)
+        .observe(started.elapsed().as_secs_f64());
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{ConnectionTrait, Database, Statement};
+
+    #[test]
+    fn test_query_operation_is_bounded() {
+        assert_eq!(query_operation("SELECT * FROM posts"), "select");
+        assert_eq!(query_operation("  insert INTO users VALUES (1)"), "insert");
+        assert_eq!(query_operation("COMMIT"), "transaction");
+        assert_eq!(query_operation("PRAGMA foreign_keys = ON"), "other");
+    }
+
+    #[tokio::test]
+    async fn test_database_queries_are_timed() {
+        let mut db = Database::connect("sqlite::memory:").await.unwrap();
+        instrument_database(&mut db);
+
+        db.query_one(Statement::from_string(db.get_database_backend(), "SELECT 1"))
+            .await
+            .unwrap();
+
+        let rendered = render();
+        assert!(rendered.contains(
+            "db_query_duration_seconds_count{operation=\"select\",outcome=\"ok\"}"
+        ));
+    }
+}
@@ -2,13 +2,15 @@
 // Standardized API response wrapper
 
 use axum::{
-    http::StatusCode,
+    http::{header, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
 use serde::Serialize;
 use utoipa::ToSchema;
 
+use crate::dto::etag;
+
 /// Pagination metadata
 #[derive(Debug, Serialize, ToSchema)]
 #[schema(example = json!({
@@ -26,7 +28,7 @@ pub struct PaginationMeta {
 
 impl PaginationMeta {
     pub fn new(total: u64, page: u64, per_page: u64) -> Self {
-        let total_pages = (total + per_page - 1) / per_page;
+        let total_pages = total.div_ceil(per_page);
         Self {
             total,
             page,
@@ -36,6 +38,28 @@ impl PaginationMeta {
     }
 }
 
+/// Cursor (keyset) pagination metadata
+/// Pas de total: le client suit next_cursor / prev_cursor
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(example = json!({
+    "per_page": 10,
+    "next_cursor": "YXwxNzM0MDAwMDAwMDAwMDAwMDAwfDQy",
+    "prev_cursor": null
+}))]
+pub struct CursorMeta {
+    pub per_page: u64,
+    pub next_cursor: Option<String>,
+    pub prev_cursor: Option<String>,
+}
+
+/// Meta d'une liste: pagination par page (offset) ou par curseur
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum ListMeta {
+    Page(PaginationMeta),
+    Cursor(CursorMeta),
+}
+
 /// Standard API response wrapper
 /// Format: { "data": T, "meta": Option<M> }
 #[derive(Debug, Serialize, ToSchema)]
@@ -73,6 +97,9 @@ impl<T: Serialize, M: Serialize> IntoResponse for ApiResponse<T, M> {
 /// Helper type for list responses with pagination
 pub type PaginatedResponse<T> = ApiResponse<Vec<T>, PaginationMeta>;
 
+/// Helper type for list responses paginated by page or by cursor
+pub type ListResponse<T> = ApiResponse<Vec<T>, ListMeta>;
+
 /// Response builder for common patterns
 pub struct ApiResponseBuilder;
 
@@ -100,6 +127,33 @@ impl ApiResponseBuilder {
         ApiResponse::with_meta(data, PaginationMeta::new(total, page, per_page))
     }
 
+    /// List response with cursor pagination
+    /// { "data": [...], "meta": { "per_page": 10, "next_cursor": "...", "prev_cursor": null } }
+    pub fn cursor_paginated<T: Serialize>(
+        data: Vec<T>,
+        per_page: u64,
+        next_cursor: Option<String>,
+        prev_cursor: Option<String>,
+    ) -> ListResponse<T> {
+        let meta = CursorMeta {
+            per_page,
+            next_cursor,
+            prev_cursor,
+        };
+        ApiResponse::with_meta(data, ListMeta::Cursor(meta))
+    }
+
+    /// Single item response with the ETag of its version
+    /// ETag: "3" + { "data": { ... } }
+    pub fn one_with_etag<T: Serialize>(data: T, version: i32) -> Response {
+        ([(header::ETAG, etag(version))], ApiResponse::data(data)).into_response()
+    }
+
+    /// Not modified response (304): the client already has this version
+    pub fn not_modified(version: i32) -> Response {
+        (StatusCode::NOT_MODIFIED, [(header::ETAG, etag(version))]).into_response()
+    }
+
     /// Created response (201)
     pub fn created<T: Serialize>(data: T) -> (StatusCode, ApiResponse<T>) {
         (StatusCode::CREATED, ApiResponse::data(data))
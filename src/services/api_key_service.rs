@@ -0,0 +1,133 @@
+// src/services/api_key_service.rs
+// Gestion des clés d'API (création, liste, révocation, authentification)
+
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::NotSet, ColumnTrait, DatabaseConnection, EntityTrait,
+    QueryFilter, QueryOrder, Set,
+};
+use tracing::{info, warn};
+
+use crate::auth::{generate_secret, hash_secret};
+use crate::dto::CreateApiKeyDto;
+use crate::entities::{api_key, user};
+use crate::error::ServiceError;
+
+/// Préfixe des clés générées ("rust api key")
+const KEY_PREFIX: &str = "rak_";
+
+/// Longueur du début de clé conservé en clair (préfixe inclus)
+const DISPLAY_PREFIX_LEN: usize = 12;
+
+/// Clé nouvellement créée : le secret n'est disponible qu'ici
+pub struct MintedApiKey {
+    pub api_key: api_key::Model,
+    pub secret: String,
+}
+
+/// ApiKeyService - Business logic for machine-client API keys
+#[derive(Clone)]
+pub struct ApiKeyService {
+    db: DatabaseConnection,
+}
+
+impl ApiKeyService {
+    /// Create a new ApiKeyService instance
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Crée une clé pour l'utilisateur ; seul son hash est stocké
+    pub async fn create(
+        &self,
+        owner: &user::Model,
+        dto: CreateApiKeyDto,
+    ) -> Result<MintedApiKey, ServiceError> {
+        info!(user_id = owner.id, name = %dto.name, "Creating API key");
+
+        let secret = generate_secret(KEY_PREFIX);
+        let scopes = serde_json::to_value(&dto.scopes)
+            .map_err(|err| ServiceError::Internal(format!("Scopes serialization failed: {err}")))?;
+
+        let new_key = api_key::ActiveModel {
+            id: NotSet,
+            user_id: Set(owner.id),
+            name: Set(dto.name),
+            prefix: Set(secret[..DISPLAY_PREFIX_LEN].to_string()),
+            key_hash: Set(hash_secret(&secret)),
+            scopes: Set(scopes),
+            last_used_at: Set(None),
+            revoked_at: Set(None),
+            created_at: Set(chrono::Utc::now().naive_utc()),
+        };
+
+        let api_key = new_key.insert(&self.db).await?;
+
+        info!(api_key_id = api_key.id, user_id = owner.id, "API key created successfully");
+        Ok(MintedApiKey { api_key, secret })
+    }
+
+    /// Liste les clés de l'utilisateur (révoquées incluses)
+    pub async fn find_by_user(&self, user_id: i32) -> Result<Vec<api_key::Model>, ServiceError> {
+        info!(user_id = user_id, "Fetching API keys");
+
+        let keys = api_key::Entity::find()
+            .filter(api_key::Column::UserId.eq(user_id))
+            .order_by_asc(api_key::Column::Id)
+            .all(&self.db)
+            .await?;
+
+        Ok(keys)
+    }
+
+    /// Révoque une clé de l'utilisateur (idempotent)
+    /// Une clé d'un autre utilisateur est traitée comme inexistante
+    pub async fn revoke(&self, id: i32, owner: &user::Model) -> Result<(), ServiceError> {
+        info!(api_key_id = id, user_id = owner.id, "Revoking API key");
+
+        let key = api_key::Entity::find_by_id(id)
+            .filter(api_key::Column::UserId.eq(owner.id))
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| {
+                warn!(api_key_id = id, user_id = owner.id, "API key not found for revocation");
+                ServiceError::NotFound
+            })?;
+
+        if key.revoked_at.is_some() {
+            return Ok(());
+        }
+
+        let mut active_model: api_key::ActiveModel = key.into();
+        active_model.revoked_at = Set(Some(chrono::Utc::now().naive_utc()));
+        active_model.update(&self.db).await?;
+
+        info!(api_key_id = id, "API key revoked");
+        Ok(())
+    }
+
+    /// Résout une clé présentée par un client : retourne la clé et son propriétaire,
+    /// et met à jour `last_used_at`
+    pub async fn authenticate(
+        &self,
+        secret: &str,
+    ) -> Result<(api_key::Model, user::Model), ServiceError> {
+        let found = api_key::Entity::find()
+            .filter(api_key::Column::KeyHash.eq(hash_secret(secret)))
+            .filter(api_key::Column::RevokedAt.is_null())
+            .find_also_related(user::Entity)
+            .one(&self.db)
+            .await?;
+
+        let Some((key, Some(owner))) = found else {
+            warn!("Unknown or revoked API key");
+            return Err(ServiceError::InvalidCredentials);
+        };
+
+        let mut active_model: api_key::ActiveModel = key.into();
+        active_model.last_used_at = Set(Some(chrono::Utc::now().naive_utc()));
+        let key = active_model.update(&self.db).await?;
+
+        info!(api_key_id = key.id, user_id = owner.id, "API key authenticated");
+        Ok((key, owner))
+    }
+}
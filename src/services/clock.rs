@@ -0,0 +1,53 @@
+// src/services/clock.rs
+// Horloge injectable (comme ClockInterface en Symfony)
+
+use std::sync::{Arc, Mutex};
+
+use chrono::{NaiveDateTime, TimeDelta, Utc};
+
+/// Source de l'heure courante (UTC)
+pub trait Clock: Send + Sync {
+    fn now(&self) -> NaiveDateTime;
+}
+
+/// Horloge partagée entre services
+pub type SharedClock = Arc<dyn Clock>;
+
+/// Horloge système
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> NaiveDateTime {
+        Utc::now().naive_utc()
+    }
+}
+
+/// Horloge réglée à la main (tests), partagée entre ses clones
+/// Equivalent de: MockClock en Symfony
+#[derive(Debug, Clone)]
+pub struct ManualClock {
+    now: Arc<Mutex<NaiveDateTime>>,
+}
+
+impl ManualClock {
+    pub fn new(now: NaiveDateTime) -> Self {
+        Self {
+            now: Arc::new(Mutex::new(now)),
+        }
+    }
+
+    pub fn set(&self, now: NaiveDateTime) {
+        *self.now.lock().unwrap() = now;
+    }
+
+    pub fn advance(&self, delta: TimeDelta) {
+        *self.now.lock().unwrap() += delta;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> NaiveDateTime {
+        *self.now.lock().unwrap()
+    }
+}
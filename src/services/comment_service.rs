@@ -0,0 +1,231 @@
+// src/services/comment_service.rs
+// Service pour la gestion des commentaires d'un post
+
+use std::collections::HashMap;
+
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::NotSet, ColumnTrait, DatabaseConnection, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set,
+};
+use tracing::{info, warn};
+
+use crate::auth::{ensure, Permission};
+use crate::dto::{CreateCommentDto, PaginationQuery, UpdateCommentDto};
+use crate::entities::{comment, post, user};
+use crate::error::ServiceError;
+use crate::i18n::Message;
+
+/// Commentaire avec son auteur chargé
+pub struct CommentWithAuthor {
+    pub comment: comment::Model,
+    pub author: user::Model,
+}
+
+/// Résultat paginé de commentaires
+pub struct PaginatedComments {
+    pub comments: Vec<CommentWithAuthor>,
+    pub total: u64,
+}
+
+/// CommentService - Logique métier pour les commentaires
+#[derive(Clone)]
+pub struct CommentService {
+    db: DatabaseConnection,
+}
+
+impl CommentService {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Liste paginée des commentaires d'un post (ordre chronologique)
+    pub async fn find_by_post(
+        &self,
+        post_id: i32,
+        pagination: &PaginationQuery,
+    ) -> Result<PaginatedComments, ServiceError> {
+        info!(post_id = post_id, page = pagination.page, "Fetching comments");
+
+        self.find_post(post_id).await?;
+
+        let query = comment::Entity::find().filter(comment::Column::PostId.eq(post_id));
+        let total = query.clone().count(&self.db).await?;
+
+        let comments = query
+            .find_also_related(user::Entity)
+            .order_by_asc(comment::Column::Id)
+            .offset(pagination.offset())
+            .limit(pagination.limit())
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .filter_map(|(comment, author)| Some(CommentWithAuthor { comment, author: author? }))
+            .collect();
+
+        Ok(PaginatedComments { comments, total })
+    }
+
+    /// Trouver un commentaire d'un post
+    pub async fn find_by_id(
+        &self,
+        post_id: i32,
+        id: i32,
+    ) -> Result<CommentWithAuthor, ServiceError> {
+        let (comment, author) = comment::Entity::find_by_id(id)
+            .filter(comment::Column::PostId.eq(post_id))
+            .find_also_related(user::Entity)
+            .one(&self.db)
+            .await?
+            .and_then(|(comment, author)| Some((comment, author?)))
+            .ok_or_else(|| {
+                warn!(post_id = post_id, comment_id = id, "Comment not found");
+                ServiceError::NotFound
+            })?;
+
+        Ok(CommentWithAuthor { comment, author })
+    }
+
+    /// Ajouter un commentaire (post publié, commentaires ouverts)
+    pub async fn create(
+        &self,
+        post_id: i32,
+        dto: CreateCommentDto,
+        actor: &user::Model,
+    ) -> Result<CommentWithAuthor, ServiceError> {
+        info!(post_id = post_id, actor_id = actor.id, "Creating comment");
+
+        let post = self.find_post(post_id).await?;
+        ensure_open_for_comments(&post)?;
+
+        // Une réponse doit viser un commentaire du même post
+        if let Some(parent_id) = dto.parent_id {
+            let parent = comment::Entity::find_by_id(parent_id)
+                .filter(comment::Column::PostId.eq(post_id))
+                .one(&self.db)
+                .await?;
+
+            if parent.is_none() {
+                warn!(post_id = post_id, parent_id = parent_id, "Parent comment not in post");
+                return Err(ServiceError::InvalidInput(Message::new("comment.parent_id.other_post")));
+            }
+        }
+
+        let comment = comment::ActiveModel {
+            id: NotSet,
+            post_id: Set(post_id),
+            author_id: Set(actor.id),
+            parent_id: Set(dto.parent_id),
+            body: Set(dto.body),
+            created_at: Set(chrono::Utc::now().naive_utc()),
+            updated_at: Set(None),
+        }
+        .insert(&self.db)
+        .await?;
+
+        info!(comment_id = comment.id, post_id = post_id, "Comment created");
+
+        Ok(CommentWithAuthor {
+            comment,
+            author: actor.clone(),
+        })
+    }
+
+    /// Modifier un commentaire (son auteur ou un modérateur)
+    pub async fn update(
+        &self,
+        post_id: i32,
+        id: i32,
+        dto: UpdateCommentDto,
+        actor: &user::Model,
+    ) -> Result<CommentWithAuthor, ServiceError> {
+        info!(post_id = post_id, comment_id = id, actor_id = actor.id, "Updating comment");
+
+        let existing = self.find_by_id(post_id, id).await?;
+
+        ensure(
+            actor.owns_or_can(existing.comment.author_id, Permission::ModerateComments),
+            "edit_comment",
+        )?;
+
+        let post = self.find_post(post_id).await?;
+        ensure_open_for_comments(&post)?;
+
+        let mut active_model: comment::ActiveModel = existing.comment.into();
+        active_model.body = Set(dto.body);
+        active_model.updated_at = Set(Some(chrono::Utc::now().naive_utc()));
+        let comment = active_model.update(&self.db).await?;
+
+        info!(comment_id = id, "Comment updated");
+
+        Ok(CommentWithAuthor {
+            comment,
+            author: existing.author,
+        })
+    }
+
+    /// Supprimer un commentaire et ses réponses (son auteur ou un modérateur)
+    /// Toujours possible, même si les commentaires ont été fermés depuis
+    pub async fn delete(&self, post_id: i32, id: i32, actor: &user::Model) -> Result<(), ServiceError> {
+        info!(post_id = post_id, comment_id = id, actor_id = actor.id, "Deleting comment");
+
+        let existing = self.find_by_id(post_id, id).await?;
+
+        ensure(
+            actor.owns_or_can(existing.comment.author_id, Permission::ModerateComments),
+            "delete_comment",
+        )?;
+
+        comment::Entity::delete_by_id(id).exec(&self.db).await?;
+
+        info!(comment_id = id, "Comment deleted");
+        Ok(())
+    }
+
+    /// Nombre de commentaires par post, en une seule requête
+    /// Les posts sans commentaire sont absents de la map
+    pub async fn count_by_posts(&self, post_ids: &[i32]) -> Result<HashMap<i32, u64>, ServiceError> {
+        if post_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let counts: Vec<(i32, i64)> = comment::Entity::find()
+            .select_only()
+            .column(comment::Column::PostId)
+            .column_as(comment::Column::Id.count(), "count")
+            .filter(comment::Column::PostId.is_in(post_ids.iter().copied()))
+            .group_by(comment::Column::PostId)
+            .into_tuple()
+            .all(&self.db)
+            .await?;
+
+        Ok(counts
+            .into_iter()
+            .map(|(post_id, count)| (post_id, count as u64))
+            .collect())
+    }
+
+    async fn find_post(&self, post_id: i32) -> Result<post::Model, ServiceError> {
+        post::Entity::find_by_id(post_id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| {
+                warn!(post_id = post_id, "Post not found");
+                ServiceError::NotFound
+            })
+    }
+}
+
+/// Un post n'accepte de commentaires que s'il est publié et que `allow_comments` est activé
+fn ensure_open_for_comments(post: &post::Model) -> Result<(), ServiceError> {
+    if !post.published {
+        warn!(post_id = post.id, "Comment on unpublished post");
+        return Err(ServiceError::Conflict(Message::new("comment.post.unpublished")));
+    }
+
+    if !post.allows_comments() {
+        warn!(post_id = post.id, "Comments disabled on post");
+        return Err(ServiceError::Forbidden(Message::new("comment.post.closed")));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+// src/services/diff.rs
+// Diff ligne à ligne entre deux versions d'un texte (révisions de posts)
+
+use similar::{ChangeTag, TextDiff};
+
+/// Une ligne du diff, de l'ancienne version vers la nouvelle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LineChange {
+    /// Ligne présente dans les deux versions
+    Equal(String),
+    /// Ligne uniquement dans l'ancienne version
+    Removed(String),
+    /// Ligne uniquement dans la nouvelle version
+    Added(String),
+}
+
+/// Diff ligne à ligne de `old` vers `new` (fins de ligne retirées)
+pub fn line_diff(old: &str, new: &str) -> Vec<LineChange> {
+    // Sans cela, une dernière ligne identique diffère selon la présence du `\n` final
+    let (old, new) = (with_final_newline(old), with_final_newline(new));
+
+    TextDiff::from_lines(&old, &new)
+        .iter_all_changes()
+        .map(|change| {
+            let line = change.value().trim_end_matches(['\n', '\r']).to_string();
+            match change.tag() {
+                ChangeTag::Equal => LineChange::Equal(line),
+                ChangeTag::Delete => LineChange::Removed(line),
+                ChangeTag::Insert => LineChange::Added(line),
+            }
+        })
+        .collect()
+}
+
+fn with_final_newline(text: &str) -> String {
+    if text.is_empty() || text.ends_with('\n') {
+        text.to_string()
+    } else {
+        format!("{text}\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_diff_marks_changed_lines() {
+        let diff = line_diff("intro\nancienne ligne\nfin", "intro\nnouvelle ligne\nfin\najout");
+
+        assert_eq!(
+            diff,
+            vec![
+                LineChange::Equal("intro".to_string()),
+                LineChange::Removed("ancienne ligne".to_string()),
+                LineChange::Added("nouvelle ligne".to_string()),
+                LineChange::Equal("fin".to_string()),
+                LineChange::Added("ajout".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_line_diff_identical_texts() {
+        let diff = line_diff("a\r\nb", "a\r\nb");
+
+        assert_eq!(
+            diff,
+            vec![LineChange::Equal("a".to_string()), LineChange::Equal("b".to_string())]
+        );
+    }
+}
@@ -0,0 +1,142 @@
+// src/services/health_service.rs
+// Vérification des dépendances pour la sonde de readiness
+// (comme les checks de liip/monitor-bundle en Symfony)
+
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use sea_orm::{DatabaseConnection, DbErr};
+use sea_orm_migration::MigratorTrait;
+use tracing::warn;
+
+use migration::Migrator;
+
+use crate::shutdown::Shutdown;
+
+/// Délai maximal d'un check: une base qui ne répond plus ne doit pas bloquer la sonde
+const CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Cause d'un check en échec, seule information renvoyée au client
+/// (le détail de l'erreur peut contenir du SQL ou des chemins: il reste dans les logs)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckFailure {
+    Timeout,
+    Unavailable,
+    PendingMigrations,
+}
+
+impl CheckFailure {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CheckFailure::Timeout => "timeout",
+            CheckFailure::Unavailable => "unavailable",
+            CheckFailure::PendingMigrations => "pending_migrations",
+        }
+    }
+}
+
+/// Echec d'une sonde: cause publique et détail pour les logs
+struct CheckError {
+    failure: CheckFailure,
+    detail: String,
+}
+
+impl CheckError {
+    fn unavailable(err: DbErr) -> Self {
+        Self {
+            failure: CheckFailure::Unavailable,
+            detail: err.to_string(),
+        }
+    }
+}
+
+/// Résultat d'un check: durée et cause de l'échec éventuel
+pub struct CheckOutcome {
+    pub latency: Duration,
+    pub failure: Option<CheckFailure>,
+}
+
+impl CheckOutcome {
+    pub fn is_up(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// Résultat de tous les checks de readiness
+pub struct Readiness {
+    /// L'arrêt de l'API a commencé: plus de nouveau trafic
+    pub draining: bool,
+    /// La base répond (ping)
+    pub database: CheckOutcome,
+    /// Toutes les migrations sont appliquées
+    pub migrations: CheckOutcome,
+}
+
+impl Readiness {
+    pub fn is_ready(&self) -> bool {
+        !self.draining && self.database.is_up() && self.migrations.is_up()
+    }
+}
+
+/// HealthService - Etat des dépendances de l'API
+#[derive(Clone)]
+pub struct HealthService {
+    db: DatabaseConnection,
+    shutdown: Shutdown,
+}
+
+impl HealthService {
+    pub fn new(db: DatabaseConnection, shutdown: Shutdown) -> Self {
+        Self { db, shutdown }
+    }
+
+    /// Exécute les checks de readiness (séquentiellement: les migrations supposent la base joignable)
+    pub async fn readiness(&self) -> Readiness {
+        let database = check("database", async {
+            self.db.ping().await.map_err(CheckError::unavailable)
+        })
+        .await;
+        let migrations = check("migrations", async {
+            let pending = Migrator::get_pending_migrations(&self.db)
+                .await
+                .map_err(CheckError::unavailable)?;
+            if pending.is_empty() {
+                return Ok(());
+            }
+            let names: Vec<_> = pending.iter().map(|migration| migration.name()).collect();
+            Err(CheckError {
+                failure: CheckFailure::PendingMigrations,
+                detail: format!("Pending migrations: {}", names.join(", ")),
+            })
+        })
+        .await;
+
+        Readiness {
+            draining: self.shutdown.is_triggered(),
+            database,
+            migrations,
+        }
+    }
+}
+
+/// Chronomètre un check, en échec s'il dépasse CHECK_TIMEOUT
+async fn check(name: &str, probe: impl Future<Output = Result<(), CheckError>>) -> CheckOutcome {
+    let started = Instant::now();
+    let error = match tokio::time::timeout(CHECK_TIMEOUT, probe).await {
+        Ok(Ok(())) => None,
+        Ok(Err(err)) => Some(err),
+        Err(_) => Some(CheckError {
+            failure: CheckFailure::Timeout,
+            detail: format!("Timed out after {}s", CHECK_TIMEOUT.as_secs()),
+        }),
+    };
+
+    if let Some(error) = &error {
+        warn!(check = name, error = %error.detail, "Readiness check failed");
+    }
+
+    CheckOutcome {
+        latency: started.elapsed(),
+        failure: error.map(|error| error.failure),
+    }
+}
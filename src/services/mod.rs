@@ -1,8 +1,30 @@
 // src/services/mod.rs
 // Equivalent de: src/Service/ en Symfony
 
+mod api_key_service;
+mod clock;
+mod comment_service;
+mod diff;
+mod health_service;
 mod post_service;
+mod publisher;
+mod refresh_token_service;
+mod search_service;
+mod tag_service;
 mod user_service;
 
-pub use post_service::{PaginatedPosts, PostService, PostWithAuthor};
+pub use api_key_service::{ApiKeyService, MintedApiKey};
+pub use clock::{Clock, ManualClock, SharedClock, SystemClock};
+pub use comment_service::{CommentService, CommentWithAuthor, PaginatedComments};
+pub use diff::{line_diff, LineChange};
+pub use health_service::{CheckFailure, CheckOutcome, HealthService, Readiness};
+pub use post_service::{
+    PageInfo, PaginatedPosts, PaginatedRevisions, PostService, PostWithAuthor, RevisionComparison,
+    RevisionWithEditor, SlugLookup,
+};
+pub use publisher::ScheduledPublisher;
+pub use refresh_token_service::RefreshTokenService;
+pub use search_service::{PaginatedSearchHits, SearchHit, SearchService};
+pub use tag_service::{TagService, TagWithCount};
+pub(crate) use tag_service::sync_post_tags;
 pub use user_service::{PaginatedUsers, UserService};
@@ -1,15 +1,29 @@
 // src/services/post_service.rs
 // Service pour la gestion des posts avec nested objects
 
+use std::collections::HashSet;
+
+use chrono::NaiveDateTime;
+
+use sea_orm::sea_query::Query;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
-    QueryOrder, QuerySelect, Set,
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, DbErr,
+    EntityTrait, Order, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Select, Set, SqlErr,
+    TransactionTrait,
 };
 use tracing::{info, warn};
 
-use crate::dto::{CreatePostDto, PaginationQuery, UpdatePostDto};
-use crate::entities::{post, user};
+use crate::auth::{ensure, Permission};
+use crate::dto::{
+    stale_version, version_conflict, CreatePostDto, Cursor, CursorDirection, IfMatch, PageRequest,
+    PaginationQuery, PostFilterQuery, UpdatePostDto,
+};
+use crate::entities::post::PostStatus;
+use crate::entities::{post, post_revision, post_slug_redirect, post_tag, tag, user};
 use crate::error::ServiceError;
+use crate::i18n::Message;
+use crate::metrics;
+use crate::services::{line_diff, sync_post_tags, LineChange, SharedClock};
 
 /// Post avec son auteur chargé
 pub struct PostWithAuthor {
@@ -17,53 +31,84 @@ pub struct PostWithAuthor {
     pub author: user::Model,
 }
 
+/// Résultat de la recherche d'un post par slug
+pub enum SlugLookup {
+    /// Slug courant du post
+    Found(Box<PostWithAuthor>),
+    /// Ancien slug: le post est désormais sous ce slug
+    Moved(String),
+}
+
+/// Révision d'un post avec l'utilisateur qui l'a remplacée (None s'il a été supprimé)
+pub struct RevisionWithEditor {
+    pub revision: post_revision::Model,
+    pub editor: Option<user::Model>,
+}
+
+/// Résultat paginé de révisions
+pub struct PaginatedRevisions {
+    pub revisions: Vec<RevisionWithEditor>,
+    pub total: u64,
+}
+
+/// Révision comparée à la version courante du post (diffs ligne à ligne, révision -> courant)
+pub struct RevisionComparison {
+    pub revision: RevisionWithEditor,
+    pub title: Vec<LineChange>,
+    pub content: Vec<LineChange>,
+    /// Diff du JSON metadata (indenté, une clé par ligne)
+    pub metadata: Vec<LineChange>,
+}
+
+/// Informations de pagination d'une page de posts
+pub enum PageInfo {
+    /// Mode offset: nombre total de posts correspondants
+    Offset { total: u64, page: u64, per_page: u64 },
+    /// Mode curseur: positions des pages voisines (pas de count())
+    Cursor {
+        per_page: u64,
+        next: Option<Cursor>,
+        prev: Option<Cursor>,
+    },
+}
+
 /// Résultat paginé de posts
 pub struct PaginatedPosts {
     pub posts: Vec<PostWithAuthor>,
-    pub total: u64,
+    pub page_info: PageInfo,
 }
 
 /// PostService - Logique métier pour les posts
 #[derive(Clone)]
 pub struct PostService {
     db: DatabaseConnection,
+    clock: SharedClock,
 }
 
 impl PostService {
-    pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+    pub fn new(db: DatabaseConnection, clock: SharedClock) -> Self {
+        Self { db, clock }
     }
 
-    /// Liste paginée des posts avec leurs auteurs
-    pub async fn find_all(&self, pagination: &PaginationQuery) -> Result<PaginatedPosts, ServiceError> {
-        info!(page = pagination.page, per_page = pagination.per_page, "Fetching posts");
+    /// Liste paginée des posts avec leurs auteurs (filtrée et triée)
+    pub async fn find_all(
+        &self,
+        page: &PageRequest,
+        filter: &PostFilterQuery,
+    ) -> Result<PaginatedPosts, ServiceError> {
+        info!(?page, ?filter, "Fetching posts");
 
-        let total = post::Entity::find().count(&self.db).await?;
-
-        let posts = post::Entity::find()
-            .order_by_desc(post::Column::CreatedAt)
-            .offset(pagination.offset())
-            .limit(pagination.limit())
-            .all(&self.db)
-            .await?;
-
-        // Charger les auteurs pour chaque post
-        let mut posts_with_authors = Vec::with_capacity(posts.len());
-        for p in posts {
-            let author = user::Entity::find_by_id(p.author_id)
-                .one(&self.db)
-                .await?
-                .ok_or(ServiceError::NotFound)?;
-
-            posts_with_authors.push(PostWithAuthor { post: p, author });
+        if matches!(page, PageRequest::Cursor { .. }) && filter.sort.is_some() {
+            return Err(ServiceError::InvalidInput(Message::new("post.sort.cursor")));
         }
 
-        info!(count = posts_with_authors.len(), total = total, "Posts fetched");
+        let query = post::Entity::find().filter(filter.condition());
+        let (rows, page_info) = self.fetch_page(query, page, filter.order_by()).await?;
+        let posts = with_authors(rows);
 
-        Ok(PaginatedPosts {
-            posts: posts_with_authors,
-            total,
-        })
+        info!(count = posts.len(), "Posts fetched");
+
+        Ok(PaginatedPosts { posts, page_info })
     }
 
     /// Trouver un post par ID avec son auteur
@@ -91,18 +136,69 @@ impl PostService {
         Ok(PostWithAuthor { post, author })
     }
 
-    /// Créer un nouveau post
-    pub async fn create(&self, dto: CreatePostDto) -> Result<PostWithAuthor, ServiceError> {
-        info!(title = %dto.title, author_id = dto.author_id, "Creating post");
+    /// Trouver un post par slug ; un ancien slug renvoie le slug courant
+    pub async fn find_by_slug(&self, slug: &str) -> Result<SlugLookup, ServiceError> {
+        info!(slug, "Fetching post by slug");
 
-        // Vérifier que l'auteur existe
-        let author = user::Entity::find_by_id(dto.author_id)
+        let found = post::Entity::find()
+            .filter(post::Column::Slug.eq(slug))
+            .find_also_related(user::Entity)
             .one(&self.db)
-            .await?
-            .ok_or_else(|| {
-                warn!(author_id = dto.author_id, "Author not found");
+            .await?;
+
+        if let Some((post, author)) = found {
+            let author = author.ok_or_else(|| {
+                warn!(author_id = post.author_id, "Author not found");
                 ServiceError::NotFound
             })?;
+            return Ok(SlugLookup::Found(Box::new(PostWithAuthor { post, author })));
+        }
+
+        let redirect = post_slug_redirect::Entity::find_by_id(slug)
+            .find_also_related(post::Entity)
+            .one(&self.db)
+            .await?;
+
+        match redirect {
+            Some((_, Some(post))) => {
+                info!(slug, current = %post.slug, "Old slug, redirecting");
+                Ok(SlugLookup::Moved(post.slug))
+            }
+            _ => {
+                warn!(slug, "Post not found by slug");
+                Err(ServiceError::NotFound)
+            }
+        }
+    }
+
+    /// Créer un nouveau post
+    /// L'auteur est l'utilisateur authentifié ; seul un admin peut publier pour un autre
+    pub async fn create(
+        &self,
+        dto: CreatePostDto,
+        actor: &user::Model,
+    ) -> Result<PostWithAuthor, ServiceError> {
+        let author_id = dto.author_id.unwrap_or(actor.id);
+
+        info!(title = %dto.title, author_id, actor_id = actor.id, "Creating post");
+
+        if author_id != actor.id {
+            warn!(author_id, actor_id = actor.id, "Attempt to post on behalf of another user");
+            ensure(actor.can(Permission::PublishOnBehalf), "post_on_behalf")?;
+        }
+
+        // Vérifier que l'auteur existe
+        let author = if author_id == actor.id {
+            actor.clone()
+        } else {
+            user::Entity::find_by_id(author_id)
+                .one(&self.db)
+                .await?
+                .ok_or_else(|| {
+                    warn!(author_id = author_id, "Author not found");
+                    ServiceError::NotFound
+                })?
+        };
 
         // Convertir metadata DTO en JSON
         let metadata_json = dto
@@ -110,27 +206,63 @@ impl PostService {
             .unwrap_or_default()
             .to_json();
 
+        let now = self.clock.now();
+        let (status, publish_at) = resolve_status(
+            dto.status,
+            dto.publish_at,
+            dto.published.then_some(true),
+            (PostStatus::Draft, None),
+            now,
+        )?;
+
+        // Post, slug et table post_tags écrits ensemble
+        let txn = self.db.begin().await?;
+
+        let slug = match dto.slug {
+            Some(slug) => {
+                ensure_slug_available(&txn, &slug).await?;
+                slug
+            }
+            None => unique_slug(&txn, &post::slugify(&dto.title), None).await?,
+        };
+
         let new_post = post::ActiveModel {
             id: sea_orm::ActiveValue::NotSet,
             title: Set(dto.title),
+            slug: Set(slug),
             content: Set(dto.content),
-            author_id: Set(dto.author_id),
+            author_id: Set(author_id),
             metadata: Set(metadata_json),
-            published: Set(dto.published),
-            created_at: Set(chrono::Utc::now().naive_utc()),
+            published: Set(status == PostStatus::Published),
+            status: Set(status),
+            publish_at: Set(publish_at),
+            created_at: Set(now),
             updated_at: Set(None),
+            version: Set(1),
         };
 
-        let post = new_post.insert(&self.db).await?;
+        let post = new_post.insert(&txn).await.map_err(slug_conflict)?;
+        sync_post_tags(&txn, post.id, &post.get_tags()).await?;
+        txn.commit().await?;
+        if post.status == PostStatus::Published {
+            metrics::record_posts_published(1);
+        }
 
         info!(post_id = post.id, title = %post.title, "Post created");
 
         Ok(PostWithAuthor { post, author })
     }
 
-    /// Modifier un post
-    pub async fn update(&self, id: i32, dto: UpdatePostDto) -> Result<PostWithAuthor, ServiceError> {
-        info!(post_id = id, "Updating post");
+    /// Modifier un post (auteur, editor ou admin uniquement)
+    /// `if_match`: version lue par le client (412 si le post a changé entre-temps)
+    pub async fn update(
+        &self,
+        id: i32,
+        dto: UpdatePostDto,
+        actor: &user::Model,
+        if_match: &IfMatch,
+    ) -> Result<PostWithAuthor, ServiceError> {
+        info!(post_id = id, actor_id = actor.id, "Updating post");
 
         let existing = post::Entity::find_by_id(id)
             .one(&self.db)
@@ -140,29 +272,73 @@ impl PostService {
                 ServiceError::NotFound
             })?;
 
+        ensure(
+            actor.owns_or_can(existing.author_id, Permission::ManageAnyPost),
+            "edit_post",
+        )?;
+        if_match.ensure(existing.version)?;
+
         let author = user::Entity::find_by_id(existing.author_id)
             .one(&self.db)
             .await?
             .ok_or(ServiceError::NotFound)?;
 
+        let now = self.clock.now();
+        let (status, publish_at) = resolve_status(
+            dto.status,
+            dto.publish_at,
+            dto.published,
+            (existing.status, existing.publish_at),
+            now,
+        )?;
+
+        let metadata = dto.metadata.map(|metadata| metadata.to_json());
+        let edits_content = dto.title.as_ref().is_some_and(|title| *title != existing.title)
+            || dto.content.as_ref().is_some_and(|content| *content != existing.content)
+            || metadata.as_ref().is_some_and(|metadata| *metadata != existing.metadata);
+
+        let txn = self.db.begin().await?;
+
+        // La version remplacée est conservée (titre, contenu, metadata uniquement)
+        if edits_content {
+            record_revision(&txn, &existing, actor.id, now).await?;
+        }
+        let new_slug = match &dto.title {
+            Some(title) => retitle(&txn, &existing, title, now).await?,
+            None => None,
+        };
+
+        let publishes = status == PostStatus::Published && existing.status != PostStatus::Published;
+        let read_version = existing.version;
         let mut active_model: post::ActiveModel = existing.into();
 
         if let Some(title) = dto.title {
             active_model.title = Set(title);
         }
+        if let Some(slug) = new_slug {
+            active_model.slug = Set(slug);
+        }
         if let Some(content) = dto.content {
             active_model.content = Set(content);
         }
-        if let Some(metadata) = dto.metadata {
-            active_model.metadata = Set(metadata.to_json());
-        }
-        if let Some(published) = dto.published {
-            active_model.published = Set(published);
+        let metadata_changed = metadata.is_some();
+        if let Some(metadata) = metadata {
+            active_model.metadata = Set(metadata);
         }
+        active_model.published = Set(status == PostStatus::Published);
+        active_model.status = Set(status);
+        active_model.publish_at = Set(publish_at);
 
-        active_model.updated_at = Set(Some(chrono::Utc::now().naive_utc()));
+        active_model.updated_at = Set(Some(now));
 
-        let updated = active_model.update(&self.db).await?;
+        let updated = update_versioned(&txn, active_model, read_version).await?;
+        if metadata_changed {
+            sync_post_tags(&txn, updated.id, &updated.get_tags()).await?;
+        }
+        txn.commit().await?;
+        if publishes {
+            metrics::record_posts_published(1);
+        }
 
         info!(post_id = id, "Post updated");
 
@@ -172,75 +348,672 @@ impl PostService {
         })
     }
 
-    /// Supprimer un post
-    pub async fn delete(&self, id: i32) -> Result<(), ServiceError> {
-        info!(post_id = id, "Deleting post");
+    /// Historique paginé des révisions d'un post, la plus récente d'abord
+    /// (auteur, editor ou admin uniquement)
+    pub async fn find_revisions(
+        &self,
+        id: i32,
+        pagination: &PaginationQuery,
+        actor: &user::Model,
+    ) -> Result<PaginatedRevisions, ServiceError> {
+        info!(post_id = id, page = pagination.page, "Fetching post revisions");
 
-        let result = post::Entity::delete_by_id(id).exec(&self.db).await?;
+        self.editable_post(id, actor).await?;
 
-        if result.rows_affected == 0 {
-            warn!(post_id = id, "Post not found for deletion");
-            return Err(ServiceError::NotFound);
-        }
+        let query = post_revision::Entity::find().filter(post_revision::Column::PostId.eq(id));
+        let total = query.clone().count(&self.db).await?;
 
-        info!(post_id = id, "Post deleted");
-        Ok(())
+        let revisions = query
+            .find_also_related(user::Entity)
+            .order_by_desc(post_revision::Column::Revision)
+            .offset(pagination.offset())
+            .limit(pagination.limit())
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(|(revision, editor)| RevisionWithEditor { revision, editor })
+            .collect();
+
+        Ok(PaginatedRevisions { revisions, total })
+    }
+
+    /// Une révision et son diff avec la version courante
+    pub async fn find_revision(
+        &self,
+        id: i32,
+        revision: i32,
+        actor: &user::Model,
+    ) -> Result<RevisionComparison, ServiceError> {
+        info!(post_id = id, revision, "Fetching post revision");
+
+        let current = self.editable_post(id, actor).await?;
+        let (revision, editor) = post_revision::Entity::find()
+            .filter(post_revision::Column::PostId.eq(id))
+            .filter(post_revision::Column::Revision.eq(revision))
+            .find_also_related(user::Entity)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| {
+                warn!(post_id = id, revision, "Revision not found");
+                ServiceError::NotFound
+            })?;
+
+        Ok(RevisionComparison {
+            title: line_diff(&revision.title, &current.title),
+            content: line_diff(&revision.content, &current.content),
+            metadata: line_diff(
+                &pretty_json(&revision.metadata),
+                &pretty_json(&current.metadata),
+            ),
+            revision: RevisionWithEditor { revision, editor },
+        })
     }
 
-    /// Trouver les posts d'un auteur
-    pub async fn find_by_author(&self, author_id: i32) -> Result<Vec<PostWithAuthor>, ServiceError> {
-        info!(author_id = author_id, "Fetching posts by author");
+    /// Restaure le titre, le contenu et les metadata d'une révision
+    /// La version remplacée devient elle-même une révision (restauration annulable)
+    pub async fn restore_revision(
+        &self,
+        id: i32,
+        revision: i32,
+        actor: &user::Model,
+        if_match: &IfMatch,
+    ) -> Result<PostWithAuthor, ServiceError> {
+        info!(post_id = id, revision, actor_id = actor.id, "Restoring post revision");
+
+        let existing = self.editable_post(id, actor).await?;
+        if_match.ensure(existing.version)?;
+        let snapshot = post_revision::Entity::find()
+            .filter(post_revision::Column::PostId.eq(id))
+            .filter(post_revision::Column::Revision.eq(revision))
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| {
+                warn!(post_id = id, revision, "Revision not found");
+                ServiceError::NotFound
+            })?;
 
-        let author = user::Entity::find_by_id(author_id)
+        let author = user::Entity::find_by_id(existing.author_id)
             .one(&self.db)
             .await?
             .ok_or(ServiceError::NotFound)?;
 
-        let posts = post::Entity::find()
-            .filter(post::Column::AuthorId.eq(author_id))
-            .order_by_desc(post::Column::CreatedAt)
-            .all(&self.db)
-            .await?;
+        let unchanged = snapshot.title == existing.title
+            && snapshot.content == existing.content
+            && snapshot.metadata == existing.metadata;
+        if unchanged {
+            info!(post_id = id, revision, "Revision identical to current version");
+            return Ok(PostWithAuthor { post: existing, author });
+        }
 
-        let posts_with_authors = posts
-            .into_iter()
-            .map(|p| PostWithAuthor {
-                post: p,
-                author: author.clone(),
-            })
-            .collect();
+        let now = self.clock.now();
+        let txn = self.db.begin().await?;
 
-        Ok(posts_with_authors)
+        record_revision(&txn, &existing, actor.id, now).await?;
+        let new_slug = retitle(&txn, &existing, &snapshot.title, now).await?;
+
+        let read_version = existing.version;
+        let mut active_model: post::ActiveModel = existing.into();
+        active_model.title = Set(snapshot.title);
+        if let Some(slug) = new_slug {
+            active_model.slug = Set(slug);
+        }
+        active_model.content = Set(snapshot.content);
+        active_model.metadata = Set(snapshot.metadata);
+        active_model.updated_at = Set(Some(now));
+
+        let restored = update_versioned(&txn, active_model, read_version).await?;
+        sync_post_tags(&txn, restored.id, &restored.get_tags()).await?;
+        txn.commit().await?;
+
+        info!(post_id = id, revision, "Post revision restored");
+
+        Ok(PostWithAuthor {
+            post: restored,
+            author,
+        })
     }
 
-    /// Trouver les posts publiés uniquement
-    pub async fn find_published(&self, pagination: &PaginationQuery) -> Result<PaginatedPosts, ServiceError> {
-        let total = post::Entity::find()
-            .filter(post::Column::Published.eq(true))
-            .count(&self.db)
-            .await?;
+    /// Post modifiable par l'acteur (auteur, editor ou admin), sinon 404 / 403
+    pub async fn editable_post(
+        &self,
+        id: i32,
+        actor: &user::Model,
+    ) -> Result<post::Model, ServiceError> {
+        let post = post::Entity::find_by_id(id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| {
+                warn!(post_id = id, "Post not found");
+                ServiceError::NotFound
+            })?;
 
-        let posts = post::Entity::find()
-            .filter(post::Column::Published.eq(true))
-            .order_by_desc(post::Column::CreatedAt)
-            .offset(pagination.offset())
-            .limit(pagination.limit())
-            .all(&self.db)
+        ensure(
+            actor.owns_or_can(post.author_id, Permission::ManageAnyPost),
+            "edit_post",
+        )?;
+
+        Ok(post)
+    }
+
+    /// Supprimer un post (auteur, editor ou admin uniquement)
+    pub async fn delete(
+        &self,
+        id: i32,
+        actor: &user::Model,
+        if_match: &IfMatch,
+    ) -> Result<(), ServiceError> {
+        info!(post_id = id, actor_id = actor.id, "Deleting post");
+
+        let existing = post::Entity::find_by_id(id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| {
+                warn!(post_id = id, "Post not found for deletion");
+                ServiceError::NotFound
+            })?;
+
+        ensure(
+            actor.owns_or_can(existing.author_id, Permission::ManageAnyPost),
+            "delete_post",
+        )?;
+        if_match.ensure(existing.version)?;
+
+        // Suppression conditionnée sur la version lue: une modification concurrente gagne
+        let result = post::Entity::delete_many()
+            .filter(post::Column::Id.eq(id))
+            .filter(post::Column::Version.eq(existing.version))
+            .exec(&self.db)
             .await?;
+        if result.rows_affected == 0 {
+            return Err(stale_version());
+        }
 
-        let mut posts_with_authors = Vec::with_capacity(posts.len());
-        for p in posts {
-            let author = user::Entity::find_by_id(p.author_id)
-                .one(&self.db)
-                .await?
-                .ok_or(ServiceError::NotFound)?;
+        info!(post_id = id, "Post deleted");
+        Ok(())
+    }
+
+    /// Liste paginée des posts d'un auteur
+    pub async fn find_by_author(
+        &self,
+        author_id: i32,
+        page: &PageRequest,
+    ) -> Result<PaginatedPosts, ServiceError> {
+        info!(author_id, ?page, "Fetching posts by author");
+
+        let author_exists = user::Entity::find_by_id(author_id).count(&self.db).await? > 0;
+        if !author_exists {
+            warn!(author_id, "Author not found");
+            return Err(ServiceError::NotFound);
+        }
+
+        let query = post::Entity::find().filter(post::Column::AuthorId.eq(author_id));
+        let (rows, page_info) = self.fetch_page(query, page, newest_first()).await?;
+
+        Ok(PaginatedPosts {
+            posts: with_authors(rows),
+            page_info,
+        })
+    }
+
+    /// Liste paginée des posts portant un tag (404 si le tag n'existe pas)
+    pub async fn find_by_tag(
+        &self,
+        name: &str,
+        page: &PageRequest,
+    ) -> Result<PaginatedPosts, ServiceError> {
+        info!(tag = name, ?page, "Fetching posts by tag");
 
-            posts_with_authors.push(PostWithAuthor { post: p, author });
+        let tag_exists = tag::Entity::find()
+            .filter(tag::Column::Name.eq(name))
+            .count(&self.db)
+            .await?
+            > 0;
+        if !tag_exists {
+            warn!(tag = name, "Tag not found");
+            return Err(ServiceError::NotFound);
         }
 
+        let query = post::Entity::find()
+            .filter(post::Column::Id.in_subquery(post_tag::post_ids_with_tag(name)));
+        let (rows, page_info) = self.fetch_page(query, page, newest_first()).await?;
+
+        Ok(PaginatedPosts {
+            posts: with_authors(rows),
+            page_info,
+        })
+    }
+
+    /// Trouver les posts publiés uniquement
+    pub async fn find_published(&self, page: &PageRequest) -> Result<PaginatedPosts, ServiceError> {
+        info!(?page, "Fetching published posts");
+
+        let query = post::Entity::find().filter(post::Column::Published.eq(true));
+        let (rows, page_info) = self.fetch_page(query, page, newest_first()).await?;
+
         Ok(PaginatedPosts {
-            posts: posts_with_authors,
-            total,
+            posts: with_authors(rows),
+            page_info,
+        })
+    }
+
+    /// Exécute la requête de liste selon le mode de pagination, auteurs chargés par LEFT JOIN
+    /// Les posts orphelins sont exclus de la requête: `total` et les pages comptent les mêmes lignes
+    /// - offset: count() + OFFSET/LIMIT avec le tri demandé
+    /// - curseur: seek sur (created_at, id) DESC, une ligne de plus pour savoir s'il reste une page
+    async fn fetch_page(
+        &self,
+        query: Select<post::Entity>,
+        page: &PageRequest,
+        orders: Vec<(post::Column, Order)>,
+    ) -> Result<(Vec<PostRow>, PageInfo), ServiceError> {
+        let query = query.filter(
+            post::Column::AuthorId.in_subquery(
+                Query::select().column(user::Column::Id).from(user::Entity).to_owned(),
+            ),
+        );
+
+        match *page {
+            PageRequest::Offset { page, per_page } => {
+                let total = query.clone().count(&self.db).await?;
+
+                let posts = orders
+                    .into_iter()
+                    .fold(query.find_also_related(user::Entity), |query, (column, order)| {
+                        query.order_by(column, order)
+                    })
+                    .offset(page.saturating_sub(1) * per_page)
+                    .limit(per_page)
+                    .all(&self.db)
+                    .await?;
+
+                Ok((posts, PageInfo::Offset { total, page, per_page }))
+            }
+            PageRequest::Cursor { cursor, per_page } => {
+                let direction = cursor.map_or(CursorDirection::After, |c| c.direction);
+                let order = match direction {
+                    CursorDirection::After => Order::Desc,
+                    CursorDirection::Before => Order::Asc,
+                };
+
+                let query = match cursor {
+                    Some(cursor) => query.filter(seek_condition(&cursor)),
+                    None => query,
+                };
+
+                let mut posts = query
+                    .find_also_related(user::Entity)
+                    .order_by(post::Column::CreatedAt, order.clone())
+                    .order_by(post::Column::Id, order)
+                    .limit(per_page + 1)
+                    .all(&self.db)
+                    .await?;
+
+                let has_more = posts.len() as u64 > per_page;
+                posts.truncate(per_page as usize);
+                if direction == CursorDirection::Before {
+                    posts.reverse();
+                }
+
+                // Après un retour en arrière, la page suivante existe toujours
+                let more_after = match direction {
+                    CursorDirection::After => has_more,
+                    CursorDirection::Before => true,
+                };
+                let more_before = match direction {
+                    CursorDirection::After => cursor.is_some(),
+                    CursorDirection::Before => has_more,
+                };
+
+                let next = posts
+                    .last()
+                    .filter(|_| more_after)
+                    .map(|(p, _)| Cursor::after(p.created_at, p.id));
+                let prev = posts
+                    .first()
+                    .filter(|_| more_before)
+                    .map(|(p, _)| Cursor::before(p.created_at, p.id));
+
+                Ok((posts, PageInfo::Cursor { per_page, next, prev }))
+            }
+        }
+    }
+}
+
+/// Ligne de liste: post + auteur du LEFT JOIN (None si l'auteur a disparu)
+pub(crate) type PostRow = (post::Model, Option<user::Model>);
+
+/// Associe chaque post à son auteur
+/// Un post orphelin est ignoré plutôt que de faire échouer toute la page
+pub(crate) fn with_authors(rows: Vec<PostRow>) -> Vec<PostWithAuthor> {
+    rows.into_iter()
+        .filter_map(|(post, author)| match author {
+            Some(author) => Some(PostWithAuthor { post, author }),
+            None => {
+                warn!(
+                    post_id = post.id,
+                    author_id = post.author_id,
+                    "Author not found, post skipped"
+                );
+                None
+            }
+        })
+        .collect()
+}
+
+/// Calcule (statut, publish_at) à partir de la demande et de l'état actuel du post
+/// Priorité: `status` explicite, puis `publish_at` (futur = scheduled),
+/// puis le booléen `published`
+fn resolve_status(
+    status: Option<PostStatus>,
+    publish_at: Option<NaiveDateTime>,
+    published: Option<bool>,
+    (current_status, current_at): (PostStatus, Option<NaiveDateTime>),
+    now: NaiveDateTime,
+) -> Result<(PostStatus, Option<NaiveDateTime>), ServiceError> {
+    let invalid = |key: &'static str| ServiceError::InvalidInput(Message::new(key));
+
+    if let (Some(status), Some(published)) = (status, published)
+        && published != (status == PostStatus::Published)
+    {
+        return Err(invalid("post.published.contradicts_status"));
+    }
+
+    let requested = status.or(match publish_at {
+        Some(at) if at > now => Some(PostStatus::Scheduled),
+        Some(_) => Some(PostStatus::Published),
+        None => published.map(|published| {
+            if published { PostStatus::Published } else { PostStatus::Draft }
+        }),
+    });
+    let Some(status) = requested else {
+        return Ok((current_status, current_at));
+    };
+    // La date actuelle n'est conservée que si le statut ne change pas de nature
+    let kept_at = current_at.filter(|_| current_status == status);
+
+    match status {
+        PostStatus::Scheduled => {
+            let at = publish_at
+                .or(kept_at)
+                .ok_or_else(|| invalid("post.publish_at.required"))?;
+            if at <= now {
+                return Err(invalid("post.publish_at.past"));
+            }
+            Ok((status, Some(at)))
+        }
+        PostStatus::Published => {
+            if publish_at.is_some_and(|at| at > now) {
+                return Err(invalid("post.publish_at.future"));
+            }
+            Ok((status, Some(publish_at.or(kept_at).unwrap_or(now))))
+        }
+        PostStatus::Draft | PostStatus::Archived => {
+            if publish_at.is_some() {
+                return Err(invalid("post.publish_at.not_allowed"));
+            }
+            // Un post archivé garde sa date de publication
+            let archived_at = current_at.filter(|_| {
+                matches!(current_status, PostStatus::Published | PostStatus::Archived)
+            });
+            Ok((status, archived_at.filter(|_| status == PostStatus::Archived)))
+        }
+    }
+}
+
+/// Ecrit un post seulement s'il est toujours à la version lue (`WHERE version = ?`)
+/// et incrémente sa version ; 412 si un autre client l'a modifié entre-temps
+async fn update_versioned<C: ConnectionTrait>(
+    db: &C,
+    mut active_model: post::ActiveModel,
+    read_version: i32,
+) -> Result<post::Model, ServiceError> {
+    active_model.version = Set(read_version + 1);
+    post::Entity::update(active_model)
+        .filter(post::Column::Version.eq(read_version))
+        .exec(db)
+        .await
+        .map_err(|err| match err {
+            DbErr::RecordNotUpdated => version_conflict(err),
+            err => slug_conflict(err),
         })
+}
+
+/// Deux écritures concurrentes du même slug: la contrainte UNIQUE de posts.slug
+/// rejette la seconde, c'est un 409 comme un slug déjà pris (pas une erreur serveur)
+fn slug_conflict(err: DbErr) -> ServiceError {
+    match err.sql_err() {
+        Some(SqlErr::UniqueConstraintViolation(detail)) if detail.contains("posts.slug") => {
+            warn!(%detail, "Slug taken concurrently");
+            ServiceError::Conflict(Message::new("post.slug.conflict"))
+        }
+        _ => err.into(),
+    }
+}
+
+/// Slugs déjà pris (posts et anciens slugs) parmi `base` et `base-N`, hors ceux du post `own`
+async fn taken_slugs<C: ConnectionTrait>(
+    db: &C,
+    base: &str,
+    own: Option<i32>,
+) -> Result<HashSet<String>, ServiceError> {
+    // Les variantes suffixées raccourcissent la base: on cherche sur le préfixe commun
+    let stem: String = base.chars().take(post::SLUG_MAX_LENGTH - MAX_SLUG_SUFFIX_LEN).collect();
+    let candidates = format!("{stem}%");
+
+    let mut posts = post::Entity::find()
+        .select_only()
+        .column(post::Column::Slug)
+        .filter(post::Column::Slug.like(&candidates));
+    let mut redirects = post_slug_redirect::Entity::find()
+        .select_only()
+        .column(post_slug_redirect::Column::Slug)
+        .filter(post_slug_redirect::Column::Slug.like(&candidates));
+    if let Some(id) = own {
+        posts = posts.filter(post::Column::Id.ne(id));
+        redirects = redirects.filter(post_slug_redirect::Column::PostId.ne(id));
+    }
+
+    let mut taken: HashSet<String> = posts.into_tuple().all(db).await?.into_iter().collect();
+    taken.extend(redirects.into_tuple::<String>().all(db).await?);
+    Ok(taken)
+}
+
+/// Longueur maximale d'un suffixe `-n` (jusqu'à `-9999999`)
+const MAX_SLUG_SUFFIX_LEN: usize = 8;
+
+/// Premier slug libre parmi `base`, `base-2`, `base-3`...
+async fn unique_slug<C: ConnectionTrait>(
+    db: &C,
+    base: &str,
+    own: Option<i32>,
+) -> Result<String, ServiceError> {
+    let taken = taken_slugs(db, base, own).await?;
+
+    let slug = std::iter::once(base.to_string())
+        .chain((2..).map(|n| post::suffixed_slug(base, n)))
+        .find(|slug| !taken.contains(slug))
+        .unwrap_or_else(|| base.to_string());
+    Ok(slug)
+}
+
+/// Un slug choisi par le client ne doit être ni utilisé ni réservé par une redirection
+async fn ensure_slug_available<C: ConnectionTrait>(db: &C, slug: &str) -> Result<(), ServiceError> {
+    if taken_slugs(db, slug, None).await?.contains(slug) {
+        warn!(slug, "Slug already in use");
+        return Err(ServiceError::Conflict(Message::new("post.slug.taken").param("slug", slug)));
+    }
+    Ok(())
+}
+
+/// Garde l'ancien slug en redirection ; un post qui reprend un de ses anciens slugs le libère
+async fn move_slug<C: ConnectionTrait>(
+    db: &C,
+    post_id: i32,
+    old: &str,
+    new: &str,
+    now: NaiveDateTime,
+) -> Result<(), ServiceError> {
+    post_slug_redirect::Entity::delete_by_id(new).exec(db).await?;
+
+    post_slug_redirect::ActiveModel {
+        slug: Set(old.to_string()),
+        post_id: Set(post_id),
+        created_at: Set(now),
+    }
+    .insert(db)
+    .await?;
+
+    info!(post_id, old, new, "Post slug changed");
+    Ok(())
+}
+
+/// Nouveau titre => nouveau slug ; l'ancien reste valable comme redirection
+/// Retourne le slug à enregistrer, None s'il ne change pas
+async fn retitle<C: ConnectionTrait>(
+    db: &C,
+    existing: &post::Model,
+    title: &str,
+    now: NaiveDateTime,
+) -> Result<Option<String>, ServiceError> {
+    if title == existing.title {
+        return Ok(None);
+    }
+
+    let slug = unique_slug(db, &post::slugify(title), Some(existing.id)).await?;
+    if slug == existing.slug {
+        return Ok(None);
+    }
+
+    move_slug(db, existing.id, &existing.slug, &slug, now).await?;
+    Ok(Some(slug))
+}
+
+/// Enregistre la version actuelle du post comme révision suivante
+async fn record_revision<C: ConnectionTrait>(
+    db: &C,
+    existing: &post::Model,
+    editor_id: i32,
+    now: NaiveDateTime,
+) -> Result<(), ServiceError> {
+    let last: Option<i32> = post_revision::Entity::find()
+        .select_only()
+        .column_as(post_revision::Column::Revision.max(), "revision")
+        .filter(post_revision::Column::PostId.eq(existing.id))
+        .into_tuple()
+        .one(db)
+        .await?
+        .flatten();
+    let revision = last.unwrap_or(0) + 1;
+
+    post_revision::ActiveModel {
+        id: sea_orm::ActiveValue::NotSet,
+        post_id: Set(existing.id),
+        revision: Set(revision),
+        title: Set(existing.title.clone()),
+        content: Set(existing.content.clone()),
+        metadata: Set(existing.metadata.clone()),
+        edited_by: Set(Some(editor_id)),
+        created_at: Set(now),
+    }
+    .insert(db)
+    .await?;
+
+    info!(post_id = existing.id, revision, "Post revision recorded");
+    Ok(())
+}
+
+/// JSON indenté (une valeur par ligne) pour un diff lisible des metadata
+fn pretty_json(value: &serde_json::Value) -> String {
+    serde_json::to_string_pretty(value).unwrap_or_default()
+}
+
+/// Tri par défaut des listes: plus récents d'abord, id en départage
+fn newest_first() -> Vec<(post::Column, Order)> {
+    vec![
+        (post::Column::CreatedAt, Order::Desc),
+        (post::Column::Id, Order::Desc),
+    ]
+}
+
+/// Condition keyset: lignes strictement après (ou avant) la position du curseur
+fn seek_condition(cursor: &Cursor) -> Condition {
+    let (created_at, id) = (post::Column::CreatedAt, post::Column::Id);
+
+    match cursor.direction {
+        CursorDirection::After => Condition::any()
+            .add(created_at.lt(cursor.created_at))
+            .add(created_at.eq(cursor.created_at).and(id.lt(cursor.id))),
+        CursorDirection::Before => Condition::any()
+            .add(created_at.gt(cursor.created_at))
+            .add(created_at.eq(cursor.created_at).and(id.gt(cursor.id))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeDelta;
+
+    fn now() -> NaiveDateTime {
+        chrono::DateTime::from_timestamp(1_734_000_000, 0).unwrap().naive_utc()
+    }
+
+    #[test]
+    fn test_resolve_status_from_publish_at() {
+        let later = now() + TimeDelta::hours(1);
+        let earlier = now() - TimeDelta::hours(1);
+        let draft = (PostStatus::Draft, None);
+
+        let scheduled = resolve_status(None, Some(later), None, draft, now()).unwrap();
+        assert_eq!(scheduled, (PostStatus::Scheduled, Some(later)));
+
+        let published = resolve_status(None, Some(earlier), None, draft, now()).unwrap();
+        assert_eq!(published, (PostStatus::Published, Some(earlier)));
+
+        let flagged = resolve_status(None, None, Some(true), draft, now()).unwrap();
+        assert_eq!(flagged, (PostStatus::Published, Some(now())));
+
+        let unchanged = resolve_status(None, None, None, scheduled, now()).unwrap();
+        assert_eq!(unchanged, scheduled);
+    }
+
+    #[test]
+    fn test_resolve_status_rejects_inconsistent_requests() {
+        let draft = (PostStatus::Draft, None);
+        let past = Some(now() - TimeDelta::minutes(1));
+
+        assert!(resolve_status(Some(PostStatus::Scheduled), None, None, draft, now()).is_err());
+        assert!(resolve_status(Some(PostStatus::Scheduled), past, None, draft, now()).is_err());
+        assert!(resolve_status(Some(PostStatus::Draft), past, None, draft, now()).is_err());
+
+        let future = Some(now() + TimeDelta::minutes(1));
+        assert!(resolve_status(Some(PostStatus::Published), future, None, draft, now()).is_err());
+        assert!(resolve_status(Some(PostStatus::Draft), None, Some(true), draft, now()).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_slug_unique_violation_is_a_conflict() {
+        let db = sea_orm::Database::connect("sqlite::memory:").await.unwrap();
+        db.execute_unprepared("CREATE TABLE posts (slug TEXT NOT NULL UNIQUE)").await.unwrap();
+        db.execute_unprepared("INSERT INTO posts (slug) VALUES ('mon-post')").await.unwrap();
+
+        let err = db
+            .execute_unprepared("INSERT INTO posts (slug) VALUES ('mon-post')")
+            .await
+            .unwrap_err();
+
+        assert!(matches!(slug_conflict(err), ServiceError::Conflict(_)));
+    }
+
+    #[test]
+    fn test_archiving_keeps_publication_date() {
+        let published = (PostStatus::Published, Some(now() - TimeDelta::days(3)));
+
+        let archived =
+            resolve_status(Some(PostStatus::Archived), None, None, published, now()).unwrap();
+        assert_eq!(archived, (PostStatus::Archived, published.1));
+
+        let draft = resolve_status(None, None, Some(false), published, now()).unwrap();
+        assert_eq!(draft, (PostStatus::Draft, None));
     }
 }
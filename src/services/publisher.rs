@@ -0,0 +1,82 @@
+// src/services/publisher.rs
+// Tâche de fond : publication des posts programmés (comme une commande cron / Messenger en Symfony)
+
+use std::time::Duration;
+
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryTrait};
+use tokio::task::JoinHandle;
+use tracing::{error, info};
+
+use crate::entities::post::{self, PostStatus};
+use crate::error::ServiceError;
+use crate::metrics;
+use crate::services::SharedClock;
+use crate::shutdown::Shutdown;
+
+/// ScheduledPublisher - Passe en "published" les posts programmés arrivés à échéance
+#[derive(Clone)]
+pub struct ScheduledPublisher {
+    db: DatabaseConnection,
+    clock: SharedClock,
+}
+
+impl ScheduledPublisher {
+    pub fn new(db: DatabaseConnection, clock: SharedClock) -> Self {
+        Self { db, clock }
+    }
+
+    /// Publie les posts dont `publish_at` est passé ; retourne les IDs publiés
+    pub async fn publish_due(&self) -> Result<Vec<i32>, ServiceError> {
+        let now = self.clock.now();
+
+        // Un seul UPDATE ... RETURNING: seuls les posts encore programmés au moment de
+        // l'écriture sont publiés et rapportés (un post déprogrammé entre-temps est ignoré)
+        let mut update = post::Entity::update_many()
+            .col_expr(post::Column::Status, Expr::value(PostStatus::Published))
+            .col_expr(post::Column::Published, Expr::value(true))
+            .col_expr(post::Column::UpdatedAt, Expr::value(now))
+            .col_expr(post::Column::Version, Expr::col(post::Column::Version).add(1))
+            .filter(post::Column::Status.eq(PostStatus::Scheduled))
+            .filter(post::Column::PublishAt.lte(now))
+            .into_query();
+        update.returning_col(post::Column::Id);
+
+        let backend = self.db.get_database_backend();
+        let published = self
+            .db
+            .query_all(backend.build(&update))
+            .await?
+            .iter()
+            .map(|row| row.try_get::<i32>("", "id"))
+            .collect::<Result<Vec<_>, _>>()?;
+        metrics::record_posts_published(published.len() as u64);
+
+        for post_id in &published {
+            info!(post_id, "Scheduled post published");
+        }
+
+        Ok(published)
+    }
+
+    /// Lance la boucle de publication en tâche tokio (un passage toutes les `interval`)
+    /// La tâche se termine au déclenchement de `shutdown`, sans interrompre un passage en cours
+    pub fn spawn(self, interval: Duration, shutdown: Shutdown) -> JoinHandle<()> {
+        info!(interval_seconds = interval.as_secs_f64(), "Scheduled publisher started");
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                tokio::select! {
+                    biased;
+                    _ = shutdown.wait() => break,
+                    _ = ticker.tick() => {}
+                }
+                if let Err(err) = self.publish_due().await {
+                    error!(error = %err, "Scheduled publishing failed");
+                }
+            }
+            info!("Scheduled publisher stopped");
+        })
+    }
+}
@@ -0,0 +1,149 @@
+// src/services/refresh_token_service.rs
+// Equivalent de: gesdinet/jwt-refresh-token-bundle (avec rotation) en Symfony
+
+use chrono::{Duration, Utc};
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ActiveValue::NotSet, ColumnTrait, DatabaseConnection,
+    EntityTrait, QueryFilter, Set,
+};
+use tracing::{info, warn};
+
+use crate::auth::{generate_secret, hash_secret};
+use crate::entities::{refresh_token, user};
+use crate::error::ServiceError;
+
+/// Préfixe des refresh tokens ("rust refresh token")
+const TOKEN_PREFIX: &str = "rrt_";
+
+/// RefreshTokenService - Émission, rotation et révocation des refresh tokens
+///
+/// Chaque login ouvre une "famille". Un refresh échange le token présenté contre
+/// un nouveau de la même famille ; présenter à nouveau un token déjà échangé
+/// signifie qu'il a fuité : toute la famille est alors révoquée.
+#[derive(Clone)]
+pub struct RefreshTokenService {
+    db: DatabaseConnection,
+    ttl: Duration,
+}
+
+impl RefreshTokenService {
+    /// Create a new RefreshTokenService instance
+    pub fn new(db: DatabaseConnection, ttl_seconds: i64) -> Self {
+        Self {
+            db,
+            ttl: Duration::seconds(ttl_seconds),
+        }
+    }
+
+    /// Ouvre une nouvelle session (famille) et retourne son premier refresh token
+    pub async fn issue(&self, user: &user::Model) -> Result<String, ServiceError> {
+        info!(user_id = user.id, "Issuing refresh token");
+        self.insert(user.id, generate_secret("")).await
+    }
+
+    /// Échange un refresh token contre un nouveau (rotation)
+    /// Retourne l'utilisateur et le nouveau refresh token
+    pub async fn rotate(&self, token: &str) -> Result<(user::Model, String), ServiceError> {
+        let now = Utc::now().naive_utc();
+
+        let (current, owner) = refresh_token::Entity::find()
+            .filter(refresh_token::Column::TokenHash.eq(hash_secret(token)))
+            .find_also_related(user::Entity)
+            .one(&self.db)
+            .await?
+            .and_then(|(current, owner)| Some((current, owner?)))
+            .ok_or_else(|| {
+                warn!("Unknown refresh token");
+                ServiceError::InvalidCredentials
+            })?;
+
+        if current.revoked_at.is_some() || current.expires_at <= now {
+            warn!(refresh_token_id = current.id, "Revoked or expired refresh token");
+            return Err(ServiceError::InvalidCredentials);
+        }
+
+        // Marque le token comme échangé, seulement s'il ne l'a pas déjà été :
+        // deux refresh concurrents avec le même token ne peuvent pas réussir tous les deux
+        let claimed = refresh_token::Entity::update_many()
+            .col_expr(refresh_token::Column::RotatedAt, Expr::value(now))
+            .filter(refresh_token::Column::Id.eq(current.id))
+            .filter(refresh_token::Column::RotatedAt.is_null())
+            .exec(&self.db)
+            .await?;
+
+        if claimed.rows_affected == 0 {
+            warn!(
+                user_id = owner.id,
+                family_id = %current.family_id,
+                "Refresh token reuse detected, revoking the whole family"
+            );
+            self.revoke_family(&current.family_id).await?;
+            return Err(ServiceError::InvalidCredentials);
+        }
+
+        let next = self.insert(owner.id, current.family_id).await?;
+
+        info!(user_id = owner.id, "Refresh token rotated");
+        Ok((owner, next))
+    }
+
+    /// Déconnexion : révoque la session (famille) du token présenté
+    /// Un token inconnu est ignoré (rien à révoquer)
+    pub async fn revoke(&self, token: &str) -> Result<(), ServiceError> {
+        let current = refresh_token::Entity::find()
+            .filter(refresh_token::Column::TokenHash.eq(hash_secret(token)))
+            .one(&self.db)
+            .await?;
+
+        if let Some(current) = current {
+            info!(user_id = current.user_id, "Logging out session");
+            self.revoke_family(&current.family_id).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Déconnexion de toutes les sessions d'un utilisateur
+    pub async fn revoke_all(&self, user_id: i32) -> Result<u64, ServiceError> {
+        let result = refresh_token::Entity::update_many()
+            .col_expr(refresh_token::Column::RevokedAt, Expr::value(Utc::now().naive_utc()))
+            .filter(refresh_token::Column::UserId.eq(user_id))
+            .filter(refresh_token::Column::RevokedAt.is_null())
+            .exec(&self.db)
+            .await?;
+
+        info!(user_id = user_id, revoked = result.rows_affected, "All sessions revoked");
+        Ok(result.rows_affected)
+    }
+
+    async fn revoke_family(&self, family_id: &str) -> Result<(), ServiceError> {
+        refresh_token::Entity::update_many()
+            .col_expr(refresh_token::Column::RevokedAt, Expr::value(Utc::now().naive_utc()))
+            .filter(refresh_token::Column::FamilyId.eq(family_id))
+            .filter(refresh_token::Column::RevokedAt.is_null())
+            .exec(&self.db)
+            .await?;
+        Ok(())
+    }
+
+    /// Insère un nouveau token dans la famille et retourne sa valeur en clair
+    async fn insert(&self, user_id: i32, family_id: String) -> Result<String, ServiceError> {
+        let token = generate_secret(TOKEN_PREFIX);
+        let now = Utc::now().naive_utc();
+
+        refresh_token::ActiveModel {
+            id: NotSet,
+            user_id: Set(user_id),
+            family_id: Set(family_id),
+            token_hash: Set(hash_secret(&token)),
+            expires_at: Set(now + self.ttl),
+            rotated_at: Set(None),
+            revoked_at: Set(None),
+            created_at: Set(now),
+        }
+        .insert(&self.db)
+        .await?;
+
+        Ok(token)
+    }
+}
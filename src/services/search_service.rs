@@ -0,0 +1,188 @@
+// src/services/search_service.rs
+// Recherche plein texte des posts (index SQLite FTS5 posts_fts)
+
+use std::collections::HashMap;
+
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, DbBackend, EntityTrait, FromQueryResult, QueryFilter,
+    Statement,
+};
+use tracing::info;
+
+use crate::dto::PaginationQuery;
+use crate::entities::{post, user};
+use crate::error::ServiceError;
+use crate::i18n::Message;
+use crate::services::post_service::with_authors;
+use crate::services::PostWithAuthor;
+
+/// Poids BM25 des colonnes de posts_fts: title, content, tags, keywords
+const RANK: &str = "bm25(posts_fts, 10.0, 1.0, 5.0, 3.0)";
+
+/// Délimiteurs des termes trouvés dans snippet() (zone à usage privé Unicode):
+/// remplacés par `<mark>` / `</mark>` une fois le texte du post échappé
+const MARK_START: char = '\u{E000}';
+const MARK_END: char = '\u{E001}';
+
+/// Posts correspondant à la recherche, sans les posts orphelins (même exclusion que
+/// `fetch_page`): le total compte exactement les lignes paginées
+const MATCHING_POSTS: &str = "posts_fts JOIN posts ON posts.id = posts_fts.rowid \
+     WHERE posts_fts MATCH ? AND posts.author_id IN (SELECT id FROM users)";
+
+/// Post trouvé, avec sa pertinence et un extrait surligné
+pub struct SearchHit {
+    pub post: PostWithAuthor,
+    pub score: f64,
+    pub snippet: String,
+}
+
+/// Résultat paginé d'une recherche
+pub struct PaginatedSearchHits {
+    pub hits: Vec<SearchHit>,
+    pub total: u64,
+}
+
+/// Ligne brute de l'index FTS5
+#[derive(FromQueryResult)]
+struct RankedRow {
+    id: i32,
+    rank: f64,
+    snippet: String,
+}
+
+#[derive(FromQueryResult)]
+struct CountRow {
+    total: i64,
+}
+
+/// SearchService - Recherche plein texte des posts
+#[derive(Clone)]
+pub struct SearchService {
+    db: DatabaseConnection,
+}
+
+impl SearchService {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Recherche paginée, les plus pertinents d'abord
+    pub async fn search_posts(
+        &self,
+        q: &str,
+        pagination: &PaginationQuery,
+    ) -> Result<PaginatedSearchHits, ServiceError> {
+        info!(q, page = pagination.page, per_page = pagination.per_page, "Searching posts");
+
+        let expression = match_expression(q)
+            .ok_or_else(|| ServiceError::InvalidInput(Message::new("search.q.no_word")))?;
+
+        let total = CountRow::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Sqlite,
+            format!("SELECT count(*) AS total FROM {MATCHING_POSTS}"),
+            [expression.clone().into()],
+        ))
+        .one(&self.db)
+        .await?
+        .map_or(0, |row| row.total as u64);
+
+        let ranked = RankedRow::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Sqlite,
+            format!(
+                "SELECT posts_fts.rowid AS id, {RANK} AS rank, \
+                 snippet(posts_fts, -1, '{MARK_START}', '{MARK_END}', '…', 16) AS snippet \
+                 FROM {MATCHING_POSTS} \
+                 ORDER BY rank, id DESC LIMIT ? OFFSET ?"
+            ),
+            [
+                expression.into(),
+                pagination.limit().into(),
+                pagination.offset().into(),
+            ],
+        ))
+        .all(&self.db)
+        .await?;
+
+        // Posts et auteurs de la page en une requête, remis dans l'ordre de pertinence
+        let ids: Vec<i32> = ranked.iter().map(|row| row.id).collect();
+        let rows = post::Entity::find()
+            .filter(post::Column::Id.is_in(ids))
+            .find_also_related(user::Entity)
+            .all(&self.db)
+            .await?;
+        let mut posts: HashMap<i32, PostWithAuthor> = with_authors(rows)
+            .into_iter()
+            .map(|pwa| (pwa.post.id, pwa))
+            .collect();
+
+        let hits: Vec<SearchHit> = ranked
+            .into_iter()
+            .filter_map(|row| {
+                posts.remove(&row.id).map(|post| SearchHit {
+                    post,
+                    score: -row.rank,
+                    snippet: highlight(&row.snippet),
+                })
+            })
+            .collect();
+
+        info!(count = hits.len(), total, "Search done");
+
+        Ok(PaginatedSearchHits { hits, total })
+    }
+}
+
+/// Echappe le HTML de l'extrait (contenu saisi par les auteurs) puis surligne les termes
+fn highlight(snippet: &str) -> String {
+    let mut html = String::with_capacity(snippet.len());
+    for c in snippet.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#x27;"),
+            MARK_START => html.push_str("<mark>"),
+            MARK_END => html.push_str("</mark>"),
+            c => html.push(c),
+        }
+    }
+    html
+}
+
+/// Transforme la saisie utilisateur en requête FTS5 sûre:
+/// chaque mot devient un terme préfixe entre guillemets ("rust"* "api"*), tous requis
+fn match_expression(q: &str) -> Option<String> {
+    let terms: Vec<String> = q
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| format!("\"{word}\"*"))
+        .collect();
+
+    (!terms.is_empty()).then(|| terms.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_match_expression_quotes_terms() {
+        assert_eq!(match_expression("rust api").as_deref(), Some("\"rust\"* \"api\"*"));
+        assert_eq!(
+            match_expression("title:\"x\" OR NEAR(").as_deref(),
+            Some("\"title\"* \"x\"* \"OR\"* \"NEAR\"*")
+        );
+        assert_eq!(match_expression("  \"*- ").as_deref(), None);
+    }
+
+    #[test]
+    fn test_highlight_escapes_content() {
+        let snippet = format!("<script>alert('x')</script> & {MARK_START}rust{MARK_END}");
+
+        assert_eq!(
+            highlight(&snippet),
+            "&lt;script&gt;alert(&#x27;x&#x27;)&lt;/script&gt; &amp; <mark>rust</mark>"
+        );
+    }
+}
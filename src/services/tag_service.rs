@@ -0,0 +1,122 @@
+// src/services/tag_service.rs
+// Service pour les tags normalisés (tables tags / post_tags)
+
+use std::collections::BTreeMap;
+
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, JoinType,
+    Order, QueryFilter, QueryOrder, QuerySelect, RelationTrait, Set,
+};
+use tracing::info;
+
+use crate::entities::{post, post_tag, tag};
+use crate::error::ServiceError;
+
+/// Tag avec le nombre de posts qui le portent
+pub struct TagWithCount {
+    pub name: String,
+    pub color: Option<String>,
+    pub post_count: u64,
+}
+
+/// TagService - Lecture des tags et de leurs compteurs
+#[derive(Clone)]
+pub struct TagService {
+    db: DatabaseConnection,
+}
+
+impl TagService {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Tags utilisés par au moins un post, les plus fréquents d'abord
+    pub async fn find_all(&self) -> Result<Vec<TagWithCount>, ServiceError> {
+        info!("Fetching tags");
+
+        let post_count = post_tag::Column::PostId.count();
+        let tags: Vec<(String, Option<String>, i64)> = tag::Entity::find()
+            .select_only()
+            .column(tag::Column::Name)
+            .column(tag::Column::Color)
+            .column_as(post_count.clone(), "post_count")
+            .join(JoinType::InnerJoin, tag::Relation::PostTag.def())
+            .group_by(tag::Column::Id)
+            .order_by(post_count, Order::Desc)
+            .order_by_asc(tag::Column::Name)
+            .into_tuple()
+            .all(&self.db)
+            .await?;
+
+        Ok(tags
+            .into_iter()
+            .map(|(name, color, count)| TagWithCount {
+                name,
+                color,
+                post_count: count as u64,
+            })
+            .collect())
+    }
+}
+
+/// Synchronise post_tags avec les tags du metadata JSON d'un post
+/// Les tags inconnus sont créés ; la couleur n'est renseignée que si le tag n'en a pas encore
+/// À appeler dans la même transaction que l'écriture du post
+pub(crate) async fn sync_post_tags<C: ConnectionTrait>(
+    db: &C,
+    post_id: i32,
+    tags: &[post::Tag],
+) -> Result<(), ServiceError> {
+    post_tag::Entity::delete_many()
+        .filter(post_tag::Column::PostId.eq(post_id))
+        .exec(db)
+        .await?;
+
+    // Un même nom peut apparaître deux fois dans le JSON: on garde la première couleur
+    let mut wanted: BTreeMap<&str, Option<&str>> = BTreeMap::new();
+    for t in tags {
+        let color = wanted.entry(t.name.as_str()).or_default();
+        if color.is_none() {
+            *color = t.color.as_deref();
+        }
+    }
+
+    let mut links = Vec::with_capacity(wanted.len());
+    for (name, color) in wanted {
+        tag::Entity::insert(tag::ActiveModel {
+            name: Set(name.to_string()),
+            color: Set(color.map(str::to_string)),
+            ..Default::default()
+        })
+        .on_conflict(OnConflict::column(tag::Column::Name).do_nothing().to_owned())
+        .exec_without_returning(db)
+        .await?;
+
+        let existing = tag::Entity::find()
+            .filter(tag::Column::Name.eq(name))
+            .one(db)
+            .await?
+            .ok_or_else(|| ServiceError::Internal(format!("Tag '{name}' vanished")))?;
+        links.push(existing.id);
+
+        if existing.color.is_none()
+            && let Some(color) = color
+        {
+            let mut active: tag::ActiveModel = existing.into();
+            active.color = Set(Some(color.to_string()));
+            active.update(db).await?;
+        }
+    }
+
+    if !links.is_empty() {
+        post_tag::Entity::insert_many(links.into_iter().map(|tag_id| post_tag::ActiveModel {
+            post_id: Set(post_id),
+            tag_id: Set(tag_id),
+        }))
+        .exec_without_returning(db)
+        .await?;
+    }
+
+    Ok(())
+}
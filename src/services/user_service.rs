@@ -8,9 +8,15 @@ use sea_orm::{
 };
 use tracing::{info, warn};
 
-use crate::dto::{CreateUserDto, PaginationQuery, UpdateUserDto};
-use crate::entities::user;
+use crate::auth::{ensure, hash_password, verify_password, Permission};
+use crate::dto::{
+    stale_version, version_conflict, CreateUserDto, IfMatch, PaginationQuery, UpdateUserDto,
+    UserFilterQuery,
+};
+use crate::entities::user::{self, Role};
 use crate::error::ServiceError;
+use crate::i18n::Message;
+use crate::metrics;
 
 /// Paginated result - returns entities, not DTOs
 /// Transformation to DTO is done in the controller
@@ -33,18 +39,24 @@ impl UserService {
         Self { db }
     }
 
-    /// Find all users with pagination
-    pub async fn find_all(&self, pagination: &PaginationQuery) -> Result<PaginatedUsers, ServiceError> {
-        info!(page = pagination.page, per_page = pagination.per_page, "Fetching users");
+    /// Find all users with pagination, filters and sorting
+    pub async fn find_all(
+        &self,
+        pagination: &PaginationQuery,
+        filter: &UserFilterQuery,
+    ) -> Result<PaginatedUsers, ServiceError> {
+        info!(page = pagination.page, per_page = pagination.per_page, ?filter, "Fetching users");
+
+        let query = user::Entity::find().filter(filter.condition());
 
         // Get total count
-        let total = user::Entity::find()
-            .count(&self.db)
-            .await?;
+        let total = query.clone().count(&self.db).await?;
 
         // Get paginated users
-        let users = user::Entity::find()
-            .order_by_asc(user::Column::Id)
+        let users = filter
+            .order_by()
+            .into_iter()
+            .fold(query, |query, (column, order)| query.order_by(column, order))
             .offset(pagination.offset())
             .limit(pagination.limit())
             .all(&self.db)
@@ -76,6 +88,16 @@ impl UserService {
         Ok(user)
     }
 
+    /// Find a user the actor may update (the user themselves or an admin)
+    pub async fn editable_user(
+        &self,
+        id: i32,
+        actor: &user::Model,
+    ) -> Result<user::Model, ServiceError> {
+        ensure(actor.owns_or_can(id, Permission::ManageUsers), "update_user")?;
+        self.find_by_id(id).await
+    }
+
     /// Create a new user
     pub async fn create(&self, dto: CreateUserDto) -> Result<user::Model, ServiceError> {
         info!(username = %dto.username, email = %dto.email, "Creating new user");
@@ -88,25 +110,89 @@ impl UserService {
 
         if existing.is_some() {
             warn!(email = %dto.email, "Email already exists");
-            return Err(ServiceError::AlreadyExists("Email already exists".to_string()));
+            return Err(ServiceError::AlreadyExists(Message::new("user.email.taken")));
         }
 
+        let password_hash = hash_password(&dto.password)
+            .map_err(|err| ServiceError::Internal(format!("Password hashing failed: {err}")))?;
+
         let new_user = user::ActiveModel {
             id: NotSet,
             username: Set(dto.username),
             email: Set(dto.email),
+            password_hash: Set(password_hash),
+            role: Set(Role::default()),
             created_at: Set(chrono::Utc::now().naive_utc()),
+            version: Set(1),
         };
 
         let user = new_user.insert(&self.db).await?;
+        metrics::record_user_created();
 
         info!(user_id = user.id, username = %user.username, "User created successfully");
         Ok(user)
     }
 
-    /// Update an existing user
-    pub async fn update(&self, id: i32, dto: UpdateUserDto) -> Result<user::Model, ServiceError> {
-        info!(user_id = id, "Updating user");
+    /// Check email/password credentials
+    /// Equivalent de: json_login authenticator en Symfony
+    pub async fn authenticate(&self, email: &str, password: &str) -> Result<user::Model, ServiceError> {
+        info!(email = %email, "Authenticating user");
+
+        let user = user::Entity::find()
+            .filter(user::Column::Email.eq(email))
+            .one(&self.db)
+            .await?;
+
+        match user {
+            Some(user) if verify_password(password, &user.password_hash) => {
+                info!(user_id = user.id, "User authenticated");
+                Ok(user)
+            }
+            _ => {
+                warn!(email = %email, "Invalid credentials");
+                Err(ServiceError::InvalidCredentials)
+            }
+        }
+    }
+
+    /// Change a user's password after checking the current one
+    pub async fn change_password(
+        &self,
+        user: user::Model,
+        current_password: &str,
+        new_password: &str,
+    ) -> Result<(), ServiceError> {
+        info!(user_id = user.id, "Changing password");
+
+        if !verify_password(current_password, &user.password_hash) {
+            warn!(user_id = user.id, "Current password mismatch");
+            return Err(ServiceError::InvalidCredentials);
+        }
+
+        let password_hash = hash_password(new_password)
+            .map_err(|err| ServiceError::Internal(format!("Password hashing failed: {err}")))?;
+
+        let user_id = user.id;
+        let mut active_model: user::ActiveModel = user.into();
+        active_model.password_hash = Set(password_hash);
+        active_model.update(&self.db).await?;
+
+        info!(user_id = user_id, "Password changed successfully");
+        Ok(())
+    }
+
+    /// Update an existing user (the user themselves or an admin)
+    /// `if_match`: version read by the client (412 if the user changed since)
+    pub async fn update(
+        &self,
+        id: i32,
+        dto: UpdateUserDto,
+        actor: &user::Model,
+        if_match: &IfMatch,
+    ) -> Result<user::Model, ServiceError> {
+        info!(user_id = id, actor_id = actor.id, "Updating user");
+
+        ensure(actor.owns_or_can(id, Permission::ManageUsers), "update_user")?;
 
         // Find existing user
         let user = user::Entity::find_by_id(id)
@@ -116,22 +202,24 @@ impl UserService {
                 warn!(user_id = id, "User not found for update");
                 ServiceError::NotFound
             })?;
+        if_match.ensure(user.version)?;
 
         // Check email uniqueness if changing
-        if let Some(ref new_email) = dto.email {
-            if new_email != &user.email {
-                let existing = user::Entity::find()
-                    .filter(user::Column::Email.eq(new_email))
-                    .one(&self.db)
-                    .await?;
-
-                if existing.is_some() {
-                    return Err(ServiceError::AlreadyExists("Email already exists".to_string()));
-                }
+        if let Some(ref new_email) = dto.email
+            && new_email != &user.email
+        {
+            let existing = user::Entity::find()
+                .filter(user::Column::Email.eq(new_email))
+                .one(&self.db)
+                .await?;
+
+            if existing.is_some() {
+                return Err(ServiceError::AlreadyExists(Message::new("user.email.taken")));
             }
         }
 
         // Build update model
+        let read_version = user.version;
         let mut active_model: user::ActiveModel = user.into();
 
         if let Some(username) = dto.username {
@@ -141,26 +229,79 @@ impl UserService {
             active_model.email = Set(email);
         }
 
-        let updated_user = active_model.update(&self.db).await?;
+        let updated_user = update_versioned(&self.db, active_model, read_version).await?;
 
         info!(user_id = id, "User updated successfully");
         Ok(updated_user)
     }
 
-    /// Delete a user
-    pub async fn delete(&self, id: i32) -> Result<(), ServiceError> {
-        info!(user_id = id, "Deleting user");
+    /// Change a user's role (admin only)
+    pub async fn change_role(
+        &self,
+        id: i32,
+        role: Role,
+        actor: &user::Model,
+        if_match: &IfMatch,
+    ) -> Result<user::Model, ServiceError> {
+        info!(user_id = id, actor_id = actor.id, role = ?role, "Changing user role");
+
+        ensure(actor.can(Permission::ManageUsers), "change_user_roles")?;
+
+        let user = self.find_by_id(id).await?;
+        if_match.ensure(user.version)?;
+
+        let read_version = user.version;
+        let mut active_model: user::ActiveModel = user.into();
+        active_model.role = Set(role);
+        let updated_user = update_versioned(&self.db, active_model, read_version).await?;
+
+        info!(user_id = id, role = ?role, "User role changed successfully");
+        Ok(updated_user)
+    }
+
+    /// Delete a user (admin only)
+    pub async fn delete(
+        &self,
+        id: i32,
+        actor: &user::Model,
+        if_match: &IfMatch,
+    ) -> Result<(), ServiceError> {
+        info!(user_id = id, actor_id = actor.id, "Deleting user");
+
+        ensure(actor.can(Permission::ManageUsers), "delete_users")?;
 
-        let result = user::Entity::delete_by_id(id)
+        let user = self.find_by_id(id).await.inspect_err(|_| {
+            warn!(user_id = id, "User not found for deletion");
+        })?;
+        if_match.ensure(user.version)?;
+
+        // Conditional delete: a concurrent update wins (412)
+        let result = user::Entity::delete_many()
+            .filter(user::Column::Id.eq(id))
+            .filter(user::Column::Version.eq(user.version))
             .exec(&self.db)
             .await?;
 
         if result.rows_affected == 0 {
-            warn!(user_id = id, "User not found for deletion");
-            return Err(ServiceError::NotFound);
+            return Err(stale_version());
         }
 
         info!(user_id = id, "User deleted successfully");
         Ok(())
     }
 }
+
+/// Write a user only if it is still at the version that was read (`WHERE version = ?`)
+/// and bump its version; 412 if another client changed it in between
+async fn update_versioned(
+    db: &DatabaseConnection,
+    mut active_model: user::ActiveModel,
+    read_version: i32,
+) -> Result<user::Model, ServiceError> {
+    active_model.version = Set(read_version + 1);
+    user::Entity::update(active_model)
+        .filter(user::Column::Version.eq(read_version))
+        .exec(db)
+        .await
+        .map_err(version_conflict)
+}
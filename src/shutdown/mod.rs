@@ -0,0 +1,105 @@
+// src/shutdown/mod.rs
+// Arrêt gracieux (comme les signaux SIGTERM gérés par messenger:consume en Symfony)
+// Un même signal arrête le serveur HTTP, les tâches de fond et la sonde de readiness
+
+use std::sync::Arc;
+
+use tokio::sync::watch;
+use tracing::info;
+
+/// Signal d'arrêt partagé (clonable)
+/// Une fois déclenché, il le reste: les tâches qui l'attendent ensuite terminent aussitôt
+#[derive(Clone)]
+pub struct Shutdown {
+    sender: Arc<watch::Sender<bool>>,
+}
+
+impl Shutdown {
+    pub fn new() -> Self {
+        Self {
+            sender: Arc::new(watch::Sender::new(false)),
+        }
+    }
+
+    /// Déclenche l'arrêt
+    pub fn trigger(&self) {
+        self.sender.send_replace(true);
+    }
+
+    pub fn is_triggered(&self) -> bool {
+        *self.sender.borrow()
+    }
+
+    /// Attend le déclenchement de l'arrêt
+    pub async fn wait(&self) {
+        let mut receiver = self.sender.subscribe();
+        // Le sender vit aussi longtemps que `self`: wait_for ne peut pas échouer
+        let _ = receiver.wait_for(|triggered| *triggered).await;
+    }
+}
+
+impl Default for Shutdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Attend SIGINT (Ctrl+C) ou SIGTERM (docker stop, Kubernetes), puis déclenche `shutdown`
+pub async fn trigger_on_signal(shutdown: Shutdown) {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("Failed to listen for SIGINT");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("Failed to listen for SIGTERM")
+            .recv()
+            .await;
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => info!(signal = "SIGINT", "Shutdown signal received"),
+        _ = terminate => info!(signal = "SIGTERM", "Shutdown signal received"),
+    }
+
+    shutdown.trigger();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_trigger_wakes_waiting_tasks() {
+        let shutdown = Shutdown::new();
+        let waiter = tokio::spawn({
+            let shutdown = shutdown.clone();
+            async move { shutdown.wait().await }
+        });
+        assert!(!shutdown.is_triggered());
+
+        shutdown.trigger();
+
+        tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .expect("waiter should be woken")
+            .unwrap();
+        assert!(shutdown.is_triggered());
+    }
+
+    #[tokio::test]
+    async fn test_wait_returns_once_triggered() {
+        let shutdown = Shutdown::new();
+        shutdown.trigger();
+
+        tokio::time::timeout(Duration::from_secs(1), shutdown.clone().wait())
+            .await
+            .expect("already triggered");
+    }
+}
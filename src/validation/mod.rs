@@ -1,14 +1,26 @@
+mod patch;
+mod rules;
+
+pub use patch::{JsonPatch, JSON_PATCH_CONTENT_TYPE};
+pub use rules::{validate_password_strength, validate_slug};
+
 use axum::{
     async_trait,
-    extract::{rejection::JsonRejection, FromRequest, Request},
+    extract::{
+        rejection::{JsonRejection, PathRejection, QueryRejection},
+        FromRequest, FromRequestParts, Path, Query, Request,
+    },
+    http::request::Parts,
     http::StatusCode,
     response::{IntoResponse, Response},
     Json,
 };
 use serde::de::DeserializeOwned;
-use serde_json::json;
 use thiserror::Error;
-use validator::Validate;
+use validator::{Validate, ValidationErrors, ValidationErrorsKind};
+
+use crate::error::{ErrorCode, ProblemDetails, Violation};
+use crate::i18n::Message;
 
 // === Erreurs de validation (comme ConstraintViolationList en Symfony) ===
 
@@ -19,46 +31,111 @@ pub enum ValidationError {
 
     #[error("Invalid JSON")]
     JsonError(#[from] JsonRejection),
+
+    #[error("Invalid query string")]
+    QueryError(#[from] QueryRejection),
+
+    /// Paramètre de route mal typé (ex: /posts/abc)
+    #[error("Invalid path parameter")]
+    PathError(#[from] PathRejection),
+
+    /// Opérations JSON Patch illisibles
+    #[error("Invalid patch: {0}")]
+    InvalidPatch(String),
+
+    /// Patch inapplicable au document (test échoué, champ inconnu ou mal typé)
+    #[error("Patch failed: {0}")]
+    PatchFailed(String),
 }
 
 impl IntoResponse for ValidationError {
     fn into_response(self) -> Response {
-        let (status, errors) = match self {
+        let (status, code, violations) = match self {
             // Erreurs de validation (comme les violations Symfony)
+            // Le `code` de chaque contrainte est la clé de son message traduit
             ValidationError::ValidationFailed(validation_errors) => {
-                let errors: Vec<_> = validation_errors
-                    .field_errors()
-                    .iter()
-                    .map(|(field, errors)| {
-                        let messages: Vec<_> = errors
-                            .iter()
-                            .filter_map(|e| e.message.as_ref().map(|m| m.to_string()))
-                            .collect();
-                        json!({
-                            "field": field,
-                            "messages": messages
-                        })
-                    })
-                    .collect();
-
-                (StatusCode::UNPROCESSABLE_ENTITY, errors)
+                let mut violations = Vec::new();
+                collect_violations(&validation_errors, None, &mut violations);
+                violations.sort_by(|a, b| a.field.cmp(&b.field));
+
+                (
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    ErrorCode::ValidationFailed,
+                    violations,
+                )
             }
             // Erreurs JSON (malformed JSON)
             ValidationError::JsonError(err) => {
-                let error = json!({
-                    "field": "_json",
-                    "messages": [err.to_string()]
-                });
-                (StatusCode::BAD_REQUEST, vec![error])
+                let message = Message::new("request.invalid_json").param("reason", err);
+                (
+                    StatusCode::BAD_REQUEST,
+                    ErrorCode::InvalidJson,
+                    vec![Violation::body("_json", message)],
+                )
+            }
+            // Erreurs de query string (type invalide, ex: ?published=maybe)
+            ValidationError::QueryError(err) => {
+                let message =
+                    Message::new("request.invalid_query").param("reason", err.body_text());
+                (
+                    StatusCode::BAD_REQUEST,
+                    ErrorCode::InvalidQuery,
+                    vec![Violation::body("_query", message)],
+                )
+            }
+            ValidationError::PathError(err) => {
+                let message =
+                    Message::new("request.invalid_path").param("reason", err.body_text());
+                (
+                    StatusCode::BAD_REQUEST,
+                    ErrorCode::InvalidPath,
+                    vec![Violation::body("_path", message)],
+                )
+            }
+            ValidationError::InvalidPatch(reason) => {
+                let message = Message::new("request.invalid_patch").param("reason", reason);
+                (
+                    StatusCode::BAD_REQUEST,
+                    ErrorCode::InvalidPatch,
+                    vec![Violation::body("_patch", message)],
+                )
+            }
+            ValidationError::PatchFailed(reason) => {
+                let message = Message::new("request.patch_failed").param("reason", reason);
+                (
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    ErrorCode::PatchFailed,
+                    vec![Violation::body("_patch", message)],
+                )
             }
         };
 
-        let body = json!({
-            "error": "Validation failed",
-            "violations": errors
-        });
+        ProblemDetails::new(status, code)
+            .violations(violations)
+            .into_response()
+    }
+}
 
-        (status, Json(body)).into_response()
+/// Violations de tous les niveaux, avec le chemin du champ (`metadata.tags[0].name`)
+/// comme le propertyPath des violations Symfony
+fn collect_violations(errors: &ValidationErrors, prefix: Option<&str>, out: &mut Vec<Violation>) {
+    for (field, kind) in errors.errors() {
+        let path = match prefix {
+            Some(prefix) => format!("{prefix}.{field}"),
+            None => field.to_string(),
+        };
+        match kind {
+            ValidationErrorsKind::Field(errors) => out.push(Violation {
+                field: path,
+                messages: errors.iter().map(Message::from).collect(),
+            }),
+            ValidationErrorsKind::Struct(nested) => collect_violations(nested, Some(&path), out),
+            ValidationErrorsKind::List(items) => {
+                for (index, nested) in items {
+                    collect_violations(nested, Some(&format!("{path}[{index}]")), out);
+                }
+            }
+        }
     }
 }
 
@@ -87,3 +164,42 @@ where
         Ok(ValidatedJson(data))
     }
 }
+
+// === ValidatedQuery: même chose pour la query string ===
+// Equivalent de: #[MapQueryString] avec validation en Symfony
+
+pub struct ValidatedQuery<T>(pub T);
+
+#[async_trait]
+impl<S, T> FromRequestParts<S> for ValidatedQuery<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned + Validate,
+{
+    type Rejection = ValidationError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Query(data) = Query::<T>::from_request_parts(parts, state).await?;
+        data.validate()?;
+        Ok(ValidatedQuery(data))
+    }
+}
+
+// === ValidatedPath: paramètres de route, erreurs en problem+json ===
+// Equivalent de: les requirements de route Symfony (`{id<\d+>}`)
+
+pub struct ValidatedPath<T>(pub T);
+
+#[async_trait]
+impl<S, T> FromRequestParts<S> for ValidatedPath<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned + Send,
+{
+    type Rejection = ValidationError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Path(data) = Path::<T>::from_request_parts(parts, state).await?;
+        Ok(ValidatedPath(data))
+    }
+}
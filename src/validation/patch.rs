@@ -0,0 +1,171 @@
+// src/validation/patch.rs
+// PATCH: JSON Merge Patch (RFC 7396) et JSON Patch (RFC 6902)
+// Equivalent de: l'opération Patch d'API Platform (merge-patch+json)
+
+use axum::{
+    async_trait,
+    extract::{rejection::JsonRejection, FromRequest, Request},
+    http::header::CONTENT_TYPE,
+    Json,
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use validator::Validate;
+
+use super::ValidationError;
+
+/// Content-Type d'un JSON Patch (liste d'opérations)
+pub const JSON_PATCH_CONTENT_TYPE: &str = "application/json-patch+json";
+
+/// Corps d'une requête PATCH
+/// `application/merge-patch+json` (ou `application/json`): document fusionné, `null` supprime
+/// `application/json-patch+json`: opérations add / remove / replace / move / copy / test
+#[derive(Debug, Clone)]
+pub enum JsonPatch {
+    Merge(Value),
+    Operations(json_patch::Patch),
+}
+
+impl JsonPatch {
+    /// Applique le patch au document courant de la ressource, puis désérialise
+    /// et valide le résultat comme `T` (mêmes règles que le DTO du PUT)
+    ///
+    /// Le patch ne peut pas ajouter de champ absent du document, ni supprimer
+    /// un champ de `required`.
+    pub fn apply<T>(&self, mut document: Value, required: &[&str]) -> Result<T, ValidationError>
+    where
+        T: DeserializeOwned + Validate,
+    {
+        let fields: Vec<String> = document
+            .as_object()
+            .map(|object| object.keys().cloned().collect())
+            .unwrap_or_default();
+
+        match self {
+            JsonPatch::Merge(patch) => json_patch::merge(&mut document, patch),
+            JsonPatch::Operations(patch) => json_patch::patch(&mut document, patch)
+                .map_err(|err| ValidationError::PatchFailed(err.to_string()))?,
+        }
+
+        let Some(object) = document.as_object() else {
+            return Err(ValidationError::PatchFailed(
+                "The patched document must be an object".to_string(),
+            ));
+        };
+        if let Some(field) = object.keys().find(|field| !fields.contains(field)) {
+            return Err(ValidationError::PatchFailed(format!("Unknown field `{field}`")));
+        }
+        if let Some(field) = required
+            .iter()
+            .find(|field| object.get(**field).is_none_or(Value::is_null))
+        {
+            return Err(ValidationError::PatchFailed(format!("`{field}` cannot be removed")));
+        }
+
+        let data: T = serde_json::from_value(document)
+            .map_err(|err| ValidationError::PatchFailed(err.to_string()))?;
+        data.validate()?;
+
+        Ok(data)
+    }
+}
+
+#[async_trait]
+impl<S> FromRequest<S> for JsonPatch
+where
+    S: Send + Sync,
+    Json<Value>: FromRequest<S, Rejection = JsonRejection>,
+{
+    type Rejection = ValidationError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let operations = req
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with(JSON_PATCH_CONTENT_TYPE));
+
+        // Json accepte tous les types `application/*+json`
+        let Json(body) = Json::<Value>::from_request(req, state).await?;
+
+        if operations {
+            let patch = serde_json::from_value(body)
+                .map_err(|err| ValidationError::InvalidPatch(err.to_string()))?;
+            Ok(JsonPatch::Operations(patch))
+        } else {
+            Ok(JsonPatch::Merge(body))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, Validate)]
+    struct Document {
+        #[validate(length(min = 3))]
+        name: Option<String>,
+        note: Option<String>,
+    }
+
+    fn document() -> Value {
+        json!({ "name": "original", "note": "keep" })
+    }
+
+    #[test]
+    fn merge_patch_replaces_and_removes_fields() {
+        let patch = JsonPatch::Merge(json!({ "name": "renamed", "note": null }));
+
+        let result: Document = patch.apply(document(), &[]).unwrap();
+
+        assert_eq!(result.name.as_deref(), Some("renamed"));
+        assert_eq!(result.note, None);
+    }
+
+    #[test]
+    fn json_patch_applies_operations() {
+        let patch: json_patch::Patch =
+            serde_json::from_value(json!([{ "op": "replace", "path": "/note", "value": "new" }]))
+                .unwrap();
+
+        let result: Document = JsonPatch::Operations(patch).apply(document(), &[]).unwrap();
+
+        assert_eq!(result.name.as_deref(), Some("original"));
+        assert_eq!(result.note.as_deref(), Some("new"));
+    }
+
+    #[test]
+    fn patched_document_is_validated() {
+        let patch = JsonPatch::Merge(json!({ "name": "ab" }));
+
+        let result = patch.apply::<Document>(document(), &[]);
+
+        assert!(matches!(result, Err(ValidationError::ValidationFailed(_))));
+    }
+
+    #[test]
+    fn unknown_and_required_fields_are_rejected() {
+        let unknown = JsonPatch::Merge(json!({ "extra": 1 }));
+        let removed = JsonPatch::Merge(json!({ "name": null }));
+
+        let unknown = unknown.apply::<Document>(document(), &[]);
+        let removed = removed.apply::<Document>(document(), &["name"]);
+
+        assert!(matches!(unknown, Err(ValidationError::PatchFailed(_))));
+        assert!(matches!(removed, Err(ValidationError::PatchFailed(_))));
+    }
+
+    #[test]
+    fn failing_test_operation_is_rejected() {
+        let patch: json_patch::Patch =
+            serde_json::from_value(json!([{ "op": "test", "path": "/name", "value": "other" }]))
+                .unwrap();
+
+        let result = JsonPatch::Operations(patch).apply::<Document>(document(), &[]);
+
+        assert!(matches!(result, Err(ValidationError::PatchFailed(_))));
+    }
+}
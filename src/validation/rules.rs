@@ -0,0 +1,57 @@
+// src/validation/rules.rs
+// Contraintes personnalisées (comme les Constraint/ConstraintValidator Symfony)
+
+use validator::ValidationError;
+
+use crate::entities::post::{is_valid_slug, SLUG_MAX_LENGTH};
+
+/// Vérifie la robustesse d'un mot de passe
+/// Exige au moins une minuscule, une majuscule, un chiffre et un symbole
+/// (la longueur est vérifiée séparément via `length`)
+pub fn validate_password_strength(password: &str) -> Result<(), ValidationError> {
+    let has_lower = password.chars().any(|c| c.is_lowercase());
+    let has_upper = password.chars().any(|c| c.is_uppercase());
+    let has_digit = password.chars().any(|c| c.is_ascii_digit());
+    let has_symbol = password.chars().any(|c| !c.is_alphanumeric());
+
+    if has_lower && has_upper && has_digit && has_symbol {
+        Ok(())
+    } else {
+        Err(ValidationError::new("user.password.strength"))
+    }
+}
+
+/// Vérifie qu'un slug fourni par le client est déjà normalisé (ex: mon-premier-post)
+pub fn validate_slug(slug: &str) -> Result<(), ValidationError> {
+    if is_valid_slug(slug) {
+        Ok(())
+    } else {
+        let mut error = ValidationError::new("post.slug.format");
+        error.add_param("max".into(), &SLUG_MAX_LENGTH);
+        Err(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strong_password_is_accepted() {
+        assert!(validate_password_strength("S3cure-password").is_ok());
+    }
+
+    #[test]
+    fn test_weak_passwords_are_rejected() {
+        assert!(validate_password_strength("password").is_err());
+        assert!(validate_password_strength("Password1").is_err());
+        assert!(validate_password_strength("PASSWORD-1").is_err());
+        assert!(validate_password_strength("Password-").is_err());
+    }
+
+    #[test]
+    fn test_slug_must_be_normalized() {
+        assert!(validate_slug("mon-premier-post").is_ok());
+        assert!(validate_slug("Mon Premier Post").is_err());
+    }
+}
@@ -0,0 +1,255 @@
+// tests/api_key_controller_test.rs
+// Tests des clés d'API (création, usage via X-Api-Key, révocation)
+
+mod common;
+
+use axum::http::StatusCode;
+use axum_test::TestServer;
+use serde_json::{json, Value};
+
+/// Mint a key for the given token and return (key ID, secret)
+async fn mint_key(server: &TestServer, token: &str, scopes: Value) -> (i64, String) {
+    let response = server
+        .post("/auth/api-keys")
+        .authorization_bearer(token)
+        .json(&json!({ "name": "Nightly import", "scopes": scopes }))
+        .await;
+
+    response.assert_status(StatusCode::CREATED);
+    let body: Value = response.json();
+    (
+        body["data"]["api_key"]["id"].as_i64().unwrap(),
+        body["data"]["key"].as_str().unwrap().to_string(),
+    )
+}
+
+fn post_payload() -> Value {
+    json!({
+        "title": "Post importé",
+        "content": "Contenu du post avec au moins 10 caractères"
+    })
+}
+
+// ============================================================
+// POST/GET /auth/api-keys - Mint & list
+// ============================================================
+
+/// Test the secret is shown once and never listed afterwards
+#[tokio::test]
+async fn test_create_api_key_shows_secret_once() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "bot", "bot@test.com").await;
+
+    let (_, secret) = mint_key(&server, &token, json!(["posts:read"])).await;
+    assert!(secret.starts_with("rak_"));
+
+    let response = server.get("/auth/api-keys").authorization_bearer(&token).await;
+
+    response.assert_status(StatusCode::OK);
+    let body: Value = response.json();
+    let keys = body["data"].as_array().unwrap();
+    assert_eq!(keys.len(), 1);
+    assert_eq!(keys[0]["name"], "Nightly import");
+    assert_eq!(keys[0]["scopes"], json!(["posts:read"]));
+    assert!(secret.starts_with(keys[0]["prefix"].as_str().unwrap()));
+    assert!(keys[0].get("key").is_none());
+    assert!(keys[0].get("key_hash").is_none());
+    assert!(keys[0]["last_used_at"].is_null());
+}
+
+/// Test minting a key requires at least one scope
+#[tokio::test]
+async fn test_create_api_key_requires_scopes() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "bot", "bot@test.com").await;
+
+    let response = server
+        .post("/auth/api-keys")
+        .authorization_bearer(&token)
+        .json(&json!({ "name": "Empty", "scopes": [] }))
+        .await;
+
+    response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+// ============================================================
+// X-Api-Key authentication
+// ============================================================
+
+/// Test a posts:write key creates posts as its owner and records its last use
+#[tokio::test]
+async fn test_api_key_with_write_scope_creates_post() {
+    let server = common::create_test_server().await;
+    let (user_id, token) = common::create_authenticated_user(&server, "bot", "bot@test.com").await;
+    let (_, secret) = mint_key(&server, &token, json!(["posts:write"])).await;
+
+    let response = server
+        .post("/posts")
+        .add_header("X-Api-Key", &secret)
+        .json(&post_payload())
+        .await;
+
+    response.assert_status(StatusCode::CREATED);
+    let body: Value = response.json();
+    assert_eq!(body["data"]["author"]["id"], user_id);
+
+    let keys: Value = server.get("/auth/api-keys").authorization_bearer(&token).await.json();
+    assert!(keys["data"][0]["last_used_at"].is_string());
+}
+
+/// Test a read-only key can't create posts
+#[tokio::test]
+async fn test_api_key_without_write_scope_is_forbidden() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "bot", "bot@test.com").await;
+    let (_, secret) = mint_key(&server, &token, json!(["posts:read"])).await;
+
+    let response = server
+        .post("/posts")
+        .add_header("X-Api-Key", &secret)
+        .json(&post_payload())
+        .await;
+
+    response.assert_status(StatusCode::FORBIDDEN);
+}
+
+/// Test keys can't reach endpoints outside of posts (account, keys)
+#[tokio::test]
+async fn test_api_key_cannot_manage_account() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "bot", "bot@test.com").await;
+    let (_, secret) = mint_key(&server, &token, json!(["posts:read", "posts:write"])).await;
+
+    server
+        .get("/auth/me")
+        .add_header("X-Api-Key", &secret)
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+
+    server
+        .post("/auth/api-keys")
+        .add_header("X-Api-Key", &secret)
+        .json(&json!({ "name": "Escalation", "scopes": ["posts:write"] }))
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+}
+
+/// Test each endpoint declares its own scope: posts:read reads revisions,
+/// comments need comments:write rather than posts:write
+#[tokio::test]
+async fn test_api_key_scope_is_declared_per_endpoint() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "bot", "bot@test.com").await;
+    let (_, reader) = mint_key(&server, &token, json!(["posts:read"])).await;
+    let (_, writer) = mint_key(&server, &token, json!(["posts:write"])).await;
+    let (_, commenter) = mint_key(&server, &token, json!(["comments:write"])).await;
+
+    let response = server
+        .post("/posts")
+        .authorization_bearer(&token)
+        .json(&json!({
+            "title": "Post importé",
+            "content": "Contenu du post avec au moins 10 caractères",
+            "published": true,
+            "metadata": { "settings": { "allow_comments": true } }
+        }))
+        .await;
+    let post_id = response.json::<Value>()["data"]["id"].as_i64().unwrap();
+    let revisions = format!("/posts/{}/revisions", post_id);
+    let comments = format!("/posts/{}/comments", post_id);
+
+    server
+        .get(&revisions)
+        .add_header("X-Api-Key", &reader)
+        .await
+        .assert_status(StatusCode::OK);
+    server
+        .get(&revisions)
+        .add_header("X-Api-Key", &commenter)
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+
+    let comment = json!({ "body": "Commentaire importé" });
+    server
+        .post(&comments)
+        .add_header("X-Api-Key", &writer)
+        .json(&comment)
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+    server
+        .post(&comments)
+        .add_header("X-Api-Key", &commenter)
+        .json(&comment)
+        .await
+        .assert_status(StatusCode::CREATED);
+}
+
+/// Test an unknown key is rejected with 401
+#[tokio::test]
+async fn test_api_key_unknown() {
+    let server = common::create_test_server().await;
+
+    let response = server
+        .post("/posts")
+        .add_header("X-Api-Key", "rak_not-a-real-key")
+        .json(&post_payload())
+        .await;
+
+    response.assert_status(StatusCode::UNAUTHORIZED);
+    let body: Value = response.json();
+    assert_eq!(body["detail"], "Invalid or revoked API key");
+}
+
+// ============================================================
+// DELETE /auth/api-keys/:id - Revoke
+// ============================================================
+
+/// Test a revoked key no longer authenticates
+#[tokio::test]
+async fn test_revoked_api_key_is_rejected() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "bot", "bot@test.com").await;
+    let (key_id, secret) = mint_key(&server, &token, json!(["posts:write"])).await;
+
+    server
+        .delete(&format!("/auth/api-keys/{}", key_id))
+        .authorization_bearer(&token)
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+
+    let response = server
+        .post("/posts")
+        .add_header("X-Api-Key", &secret)
+        .json(&post_payload())
+        .await;
+
+    response.assert_status(StatusCode::UNAUTHORIZED);
+
+    let keys: Value = server.get("/auth/api-keys").authorization_bearer(&token).await.json();
+    assert!(keys["data"][0]["revoked_at"].is_string());
+}
+
+/// Test a user can't revoke someone else's key
+#[tokio::test]
+async fn test_revoke_api_key_of_other_user_not_found() {
+    let server = common::create_test_server().await;
+    let (_, owner_token) =
+        common::create_authenticated_user(&server, "owner", "owner@test.com").await;
+    let (_, other_token) =
+        common::create_authenticated_user(&server, "other", "other@test.com").await;
+    let (key_id, secret) = mint_key(&server, &owner_token, json!(["posts:write"])).await;
+
+    server
+        .delete(&format!("/auth/api-keys/{}", key_id))
+        .authorization_bearer(&other_token)
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+
+    // La clé fonctionne toujours
+    server
+        .post("/posts")
+        .add_header("X-Api-Key", &secret)
+        .json(&post_payload())
+        .await
+        .assert_status(StatusCode::CREATED);
+}
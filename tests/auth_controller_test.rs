@@ -0,0 +1,336 @@
+// tests/auth_controller_test.rs
+// Equivalent de: tests/Controller/SecurityControllerTest.php
+
+mod common;
+
+use axum::http::{header, StatusCode};
+use axum_test::{TestResponse, TestServer};
+use serde_json::{json, Value};
+
+/// POST /auth/refresh with the given refresh token
+async fn refresh(server: &TestServer, refresh_token: &str) -> TestResponse {
+    server
+        .post("/auth/refresh")
+        .json(&json!({ "refresh_token": refresh_token }))
+        .await
+}
+
+// ============================================================
+// POST /auth/login - Login
+// ============================================================
+
+/// Test POST /auth/login returns a bearer token for valid credentials
+#[tokio::test]
+async fn test_login_success() {
+    let server = common::create_test_server().await;
+    common::create_user(&server, "johndoe", "john@example.com").await;
+
+    let response = server
+        .post("/auth/login")
+        .json(&json!({
+            "email": "john@example.com",
+            "password": common::TEST_PASSWORD
+        }))
+        .await;
+
+    response.assert_status(StatusCode::OK);
+    let body: Value = response.json();
+    assert!(body["data"]["access_token"].as_str().is_some());
+    assert!(body["data"]["refresh_token"].as_str().unwrap().starts_with("rrt_"));
+    assert_eq!(body["data"]["token_type"], "Bearer");
+    assert_eq!(body["data"]["expires_in"], 3600);
+}
+
+/// Test POST /auth/login returns 401 for a wrong password
+#[tokio::test]
+async fn test_login_wrong_password() {
+    let server = common::create_test_server().await;
+    common::create_user(&server, "johndoe", "john@example.com").await;
+
+    let response = server
+        .post("/auth/login")
+        .json(&json!({
+            "email": "john@example.com",
+            "password": "not-the-password"
+        }))
+        .await;
+
+    response.assert_status(StatusCode::UNAUTHORIZED);
+    let body: Value = response.json();
+    assert_eq!(body["code"], "unauthorized");
+}
+
+/// Test POST /auth/login returns 401 for an unknown email
+#[tokio::test]
+async fn test_login_unknown_email() {
+    let server = common::create_test_server().await;
+
+    let response = server
+        .post("/auth/login")
+        .json(&json!({
+            "email": "nobody@example.com",
+            "password": common::TEST_PASSWORD
+        }))
+        .await;
+
+    response.assert_status(StatusCode::UNAUTHORIZED);
+}
+
+// ============================================================
+// GET /auth/me - Current user
+// ============================================================
+
+/// Test GET /auth/me resolves the user from the bearer token
+#[tokio::test]
+async fn test_me_returns_current_user() {
+    let server = common::create_test_server().await;
+    let (user_id, token) =
+        common::create_authenticated_user(&server, "johndoe", "john@example.com").await;
+
+    let response = server.get("/auth/me").authorization_bearer(&token).await;
+
+    response.assert_status(StatusCode::OK);
+    let body: Value = response.json();
+    assert_eq!(body["data"]["id"], user_id);
+    assert_eq!(body["data"]["username"], "johndoe");
+}
+
+/// Test GET /auth/me returns 401 without token
+#[tokio::test]
+async fn test_me_without_token() {
+    let server = common::create_test_server().await;
+
+    let response = server.get("/auth/me").await;
+
+    response.assert_status(StatusCode::UNAUTHORIZED);
+    assert_eq!(response.header(header::WWW_AUTHENTICATE), "Bearer");
+}
+
+/// Test GET /auth/me returns 401 for a forged token
+#[tokio::test]
+async fn test_me_with_invalid_token() {
+    let server = common::create_test_server().await;
+
+    let response = server
+        .get("/auth/me")
+        .authorization_bearer("not.a.jwt")
+        .await;
+
+    response.assert_status(StatusCode::UNAUTHORIZED);
+    let body: Value = response.json();
+    assert_eq!(body["detail"], "Invalid or expired token");
+}
+
+// ============================================================
+// POST /auth/refresh - Token rotation
+// ============================================================
+
+/// Test POST /auth/refresh returns a new token pair
+#[tokio::test]
+async fn test_refresh_rotates_tokens() {
+    let server = common::create_test_server().await;
+    common::create_user(&server, "johndoe", "john@example.com").await;
+    let (_, refresh_token) = common::login_session(&server, "john@example.com").await;
+
+    let response = refresh(&server, &refresh_token).await;
+
+    response.assert_status(StatusCode::OK);
+    let body: Value = response.json();
+    let new_refresh_token = body["data"]["refresh_token"].as_str().unwrap();
+    assert_ne!(new_refresh_token, refresh_token);
+
+    // Le nouvel access token est utilisable
+    let access_token = body["data"]["access_token"].as_str().unwrap();
+    server
+        .get("/auth/me")
+        .authorization_bearer(access_token)
+        .await
+        .assert_status(StatusCode::OK);
+
+    // Le nouveau refresh token aussi
+    refresh(&server, new_refresh_token)
+        .await
+        .assert_status(StatusCode::OK);
+}
+
+/// Test replaying a rotated refresh token revokes the whole family
+#[tokio::test]
+async fn test_refresh_reuse_revokes_family() {
+    let server = common::create_test_server().await;
+    common::create_user(&server, "johndoe", "john@example.com").await;
+    let (_, stolen_token) = common::login_session(&server, "john@example.com").await;
+
+    let body: Value = refresh(&server, &stolen_token).await.json();
+    let legit_token = body["data"]["refresh_token"].as_str().unwrap().to_string();
+
+    // Réutilisation du token déjà échangé
+    refresh(&server, &stolen_token)
+        .await
+        .assert_status(StatusCode::UNAUTHORIZED);
+
+    // Le token issu de la rotation est révoqué lui aussi
+    refresh(&server, &legit_token)
+        .await
+        .assert_status(StatusCode::UNAUTHORIZED);
+}
+
+/// Test reuse detection only affects the compromised session
+#[tokio::test]
+async fn test_refresh_reuse_keeps_other_sessions() {
+    let server = common::create_test_server().await;
+    common::create_user(&server, "johndoe", "john@example.com").await;
+    let (_, stolen_token) = common::login_session(&server, "john@example.com").await;
+    let (_, other_session) = common::login_session(&server, "john@example.com").await;
+
+    refresh(&server, &stolen_token).await.assert_status(StatusCode::OK);
+    refresh(&server, &stolen_token)
+        .await
+        .assert_status(StatusCode::UNAUTHORIZED);
+
+    refresh(&server, &other_session).await.assert_status(StatusCode::OK);
+}
+
+/// Test POST /auth/refresh rejects an unknown token
+#[tokio::test]
+async fn test_refresh_unknown_token() {
+    let server = common::create_test_server().await;
+
+    refresh(&server, "rrt_unknown")
+        .await
+        .assert_status(StatusCode::UNAUTHORIZED);
+}
+
+// ============================================================
+// POST /auth/logout, /auth/logout-all - Session revocation
+// ============================================================
+
+/// Test POST /auth/logout revokes only the given session
+#[tokio::test]
+async fn test_logout_revokes_session() {
+    let server = common::create_test_server().await;
+    common::create_user(&server, "johndoe", "john@example.com").await;
+    let (_, refresh_token) = common::login_session(&server, "john@example.com").await;
+    let (_, other_session) = common::login_session(&server, "john@example.com").await;
+
+    server
+        .post("/auth/logout")
+        .json(&json!({ "refresh_token": refresh_token }))
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+
+    refresh(&server, &refresh_token)
+        .await
+        .assert_status(StatusCode::UNAUTHORIZED);
+    refresh(&server, &other_session).await.assert_status(StatusCode::OK);
+}
+
+/// Test POST /auth/logout-all revokes every session of the user
+#[tokio::test]
+async fn test_logout_all_revokes_every_session() {
+    let server = common::create_test_server().await;
+    common::create_user(&server, "johndoe", "john@example.com").await;
+    let (token, first_session) = common::login_session(&server, "john@example.com").await;
+    let (_, second_session) = common::login_session(&server, "john@example.com").await;
+
+    server
+        .post("/auth/logout-all")
+        .authorization_bearer(&token)
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+
+    refresh(&server, &first_session)
+        .await
+        .assert_status(StatusCode::UNAUTHORIZED);
+    refresh(&server, &second_session)
+        .await
+        .assert_status(StatusCode::UNAUTHORIZED);
+}
+
+/// Test POST /auth/logout-all requires authentication
+#[tokio::test]
+async fn test_logout_all_requires_authentication() {
+    let server = common::create_test_server().await;
+
+    server
+        .post("/auth/logout-all")
+        .await
+        .assert_status(StatusCode::UNAUTHORIZED);
+}
+
+// ============================================================
+// PUT /auth/password - Change password
+// ============================================================
+
+/// Test PUT /auth/password changes the password used to log in and ends sessions
+#[tokio::test]
+async fn test_change_password_success() {
+    let server = common::create_test_server().await;
+    common::create_user(&server, "johndoe", "john@example.com").await;
+    let (token, refresh_token) = common::login_session(&server, "john@example.com").await;
+
+    let response = server
+        .put("/auth/password")
+        .authorization_bearer(&token)
+        .json(&json!({
+            "current_password": common::TEST_PASSWORD,
+            "new_password": "N3w-S3cure-password"
+        }))
+        .await;
+
+    response.assert_status(StatusCode::NO_CONTENT);
+
+    // Old password no longer works
+    server
+        .post("/auth/login")
+        .json(&json!({ "email": "john@example.com", "password": common::TEST_PASSWORD }))
+        .await
+        .assert_status(StatusCode::UNAUTHORIZED);
+
+    // New password works
+    server
+        .post("/auth/login")
+        .json(&json!({ "email": "john@example.com", "password": "N3w-S3cure-password" }))
+        .await
+        .assert_status(StatusCode::OK);
+
+    // Sessions opened with the old password are revoked
+    refresh(&server, &refresh_token)
+        .await
+        .assert_status(StatusCode::UNAUTHORIZED);
+}
+
+/// Test PUT /auth/password rejects a wrong current password
+#[tokio::test]
+async fn test_change_password_wrong_current_password() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "johndoe", "john@example.com").await;
+
+    let response = server
+        .put("/auth/password")
+        .authorization_bearer(&token)
+        .json(&json!({
+            "current_password": "not-the-password",
+            "new_password": "N3w-S3cure-password"
+        }))
+        .await;
+
+    response.assert_status(StatusCode::UNAUTHORIZED);
+}
+
+/// Test PUT /auth/password enforces password strength
+#[tokio::test]
+async fn test_change_password_weak_new_password() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "johndoe", "john@example.com").await;
+
+    let response = server
+        .put("/auth/password")
+        .authorization_bearer(&token)
+        .json(&json!({
+            "current_password": common::TEST_PASSWORD,
+            "new_password": "password"
+        }))
+        .await;
+
+    response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+}
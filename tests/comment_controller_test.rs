@@ -0,0 +1,290 @@
+// tests/comment_controller_test.rs
+// Tests pour le CommentController (commentaires d'un post)
+
+use axum::http::StatusCode;
+use axum_test::TestServer;
+use rust_api::entities::user::Role;
+use serde_json::{json, Value};
+
+mod common;
+
+/// Crée un post et retourne son ID
+async fn create_post(
+    server: &TestServer,
+    token: &str,
+    published: bool,
+    allow_comments: bool,
+) -> i64 {
+    let response = server
+        .post("/posts")
+        .authorization_bearer(token)
+        .json(&json!({
+            "title": "Post commentable",
+            "content": "Contenu du post avec au moins 10 caractères",
+            "published": published,
+            "metadata": {
+                "settings": { "allow_comments": allow_comments }
+            }
+        }))
+        .await;
+
+    response.assert_status(StatusCode::CREATED);
+    let body: Value = response.json();
+    body["data"]["id"].as_i64().unwrap()
+}
+
+/// Commente un post et retourne la réponse brute
+async fn comment(server: &TestServer, token: &str, post_id: i64, payload: Value) -> Value {
+    let response = server
+        .post(&format!("/posts/{}/comments", post_id))
+        .authorization_bearer(token)
+        .json(&payload)
+        .await;
+
+    response.assert_status(StatusCode::CREATED);
+    response.json()
+}
+
+// ============================================================================
+// CREATE COMMENT
+// ============================================================================
+
+#[tokio::test]
+async fn test_create_comment_success() {
+    let server = common::create_test_server().await;
+    let (_, author_token) =
+        common::create_authenticated_user(&server, "author", "author@test.com").await;
+    let (reader_id, reader_token) =
+        common::create_authenticated_user(&server, "reader", "reader@test.com").await;
+    let post_id = create_post(&server, &author_token, true, true).await;
+
+    let body = comment(&server, &reader_token, post_id, json!({ "body": "Super article" })).await;
+
+    assert_eq!(body["data"]["body"], "Super article");
+    assert_eq!(body["data"]["post_id"], post_id);
+    assert_eq!(body["data"]["author"]["id"], reader_id);
+    assert!(body["data"]["parent_id"].is_null());
+}
+
+#[tokio::test]
+async fn test_create_reply_to_comment() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "author", "author@test.com").await;
+    let post_id = create_post(&server, &token, true, true).await;
+
+    let parent = comment(&server, &token, post_id, json!({ "body": "Premier" })).await;
+    let parent_id = parent["data"]["id"].as_i64().unwrap();
+
+    let reply = comment(
+        &server,
+        &token,
+        post_id,
+        json!({ "body": "Réponse", "parent_id": parent_id }),
+    )
+    .await;
+
+    assert_eq!(reply["data"]["parent_id"], parent_id);
+}
+
+#[tokio::test]
+async fn test_create_reply_to_comment_of_other_post() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "author", "author@test.com").await;
+    let first_post = create_post(&server, &token, true, true).await;
+    let second_post = create_post(&server, &token, true, true).await;
+
+    let parent = comment(&server, &token, first_post, json!({ "body": "Premier" })).await;
+
+    let response = server
+        .post(&format!("/posts/{}/comments", second_post))
+        .authorization_bearer(&token)
+        .json(&json!({ "body": "Réponse", "parent_id": parent["data"]["id"] }))
+        .await;
+
+    response.assert_status(StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_create_comment_disabled_comments() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "author", "author@test.com").await;
+    let post_id = create_post(&server, &token, true, false).await;
+
+    let response = server
+        .post(&format!("/posts/{}/comments", post_id))
+        .authorization_bearer(&token)
+        .json(&json!({ "body": "Refusé" }))
+        .await;
+
+    response.assert_status(StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_create_comment_unpublished_post() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "author", "author@test.com").await;
+    let post_id = create_post(&server, &token, false, true).await;
+
+    let response = server
+        .post(&format!("/posts/{}/comments", post_id))
+        .authorization_bearer(&token)
+        .json(&json!({ "body": "Trop tôt" }))
+        .await;
+
+    response.assert_status(StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_create_comment_requires_authentication() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "author", "author@test.com").await;
+    let post_id = create_post(&server, &token, true, true).await;
+
+    let response = server
+        .post(&format!("/posts/{}/comments", post_id))
+        .json(&json!({ "body": "Anonyme" }))
+        .await;
+
+    response.assert_status(StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_create_comment_post_not_found() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "author", "author@test.com").await;
+
+    let response = server
+        .post("/posts/9999/comments")
+        .authorization_bearer(&token)
+        .json(&json!({ "body": "Dans le vide" }))
+        .await;
+
+    response.assert_status(StatusCode::NOT_FOUND);
+}
+
+// ============================================================================
+// LIST / GET COMMENTS
+// ============================================================================
+
+#[tokio::test]
+async fn test_list_comments_with_pagination() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "author", "author@test.com").await;
+    let post_id = create_post(&server, &token, true, true).await;
+
+    for i in 1..=3 {
+        comment(&server, &token, post_id, json!({ "body": format!("Commentaire {}", i) })).await;
+    }
+
+    let response = server
+        .get(&format!("/posts/{}/comments", post_id))
+        .add_query_param("per_page", 2)
+        .await;
+
+    response.assert_status(StatusCode::OK);
+    let body: Value = response.json();
+    assert_eq!(body["data"].as_array().unwrap().len(), 2);
+    assert_eq!(body["data"][0]["body"], "Commentaire 1");
+    assert_eq!(body["meta"]["total"], 3);
+}
+
+#[tokio::test]
+async fn test_get_comment_of_other_post_not_found() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "author", "author@test.com").await;
+    let first_post = create_post(&server, &token, true, true).await;
+    let second_post = create_post(&server, &token, true, true).await;
+
+    let created = comment(&server, &token, first_post, json!({ "body": "Premier" })).await;
+
+    server
+        .get(&format!("/posts/{}/comments/{}", second_post, created["data"]["id"]))
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_post_list_includes_comment_count() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "author", "author@test.com").await;
+    let commented = create_post(&server, &token, true, true).await;
+    create_post(&server, &token, true, true).await;
+
+    comment(&server, &token, commented, json!({ "body": "Un" })).await;
+    comment(&server, &token, commented, json!({ "body": "Deux" })).await;
+
+    let body: Value = server.get("/posts").await.json();
+    let posts = body["data"].as_array().unwrap();
+
+    for post in posts {
+        let expected = if post["id"] == commented { 2 } else { 0 };
+        assert_eq!(post["comment_count"], expected);
+    }
+}
+
+// ============================================================================
+// UPDATE / DELETE COMMENTS
+// ============================================================================
+
+#[tokio::test]
+async fn test_update_comment_by_author() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "author", "author@test.com").await;
+    let post_id = create_post(&server, &token, true, true).await;
+    let created = comment(&server, &token, post_id, json!({ "body": "Typo" })).await;
+
+    let response = server
+        .put(&format!("/posts/{}/comments/{}", post_id, created["data"]["id"]))
+        .authorization_bearer(&token)
+        .json(&json!({ "body": "Corrigé" }))
+        .await;
+
+    response.assert_status(StatusCode::OK);
+    let body: Value = response.json();
+    assert_eq!(body["data"]["body"], "Corrigé");
+    assert!(body["data"]["updated_at"].is_string());
+}
+
+#[tokio::test]
+async fn test_update_comment_forbidden_for_other_user() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "author", "author@test.com").await;
+    let (_, other_token) =
+        common::create_authenticated_user(&server, "other", "other@test.com").await;
+    let post_id = create_post(&server, &token, true, true).await;
+    let created = comment(&server, &token, post_id, json!({ "body": "À moi" })).await;
+
+    server
+        .put(&format!("/posts/{}/comments/{}", post_id, created["data"]["id"]))
+        .authorization_bearer(&other_token)
+        .json(&json!({ "body": "Piraté" }))
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_delete_comment_by_moderator_removes_replies() {
+    let (server, db) = common::create_test_server_with_db().await;
+    let (_, token) = common::create_authenticated_user(&server, "author", "author@test.com").await;
+    let (_, editor_token) =
+        common::create_user_with_role(&server, &db, Role::Editor, "editor", "editor@test.com").await;
+    let post_id = create_post(&server, &token, true, true).await;
+
+    let parent = comment(&server, &token, post_id, json!({ "body": "Parent" })).await;
+    comment(
+        &server,
+        &token,
+        post_id,
+        json!({ "body": "Réponse", "parent_id": parent["data"]["id"] }),
+    )
+    .await;
+
+    server
+        .delete(&format!("/posts/{}/comments/{}", post_id, parent["data"]["id"]))
+        .authorization_bearer(&editor_token)
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+
+    let body: Value = server.get(&format!("/posts/{}/comments", post_id)).await.json();
+    assert_eq!(body["meta"]["total"], 0);
+}
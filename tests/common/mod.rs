@@ -1,40 +1,143 @@
 // tests/common/mod.rs
 // Equivalent de: tests/WebTestCase.php ou KernelTestCase
 
-use axum::Router;
+// Chaque fichier de test n'utilise qu'une partie des helpers
+#![allow(dead_code)]
+
+use axum::{middleware, Router};
 use axum_test::TestServer;
 use migration::Migrator;
 use sea_orm::{Database, DatabaseConnection};
 use sea_orm_migration::MigratorTrait;
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use tower_http::request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer};
+
+use rust_api::config::{init_jwt, AppState, Profile, Settings};
+use rust_api::controllers::{
+    ApiKeyController, AuthController, CommentController, HealthController, MetricsController,
+    PostController, RevisionController, TagController, UserController,
+};
+use rust_api::entities::user::Role;
+use rust_api::error::{method_not_allowed, problem_context, route_not_found};
+use rust_api::fixtures::{Factory, UserFactory};
+use rust_api::metrics::track_http;
+use rust_api::services::{
+    ApiKeyService, CommentService, HealthService, ManualClock, PostService, RefreshTokenService,
+    SearchService, SharedClock, SystemClock, TagService, UserService,
+};
+use rust_api::shutdown::Shutdown;
+
+/// Password used by the helpers below
+pub const TEST_PASSWORD: &str = "S3cure-password";
 
-use rust_api::config::AppState;
-use rust_api::controllers::{HealthController, PostController, UserController};
-use rust_api::services::{PostService, UserService};
+/// METRICS_TOKEN of the test server
+pub const TEST_METRICS_TOKEN: &str = "test-metrics-token";
 
 /// Create a test server with in-memory SQLite database
 /// Equivalent de: static::createClient() en Symfony
 pub async fn create_test_server() -> TestServer {
-    let app = create_test_app().await;
-    TestServer::new(app).unwrap()
+    let (server, _db) = create_test_server_with_db().await;
+    server
 }
 
-/// Create the test application router
-async fn create_test_app() -> Router {
+/// Same as create_test_server, but also returns the database
+/// to seed data directly (e.g. users with a given role)
+pub async fn create_test_server_with_db() -> (TestServer, DatabaseConnection) {
     let db = create_test_database().await;
+    let app = create_test_app(db.clone(), Arc::new(SystemClock), Shutdown::new());
+    (TestServer::new(app).unwrap(), db)
+}
+
+/// Same as create_test_server_with_db, with a manual clock driving the services
+/// (scheduled publishing tests)
+pub async fn create_test_server_with_clock() -> (TestServer, DatabaseConnection, ManualClock) {
+    let db = create_test_database().await;
+    let clock = ManualClock::new(chrono::Utc::now().naive_utc());
+    let app = create_test_app(db.clone(), Arc::new(clock.clone()), Shutdown::new());
+    (TestServer::new(app).unwrap(), db, clock)
+}
+
+/// Same as create_test_server, with the shutdown signal of the application
+/// (readiness during shutdown)
+pub async fn create_test_server_with_shutdown() -> (TestServer, Shutdown) {
+    let db = create_test_database().await;
+    let shutdown = Shutdown::new();
+    let app = create_test_app(db, Arc::new(SystemClock), shutdown.clone());
+    (TestServer::new(app).unwrap(), shutdown)
+}
+
+/// Same as create_test_server_with_db, with a counter of executed SQL statements
+/// (regression tests for N+1 queries)
+pub async fn create_test_server_with_query_counter(
+) -> (TestServer, DatabaseConnection, Arc<AtomicUsize>) {
+    let mut db = create_test_database().await;
+    let statements = Arc::new(AtomicUsize::new(0));
+
+    let counter = statements.clone();
+    db.set_metric_callback(move |_| {
+        counter.fetch_add(1, Ordering::SeqCst);
+    });
+
+    let app = create_test_app(db.clone(), Arc::new(SystemClock), Shutdown::new());
+    (TestServer::new(app).unwrap(), db, statements)
+}
+
+/// Create the test application router
+fn create_test_app(db: DatabaseConnection, clock: SharedClock, shutdown: Shutdown) -> Router {
     let user_service = UserService::new(db.clone());
-    let post_service = PostService::new(db);
-    let state = Arc::new(AppState::new(user_service, post_service));
+    let post_service = PostService::new(db.clone(), clock);
+    let comment_service = CommentService::new(db.clone());
+    let tag_service = TagService::new(db.clone());
+    let search_service = SearchService::new(db.clone());
+    let api_key_service = ApiKeyService::new(db.clone());
+    // Profil test (config/test.toml), sans variable d'environnement
+    let settings = Settings::for_profile(Profile::Test).expect("Invalid test configuration");
+    let refresh_token_service =
+        RefreshTokenService::new(db.clone(), settings.security.refresh_token_ttl_seconds);
+    let jwt_service = init_jwt(&settings.security);
+    let health_service = HealthService::new(db, shutdown);
+    let state = Arc::new(AppState::new(
+        user_service,
+        post_service,
+        comment_service,
+        tag_service,
+        search_service,
+        api_key_service,
+        refresh_token_service,
+        jwt_service,
+        health_service,
+        settings,
+    ));
 
+    let auth_routes = AuthController::routes();
+    let api_key_routes = ApiKeyController::routes();
     let user_routes = UserController::routes();
     let post_routes = PostController::routes();
+    let revision_routes = RevisionController::routes();
+    let comment_routes = CommentController::routes();
+    let tag_routes = TagController::routes();
     let health_routes = HealthController::routes();
+    let metrics_routes = MetricsController::routes(TEST_METRICS_TOKEN);
 
     Router::new()
+        .merge(auth_routes)
+        .merge(api_key_routes)
         .merge(user_routes)
         .merge(post_routes)
-        .with_state(state)
+        .merge(revision_routes)
+        .merge(comment_routes)
+        .merge(tag_routes)
         .merge(health_routes)
+        .with_state(state)
+        .merge(metrics_routes)
+        .fallback(route_not_found)
+        .method_not_allowed_fallback(method_not_allowed)
+        .layer(middleware::from_fn(track_http))
+        .layer(middleware::from_fn(problem_context))
+        .layer(PropagateRequestIdLayer::x_request_id())
+        .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
 }
 
 /// Create an in-memory SQLite database for testing
@@ -52,3 +155,84 @@ async fn create_test_database() -> DatabaseConnection {
 
     db
 }
+
+/// Create a user through the API and return its ID
+pub async fn create_user(server: &TestServer, username: &str, email: &str) -> i64 {
+    let response = server
+        .post("/users")
+        .json(&json!({
+            "username": username,
+            "email": email,
+            "password": TEST_PASSWORD
+        }))
+        .await;
+
+    let body: Value = response.json();
+    body["data"]["id"].as_i64().unwrap()
+}
+
+/// Log in and return the bearer token
+/// Equivalent de: $client->loginUser($user) en Symfony
+pub async fn login(server: &TestServer, email: &str) -> String {
+    let response = server
+        .post("/auth/login")
+        .json(&json!({
+            "email": email,
+            "password": TEST_PASSWORD
+        }))
+        .await;
+
+    let body: Value = response.json();
+    body["data"]["access_token"].as_str().unwrap().to_string()
+}
+
+/// Log in and return (access token, refresh token)
+pub async fn login_session(server: &TestServer, email: &str) -> (String, String) {
+    let response = server
+        .post("/auth/login")
+        .json(&json!({
+            "email": email,
+            "password": TEST_PASSWORD
+        }))
+        .await;
+
+    let body: Value = response.json();
+    (
+        body["data"]["access_token"].as_str().unwrap().to_string(),
+        body["data"]["refresh_token"].as_str().unwrap().to_string(),
+    )
+}
+
+/// Create a user and log in, returning (user ID, bearer token)
+pub async fn create_authenticated_user(
+    server: &TestServer,
+    username: &str,
+    email: &str,
+) -> (i64, String) {
+    let user_id = create_user(server, username, email).await;
+    let token = login(server, email).await;
+    (user_id, token)
+}
+
+/// Create a user with the given role directly in database and log in,
+/// returning (user ID, bearer token)
+/// Equivalent de: UserFactory::createOne(['roles' => [...]]) + loginUser()
+pub async fn create_user_with_role(
+    server: &TestServer,
+    db: &DatabaseConnection,
+    role: Role,
+    username: &str,
+    email: &str,
+) -> (i64, String) {
+    let user = UserFactory::new()
+        .with_username(username)
+        .with_email(email)
+        .with_password(TEST_PASSWORD)
+        .with_role(role)
+        .create(db)
+        .await
+        .expect("Failed to create user");
+
+    let token = login(server, email).await;
+    (user.id as i64, token)
+}
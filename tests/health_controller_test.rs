@@ -4,6 +4,9 @@
 mod common;
 
 use axum::http::StatusCode;
+use migration::Migrator;
+use sea_orm_migration::MigratorTrait;
+use serde_json::Value;
 
 /// Test GET / returns 200 and hello message
 /// Equivalent de: testIndex() en Symfony
@@ -31,3 +34,177 @@ async fn test_health_returns_ok() {
     response.assert_status(StatusCode::OK);
     response.assert_text("OK");
 }
+
+/// Test GET /health/live returns 200 without checking dependencies
+#[tokio::test]
+async fn test_liveness_returns_up() {
+    let server = common::create_test_server().await;
+
+    let response = server.get("/health/live").await;
+
+    response.assert_status(StatusCode::OK);
+    let body: Value = response.json();
+    assert_eq!(body["status"], "up");
+}
+
+/// Test GET /health/ready reports each check with its latency
+#[tokio::test]
+async fn test_readiness_reports_checks() {
+    let server = common::create_test_server().await;
+
+    let response = server.get("/health/ready").await;
+
+    response.assert_status(StatusCode::OK);
+    let body: Value = response.json();
+    assert_eq!(body["status"], "up");
+    assert_eq!(body["draining"], false);
+    for check in ["database", "migrations"] {
+        assert_eq!(body["checks"][check]["status"], "up");
+        assert!(body["checks"][check]["latency_ms"].is_number());
+        assert!(body["checks"][check].get("reason").is_none());
+    }
+}
+
+/// Test GET /health/ready returns 503 when a migration is pending
+#[tokio::test]
+async fn test_readiness_fails_with_pending_migrations() {
+    let (server, db) = common::create_test_server_with_db().await;
+    Migrator::down(&db, Some(1)).await.unwrap();
+
+    let response = server.get("/health/ready").await;
+
+    response.assert_status(StatusCode::SERVICE_UNAVAILABLE);
+    let body: Value = response.json();
+    assert_eq!(body["status"], "down");
+    assert_eq!(body["checks"]["database"]["status"], "up");
+    assert_eq!(body["checks"]["migrations"]["status"], "down");
+    // La cause seulement: ni le nom des migrations ni l'erreur SQL
+    assert_eq!(body["checks"]["migrations"]["reason"], "pending_migrations");
+    assert!(!response.text().contains("m2024"));
+}
+
+/// Test GET /health/ready returns 503 when the database is unreachable
+#[tokio::test]
+async fn test_readiness_fails_when_database_is_down() {
+    let (server, db) = common::create_test_server_with_db().await;
+    db.close().await.unwrap();
+
+    let response = server.get("/health/ready").await;
+
+    response.assert_status(StatusCode::SERVICE_UNAVAILABLE);
+    let body: Value = response.json();
+    assert_eq!(body["status"], "down");
+    assert_eq!(body["checks"]["database"]["status"], "down");
+    assert_eq!(body["checks"]["database"]["reason"], "unavailable");
+
+    // La liveness ne dépend pas de la base
+    server.get("/health/live").await.assert_status(StatusCode::OK);
+}
+
+/// Test GET /health/ready fails as soon as shutdown starts, while liveness stays up
+#[tokio::test]
+async fn test_readiness_fails_during_shutdown() {
+    let (server, shutdown) = common::create_test_server_with_shutdown().await;
+    server.get("/health/ready").await.assert_status(StatusCode::OK);
+
+    shutdown.trigger();
+
+    let response = server.get("/health/ready").await;
+    response.assert_status(StatusCode::SERVICE_UNAVAILABLE);
+    let body: Value = response.json();
+    assert_eq!(body["status"], "down");
+    assert_eq!(body["draining"], true);
+    assert_eq!(body["checks"]["database"]["status"], "up");
+    server.get("/health/live").await.assert_status(StatusCode::OK);
+}
+
+/// Test every response carries an X-Request-Id (generated when the client sends none)
+#[tokio::test]
+async fn test_request_id_is_generated_and_propagated() {
+    let server = common::create_test_server().await;
+
+    let response = server.get("/health").await;
+    let generated = response.header("x-request-id");
+    assert_eq!(generated.len(), 36);
+
+    let response = server
+        .get("/health")
+        .add_header("X-Request-Id", "support-ticket-42")
+        .await;
+    assert_eq!(response.header("x-request-id"), "support-ticket-42");
+}
+
+/// Test an unknown route returns a not_found problem
+#[tokio::test]
+async fn test_unknown_route_is_a_problem() {
+    let server = common::create_test_server().await;
+
+    let response = server
+        .get("/nope")
+        .add_header("X-Request-Id", "support-ticket-42")
+        .await;
+
+    response.assert_status(StatusCode::NOT_FOUND);
+    assert_eq!(response.header("content-type"), "application/problem+json");
+    let body: Value = response.json();
+    assert_eq!(body["code"], "not_found");
+    assert_eq!(body["type"], "/problems/not_found");
+    assert_eq!(body["instance"], "/nope");
+    assert_eq!(body["request_id"], "support-ticket-42");
+}
+
+/// Test a known route called with another method returns a method_not_allowed problem
+#[tokio::test]
+async fn test_wrong_method_is_a_problem() {
+    let server = common::create_test_server().await;
+
+    let response = server
+        .delete("/health")
+        .add_header("Accept-Language", "fr")
+        .await;
+
+    response.assert_status(StatusCode::METHOD_NOT_ALLOWED);
+    assert_eq!(response.header("content-type"), "application/problem+json");
+    let body: Value = response.json();
+    assert_eq!(body["code"], "method_not_allowed");
+    assert_eq!(body["title"], "Méthode non autorisée");
+    assert_eq!(body["instance"], "/health");
+}
+
+/// Test GET /metrics requires the metrics token
+#[tokio::test]
+async fn test_metrics_requires_token() {
+    let server = common::create_test_server().await;
+
+    let response = server.get("/metrics").await;
+    response.assert_status(StatusCode::UNAUTHORIZED);
+
+    let response = server
+        .get("/metrics")
+        .authorization_bearer("wrong-token")
+        .await;
+    response.assert_status(StatusCode::UNAUTHORIZED);
+}
+
+/// Test GET /metrics exposes HTTP and business metrics in Prometheus text format
+#[tokio::test]
+async fn test_metrics_exposes_http_and_business_metrics() {
+    let server = common::create_test_server().await;
+    common::create_user(&server, "metrics", "metrics@example.com").await;
+    server.get("/users/999999").await;
+
+    let response = server
+        .get("/metrics")
+        .authorization_bearer(common::TEST_METRICS_TOKEN)
+        .await;
+
+    response.assert_status(StatusCode::OK);
+    assert!(response.header("content-type").to_str().unwrap().starts_with("text/plain"));
+    let body = response.text();
+    // Route matchée, pas le chemin brut (cardinalité bornée)
+    assert!(body.contains(r#"http_requests_total{method="GET",route="/users/:id",status="404"}"#));
+    assert!(!body.contains("/users/999999"));
+    assert!(body.contains("http_request_duration_seconds_bucket"));
+    assert!(body.contains("http_requests_in_flight"));
+    assert!(body.contains("users_created_total"));
+}
@@ -2,7 +2,17 @@
 // Tests pour le PostController avec nested objects
 
 use axum::http::StatusCode;
+use migration::Migrator;
+use rust_api::entities::post::{self, PostStatus};
+use rust_api::entities::post_slug_redirect;
+use rust_api::entities::user::Role;
+use rust_api::services::{Clock, ScheduledPublisher};
+use rust_api::shutdown::Shutdown;
+use sea_orm::{ActiveModelTrait, ConnectionTrait, Database, EntityTrait, Set};
+use sea_orm_migration::MigratorTrait;
 use serde_json::json;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
 
 mod common;
 
@@ -33,19 +43,13 @@ async fn test_create_post_minimal() {
     let server = common::create_test_server().await;
 
     // Créer d'abord un utilisateur
-    let user_response = server
-        .post("/users")
-        .json(&json!({
-            "username": "author",
-            "email": "author@test.com"
-        }))
-        .await;
-    let user: serde_json::Value = user_response.json();
-    let user_id = user["data"]["id"].as_i64().unwrap();
+    let (user_id, token) =
+        common::create_authenticated_user(&server, "author", "author@test.com").await;
 
     // Créer un post avec le minimum requis
     let response = server
         .post("/posts")
+        .authorization_bearer(&token)
         .json(&json!({
             "title": "Mon premier post",
             "content": "Contenu du post avec au moins 10 caractères",
@@ -73,19 +77,13 @@ async fn test_create_post_with_full_nested_objects() {
     let server = common::create_test_server().await;
 
     // Créer un utilisateur
-    let user_response = server
-        .post("/users")
-        .json(&json!({
-            "username": "blogger",
-            "email": "blogger@test.com"
-        }))
-        .await;
-    let user: serde_json::Value = user_response.json();
-    let user_id = user["data"]["id"].as_i64().unwrap();
+    let (user_id, token) =
+        common::create_authenticated_user(&server, "blogger", "blogger@test.com").await;
 
     // Créer un post avec tous les nested objects
     let response = server
         .post("/posts")
+        .authorization_bearer(&token)
         .json(&json!({
             "title": "Article complet avec metadata",
             "content": "Ceci est un article complet avec toutes les métadonnées imbriquées pour tester le système.",
@@ -151,9 +149,11 @@ async fn test_create_post_with_full_nested_objects() {
 #[tokio::test]
 async fn test_create_post_validation_title_too_short() {
     let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
 
     let response = server
         .post("/posts")
+        .authorization_bearer(&token)
         .json(&json!({
             "title": "AB",  // Trop court (min 3)
             "content": "Contenu valide avec plus de 10 caractères",
@@ -167,9 +167,11 @@ async fn test_create_post_validation_title_too_short() {
 #[tokio::test]
 async fn test_create_post_validation_content_too_short() {
     let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
 
     let response = server
         .post("/posts")
+        .authorization_bearer(&token)
         .json(&json!({
             "title": "Titre valide",
             "content": "Court",  // Trop court (min 10)
@@ -185,18 +187,12 @@ async fn test_create_post_validation_nested_tag_too_long() {
     let server = common::create_test_server().await;
 
     // Créer un utilisateur
-    let user_response = server
-        .post("/users")
-        .json(&json!({
-            "username": "tester",
-            "email": "tester@test.com"
-        }))
-        .await;
-    let user: serde_json::Value = user_response.json();
-    let user_id = user["data"]["id"].as_i64().unwrap();
+    let (user_id, token) =
+        common::create_authenticated_user(&server, "tester", "tester@test.com").await;
 
     let response = server
         .post("/posts")
+        .authorization_bearer(&token)
         .json(&json!({
             "title": "Titre valide",
             "content": "Contenu valide avec plus de 10 caractères",
@@ -210,14 +206,23 @@ async fn test_create_post_validation_nested_tag_too_long() {
         .await;
 
     response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["violations"][0]["field"], "metadata.tags[0].name");
+    assert_eq!(
+        body["violations"][0]["messages"][0],
+        "Tag name must be between 1 and 50 characters"
+    );
 }
 
 #[tokio::test]
 async fn test_create_post_author_not_found() {
-    let server = common::create_test_server().await;
+    let (server, db) = common::create_test_server_with_db().await;
+    let (_, token) =
+        common::create_user_with_role(&server, &db, Role::Admin, "admin", "admin@test.com").await;
 
     let response = server
         .post("/posts")
+        .authorization_bearer(&token)
         .json(&json!({
             "title": "Post sans auteur",
             "content": "Contenu du post avec au moins 10 caractères",
@@ -228,6 +233,89 @@ async fn test_create_post_author_not_found() {
     response.assert_status(StatusCode::NOT_FOUND);
 }
 
+#[tokio::test]
+async fn test_create_post_defaults_author_to_current_user() {
+    let server = common::create_test_server().await;
+    let (user_id, token) =
+        common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+
+    let response = server
+        .post("/posts")
+        .authorization_bearer(&token)
+        .json(&json!({
+            "title": "Post sans author_id",
+            "content": "Contenu du post avec au moins 10 caractères"
+        }))
+        .await;
+
+    response.assert_status(StatusCode::CREATED);
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["data"]["author"]["id"], user_id);
+}
+
+#[tokio::test]
+async fn test_create_post_forbidden_on_behalf_of_other_user() {
+    let server = common::create_test_server().await;
+    let victim_id = common::create_user(&server, "victim", "victim@test.com").await;
+    let (_, token) =
+        common::create_authenticated_user(&server, "impostor", "impostor@test.com").await;
+
+    let response = server
+        .post("/posts")
+        .authorization_bearer(&token)
+        .json(&json!({
+            "title": "Post usurpé",
+            "content": "Contenu du post avec au moins 10 caractères",
+            "author_id": victim_id
+        }))
+        .await;
+
+    response.assert_status(StatusCode::FORBIDDEN);
+
+    // Aucun post n'a été créé
+    let list: serde_json::Value = server.get("/posts").await.json();
+    assert_eq!(list["meta"]["total"], 0);
+}
+
+#[tokio::test]
+async fn test_create_post_admin_on_behalf_of_other_user() {
+    let (server, db) = common::create_test_server_with_db().await;
+    let author_id = common::create_user(&server, "author", "author@test.com").await;
+    let (_, token) =
+        common::create_user_with_role(&server, &db, Role::Admin, "admin", "admin@test.com").await;
+
+    let response = server
+        .post("/posts")
+        .authorization_bearer(&token)
+        .json(&json!({
+            "title": "Post pour un auteur",
+            "content": "Contenu du post avec au moins 10 caractères",
+            "author_id": author_id
+        }))
+        .await;
+
+    response.assert_status(StatusCode::CREATED);
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["data"]["author"]["id"], author_id);
+}
+
+#[tokio::test]
+async fn test_create_post_requires_authentication() {
+    let server = common::create_test_server().await;
+    let user_id = common::create_user(&server, "anonymous", "anonymous@test.com").await;
+
+    let response = server
+        .post("/posts")
+        .json(&json!({
+            "title": "Post anonyme",
+            "content": "Contenu du post avec au moins 10 caractères",
+            "author_id": user_id
+        }))
+        .await;
+
+    response.assert_status(StatusCode::UNAUTHORIZED);
+}
+
 // ============================================================================
 // GET POST
 // ============================================================================
@@ -237,18 +325,12 @@ async fn test_get_post_success() {
     let server = common::create_test_server().await;
 
     // Créer utilisateur + post
-    let user_response = server
-        .post("/users")
-        .json(&json!({
-            "username": "reader",
-            "email": "reader@test.com"
-        }))
-        .await;
-    let user: serde_json::Value = user_response.json();
-    let user_id = user["data"]["id"].as_i64().unwrap();
+    let (user_id, token) =
+        common::create_authenticated_user(&server, "reader", "reader@test.com").await;
 
     let post_response = server
         .post("/posts")
+        .authorization_bearer(&token)
         .json(&json!({
             "title": "Post à lire",
             "content": "Contenu du post à récupérer",
@@ -290,18 +372,12 @@ async fn test_update_post_title_only() {
     let server = common::create_test_server().await;
 
     // Setup
-    let user_response = server
-        .post("/users")
-        .json(&json!({
-            "username": "editor",
-            "email": "editor@test.com"
-        }))
-        .await;
-    let user: serde_json::Value = user_response.json();
-    let user_id = user["data"]["id"].as_i64().unwrap();
+    let (user_id, token) =
+        common::create_authenticated_user(&server, "editor", "editor@test.com").await;
 
     let post_response = server
         .post("/posts")
+        .authorization_bearer(&token)
         .json(&json!({
             "title": "Titre original",
             "content": "Contenu original du post",
@@ -314,6 +390,7 @@ async fn test_update_post_title_only() {
     // Update uniquement le titre
     let response = server
         .put(&format!("/posts/{}", post_id))
+        .authorization_bearer(&token)
         .json(&json!({
             "title": "Nouveau titre modifié"
         }))
@@ -331,18 +408,12 @@ async fn test_update_post_metadata() {
     let server = common::create_test_server().await;
 
     // Setup
-    let user_response = server
-        .post("/users")
-        .json(&json!({
-            "username": "updater",
-            "email": "updater@test.com"
-        }))
-        .await;
-    let user: serde_json::Value = user_response.json();
-    let user_id = user["data"]["id"].as_i64().unwrap();
+    let (user_id, token) =
+        common::create_authenticated_user(&server, "updater", "updater@test.com").await;
 
     let post_response = server
         .post("/posts")
+        .authorization_bearer(&token)
         .json(&json!({
             "title": "Post avec metadata",
             "content": "Contenu du post avec metadata",
@@ -358,6 +429,7 @@ async fn test_update_post_metadata() {
     // Update les metadata
     let response = server
         .put(&format!("/posts/{}", post_id))
+        .authorization_bearer(&token)
         .json(&json!({
             "metadata": {
                 "tags": [
@@ -380,6 +452,74 @@ async fn test_update_post_metadata() {
     assert_eq!(body["data"]["metadata"]["seo"]["meta_title"], "Nouveau SEO title");
 }
 
+#[tokio::test]
+async fn test_update_post_forbidden_for_other_author() {
+    let server = common::create_test_server().await;
+
+    let (user_id, token) =
+        common::create_authenticated_user(&server, "owner", "owner@test.com").await;
+    let (_, other_token) =
+        common::create_authenticated_user(&server, "other", "other@test.com").await;
+
+    let post_response = server
+        .post("/posts")
+        .authorization_bearer(&token)
+        .json(&json!({
+            "title": "Post protégé",
+            "content": "Seul l'auteur peut le modifier",
+            "author_id": user_id
+        }))
+        .await;
+    let post: serde_json::Value = post_response.json();
+    let post_id = post["data"]["id"].as_i64().unwrap();
+
+    let response = server
+        .put(&format!("/posts/{}", post_id))
+        .authorization_bearer(&other_token)
+        .json(&json!({ "title": "Titre piraté" }))
+        .await;
+    response.assert_status(StatusCode::FORBIDDEN);
+
+    let response = server
+        .delete(&format!("/posts/{}", post_id))
+        .authorization_bearer(&other_token)
+        .await;
+    response.assert_status(StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_update_post_allowed_for_editor() {
+    let (server, db) = common::create_test_server_with_db().await;
+
+    let (user_id, token) =
+        common::create_authenticated_user(&server, "owner", "owner@test.com").await;
+    let (_, editor_token) =
+        common::create_user_with_role(&server, &db, Role::Editor, "editor", "editor@test.com").await;
+
+    let post_response = server
+        .post("/posts")
+        .authorization_bearer(&token)
+        .json(&json!({
+            "title": "Post à relire",
+            "content": "Un editor peut corriger ce post",
+            "author_id": user_id
+        }))
+        .await;
+    let post: serde_json::Value = post_response.json();
+    let post_id = post["data"]["id"].as_i64().unwrap();
+
+    let response = server
+        .put(&format!("/posts/{}", post_id))
+        .authorization_bearer(&editor_token)
+        .json(&json!({ "title": "Titre corrigé" }))
+        .await;
+
+    response.assert_status(StatusCode::OK);
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["data"]["title"], "Titre corrigé");
+    assert_eq!(body["data"]["author"]["id"], user_id); // Auteur inchangé
+}
+
 // ============================================================================
 // DELETE POST
 // ============================================================================
@@ -389,18 +529,12 @@ async fn test_delete_post_success() {
     let server = common::create_test_server().await;
 
     // Setup
-    let user_response = server
-        .post("/users")
-        .json(&json!({
-            "username": "deleter",
-            "email": "deleter@test.com"
-        }))
-        .await;
-    let user: serde_json::Value = user_response.json();
-    let user_id = user["data"]["id"].as_i64().unwrap();
+    let (user_id, token) =
+        common::create_authenticated_user(&server, "deleter", "deleter@test.com").await;
 
     let post_response = server
         .post("/posts")
+        .authorization_bearer(&token)
         .json(&json!({
             "title": "Post à supprimer",
             "content": "Ce post sera supprimé",
@@ -411,7 +545,10 @@ async fn test_delete_post_success() {
     let post_id = post["data"]["id"].as_i64().unwrap();
 
     // Delete
-    let response = server.delete(&format!("/posts/{}", post_id)).await;
+    let response = server
+        .delete(&format!("/posts/{}", post_id))
+        .authorization_bearer(&token)
+        .await;
     response.assert_status(StatusCode::NO_CONTENT);
 
     // Vérifier qu'il n'existe plus
@@ -422,8 +559,12 @@ async fn test_delete_post_success() {
 #[tokio::test]
 async fn test_delete_post_not_found() {
     let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "ghost", "ghost@test.com").await;
 
-    let response = server.delete("/posts/9999").await;
+    let response = server
+        .delete("/posts/9999")
+        .authorization_bearer(&token)
+        .await;
     response.assert_status(StatusCode::NOT_FOUND);
 }
 
@@ -436,20 +577,14 @@ async fn test_list_posts_with_pagination() {
     let server = common::create_test_server().await;
 
     // Créer un utilisateur
-    let user_response = server
-        .post("/users")
-        .json(&json!({
-            "username": "bulk",
-            "email": "bulk@test.com"
-        }))
-        .await;
-    let user: serde_json::Value = user_response.json();
-    let user_id = user["data"]["id"].as_i64().unwrap();
+    let (user_id, token) =
+        common::create_authenticated_user(&server, "bulk", "bulk@test.com").await;
 
     // Créer 5 posts
     for i in 1..=5 {
         server
             .post("/posts")
+            .authorization_bearer(&token)
             .json(&json!({
                 "title": format!("Post numéro {}", i),
                 "content": format!("Contenu du post numéro {}", i),
@@ -470,3 +605,1200 @@ async fn test_list_posts_with_pagination() {
     assert_eq!(body["meta"]["per_page"], 2);
     assert_eq!(body["meta"]["total_pages"], 3);
 }
+
+// ============================================================================
+// PUBLISHED / PER-AUTHOR LISTINGS
+// ============================================================================
+
+#[tokio::test]
+async fn test_list_published_posts_excludes_drafts() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+
+    for (i, published) in [true, false, true].into_iter().enumerate() {
+        server
+            .post("/posts")
+            .authorization_bearer(&token)
+            .json(&json!({
+                "title": format!("Post numéro {}", i),
+                "content": format!("Contenu du post numéro {}", i),
+                "published": published
+            }))
+            .await;
+    }
+
+    let response = server.get("/posts/published?page=1&per_page=1").await;
+
+    response.assert_status(StatusCode::OK);
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["data"].as_array().unwrap().len(), 1);
+    assert_eq!(body["data"][0]["published"], true);
+    assert_eq!(body["meta"]["total"], 2);
+    assert_eq!(body["meta"]["total_pages"], 2);
+}
+
+#[tokio::test]
+async fn test_list_user_posts_with_pagination() {
+    let server = common::create_test_server().await;
+    let (user_id, token) =
+        common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    let (_, other_token) =
+        common::create_authenticated_user(&server, "other", "other@test.com").await;
+
+    for (i, token) in [&token, &token, &token, &other_token].into_iter().enumerate() {
+        server
+            .post("/posts")
+            .authorization_bearer(token)
+            .json(&json!({
+                "title": format!("Post numéro {}", i),
+                "content": format!("Contenu du post numéro {}", i)
+            }))
+            .await;
+    }
+
+    let response = server
+        .get(&format!("/users/{}/posts?page=2&per_page=2", user_id))
+        .await;
+
+    response.assert_status(StatusCode::OK);
+    let body: serde_json::Value = response.json();
+    let posts = body["data"].as_array().unwrap();
+    assert_eq!(posts.len(), 1);
+    assert_eq!(posts[0]["author"]["id"], user_id);
+    assert_eq!(body["meta"]["total"], 3);
+    assert_eq!(body["meta"]["page"], 2);
+}
+
+#[tokio::test]
+async fn test_list_user_posts_unknown_user() {
+    let server = common::create_test_server().await;
+
+    let response = server.get("/users/9999/posts").await;
+
+    response.assert_status(StatusCode::NOT_FOUND);
+}
+
+// ============================================================================
+// FILTERS & SORT
+// ============================================================================
+
+/// Crée les posts utilisés par les tests de filtres: (titre, publié, tag)
+async fn seed_filter_posts(server: &axum_test::TestServer, token: &str) {
+    for (title, published, tag) in [
+        ("Bravo rust", true, "rust"),
+        ("Alpha draft", false, "rust"),
+        ("Charlie api", true, "api"),
+    ] {
+        server
+            .post("/posts")
+            .authorization_bearer(token)
+            .json(&json!({
+                "title": title,
+                "content": "Contenu du post avec au moins 10 caractères",
+                "published": published,
+                "metadata": { "tags": [{ "name": tag }] }
+            }))
+            .await
+            .assert_status(StatusCode::CREATED);
+    }
+}
+
+fn titles(body: &serde_json::Value) -> Vec<&str> {
+    body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|post| post["title"].as_str().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_list_posts_filter_by_published_and_tag() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    seed_filter_posts(&server, &token).await;
+
+    let body: serde_json::Value = server.get("/posts?published=true&tag=rust").await.json();
+
+    assert_eq!(titles(&body), ["Bravo rust"]);
+    assert_eq!(body["meta"]["total"], 1);
+}
+
+#[tokio::test]
+async fn test_list_posts_filter_by_author_and_date() {
+    let server = common::create_test_server().await;
+    let (user_id, token) =
+        common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    let (_, other_token) =
+        common::create_authenticated_user(&server, "other", "other@test.com").await;
+    seed_filter_posts(&server, &token).await;
+    seed_filter_posts(&server, &other_token).await;
+
+    let body: serde_json::Value = server
+        .get(&format!("/posts?author_id={}&created_after=2000-01-01T00:00:00", user_id))
+        .await
+        .json();
+    assert_eq!(body["meta"]["total"], 3);
+
+    let body: serde_json::Value = server
+        .get("/posts?created_before=2000-01-01T00:00:00")
+        .await
+        .json();
+    assert_eq!(body["meta"]["total"], 0);
+}
+
+#[tokio::test]
+async fn test_list_posts_sort_by_title() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    seed_filter_posts(&server, &token).await;
+
+    let body: serde_json::Value = server.get("/posts?sort=title").await.json();
+    assert_eq!(titles(&body), ["Alpha draft", "Bravo rust", "Charlie api"]);
+
+    let body: serde_json::Value = server.get("/posts?sort=-published,title").await.json();
+    assert_eq!(titles(&body), ["Bravo rust", "Charlie api", "Alpha draft"]);
+}
+
+#[tokio::test]
+async fn test_list_posts_rejects_unknown_sort_field() {
+    let server = common::create_test_server().await;
+
+    let response = server.get("/posts?sort=-content").await;
+
+    response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn test_list_posts_rejects_malformed_filter() {
+    let server = common::create_test_server().await;
+
+    let response = server.get("/posts?published=maybe").await;
+
+    response.assert_status(StatusCode::BAD_REQUEST);
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["violations"][0]["field"], "_query");
+}
+
+#[tokio::test]
+async fn test_malformed_pagination_and_path_are_problems() {
+    let server = common::create_test_server().await;
+
+    let response = server.get("/posts?page=abc").await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+    assert_eq!(response.header("content-type"), "application/problem+json");
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["code"], "invalid_query");
+
+    let response = server.get("/posts/search?q=rust&per_page=0").await;
+    response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["violations"][0]["field"], "per_page");
+
+    let response = server.get("/posts/abc").await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+    assert_eq!(response.header("content-type"), "application/problem+json");
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["code"], "invalid_path");
+    assert_eq!(body["violations"][0]["field"], "_path");
+}
+
+// ============================================================================
+// CURSOR PAGINATION
+// ============================================================================
+
+/// Crée `count` posts numérotés de 1 à count (le plus récent est le dernier)
+async fn seed_numbered_posts(server: &axum_test::TestServer, token: &str, count: usize) {
+    for i in 1..=count {
+        server
+            .post("/posts")
+            .authorization_bearer(token)
+            .json(&json!({
+                "title": format!("Post numéro {}", i),
+                "content": format!("Contenu du post numéro {}", i)
+            }))
+            .await
+            .assert_status(StatusCode::CREATED);
+    }
+}
+
+#[tokio::test]
+async fn test_list_posts_cursor_traversal() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "bulk", "bulk@test.com").await;
+    seed_numbered_posts(&server, &token, 5).await;
+
+    let first: serde_json::Value = server.get("/posts?cursor=&per_page=2").await.json();
+    assert_eq!(titles(&first), vec!["Post numéro 5", "Post numéro 4"]);
+    assert_eq!(first["meta"]["per_page"], 2);
+    assert!(first["meta"]["prev_cursor"].is_null());
+    assert!(first["meta"].get("total").is_none());
+
+    let next = first["meta"]["next_cursor"].as_str().unwrap();
+    let second: serde_json::Value = server
+        .get(&format!("/posts?cursor={}&per_page=2", next))
+        .await
+        .json();
+    assert_eq!(titles(&second), vec!["Post numéro 3", "Post numéro 2"]);
+
+    let next = second["meta"]["next_cursor"].as_str().unwrap();
+    let last: serde_json::Value = server
+        .get(&format!("/posts?cursor={}&per_page=2", next))
+        .await
+        .json();
+    assert_eq!(titles(&last), vec!["Post numéro 1"]);
+    assert!(last["meta"]["next_cursor"].is_null());
+
+    // Retour en arrière depuis la dernière page
+    let prev = last["meta"]["prev_cursor"].as_str().unwrap();
+    let back: serde_json::Value = server
+        .get(&format!("/posts?cursor={}&per_page=2", prev))
+        .await
+        .json();
+    assert_eq!(titles(&back), vec!["Post numéro 3", "Post numéro 2"]);
+    assert!(back["meta"]["prev_cursor"].is_string());
+    assert!(back["meta"]["next_cursor"].is_string());
+}
+
+#[tokio::test]
+async fn test_list_posts_cursor_stable_when_posts_are_inserted() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "bulk", "bulk@test.com").await;
+    seed_numbered_posts(&server, &token, 4).await;
+
+    let first: serde_json::Value = server.get("/posts?cursor=&per_page=2").await.json();
+    assert_eq!(titles(&first), vec!["Post numéro 4", "Post numéro 3"]);
+
+    // Un nouveau post pendant le parcours ne décale pas la page suivante
+    server
+        .post("/posts")
+        .authorization_bearer(&token)
+        .json(&json!({
+            "title": "Post inséré",
+            "content": "Contenu du post inséré pendant la pagination"
+        }))
+        .await
+        .assert_status(StatusCode::CREATED);
+
+    let next = first["meta"]["next_cursor"].as_str().unwrap();
+    let second: serde_json::Value = server
+        .get(&format!("/posts?cursor={}&per_page=2", next))
+        .await
+        .json();
+    assert_eq!(titles(&second), vec!["Post numéro 2", "Post numéro 1"]);
+}
+
+#[tokio::test]
+async fn test_list_user_posts_with_cursor() {
+    let server = common::create_test_server().await;
+    let (user_id, token) =
+        common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    seed_numbered_posts(&server, &token, 3).await;
+
+    let body: serde_json::Value = server
+        .get(&format!("/users/{}/posts?cursor=&per_page=2", user_id))
+        .await
+        .json();
+
+    assert_eq!(titles(&body), vec!["Post numéro 3", "Post numéro 2"]);
+    assert!(body["meta"]["next_cursor"].is_string());
+}
+
+#[tokio::test]
+async fn test_list_posts_rejects_invalid_cursor() {
+    let server = common::create_test_server().await;
+
+    let response = server.get("/posts?cursor=not-a-cursor").await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+
+    let response = server.get("/posts?cursor=&sort=title").await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+}
+
+// ============================================================================
+// N+1 QUERIES
+// ============================================================================
+
+#[tokio::test]
+async fn test_list_posts_statement_count_is_constant() {
+    let (server, _db, statements) = common::create_test_server_with_query_counter().await;
+
+    let mut list_statements = Vec::new();
+    for (round, authors) in [2, 6].into_iter().enumerate() {
+        for i in 0..authors {
+            let username = format!("author{}x{}", round, i);
+            let (_, token) = common::create_authenticated_user(
+                &server,
+                &username,
+                &format!("{}@test.com", username),
+            )
+            .await;
+            seed_numbered_posts(&server, &token, 1).await;
+        }
+
+        for path in ["/posts?per_page=20", "/posts/published?per_page=20", "/posts?cursor="] {
+            statements.store(0, Ordering::SeqCst);
+            server.get(path).await.assert_status(StatusCode::OK);
+            list_statements.push((round, path, statements.load(Ordering::SeqCst)));
+        }
+    }
+
+    // Même nombre de requêtes SQL pour 2 ou 8 posts d'auteurs différents
+    for (round, path, count) in &list_statements {
+        let (_, _, first) = list_statements
+            .iter()
+            .find(|(r, p, _)| *r == 0 && p == path)
+            .unwrap();
+        assert_eq!(count, first, "{path} issued {count} statements on round {round}");
+        assert!((1..=3).contains(count), "{path} issued {count} statements");
+    }
+}
+
+#[tokio::test]
+async fn test_list_posts_skips_post_with_missing_author() {
+    let (server, db) = common::create_test_server_with_db().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    seed_numbered_posts(&server, &token, 1).await;
+
+    insert_orphan_post(&db, "Orphelin").await;
+
+    let response = server.get("/posts").await;
+
+    response.assert_status(StatusCode::OK);
+    let body: serde_json::Value = response.json();
+    assert_eq!(titles(&body), vec!["Post numéro 1"]);
+    // Le total compte les mêmes lignes que la page
+    assert_eq!(body["meta"]["total"], 1);
+    assert_eq!(body["meta"]["total_pages"], 1);
+}
+
+/// Post orphelin (auteur supprimé hors contraintes)
+async fn insert_orphan_post(db: &sea_orm::DatabaseConnection, title: &str) {
+    db.execute_unprepared("PRAGMA foreign_keys = OFF").await.unwrap();
+    post::ActiveModel {
+        title: Set(title.to_string()),
+        slug: Set(post::slugify(title)),
+        content: Set("Contenu du post orphelin".to_string()),
+        author_id: Set(9999),
+        metadata: Set(json!({})),
+        published: Set(true),
+        status: Set(PostStatus::Published),
+        publish_at: Set(None),
+        created_at: Set(chrono::Utc::now().naive_utc()),
+        updated_at: Set(None),
+        version: Set(1),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap();
+    db.execute_unprepared("PRAGMA foreign_keys = ON").await.unwrap();
+}
+
+// ============================================================================
+// FULL-TEXT SEARCH
+// ============================================================================
+
+#[tokio::test]
+async fn test_search_posts_ranks_and_highlights() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+
+    for (title, content, tag, keyword) in [
+        ("Découvrir Rust", "Un langage système moderne", "langage", "systeme"),
+        ("Notes diverses", "On y parle un peu de rust en passant", "divers", "notes"),
+        ("Cuisine", "Recette de la tarte aux pommes", "cuisine", "dessert"),
+        ("Outils", "Présentation de cargo", "rust", "build"),
+    ] {
+        server
+            .post("/posts")
+            .authorization_bearer(&token)
+            .json(&json!({
+                "title": title,
+                "content": content,
+                "metadata": {
+                    "tags": [{ "name": tag }],
+                    "seo": { "keywords": [keyword] }
+                }
+            }))
+            .await
+            .assert_status(StatusCode::CREATED);
+    }
+
+    let response = server.get("/posts/search?q=rust").await;
+
+    response.assert_status(StatusCode::OK);
+    let body: serde_json::Value = response.json();
+    let results = titles(&body);
+    assert_eq!(results.len(), 3);
+    // Le titre pèse plus que le contenu
+    assert_eq!(results[0], "Découvrir Rust");
+    assert_eq!(body["meta"]["total"], 3);
+    let scores: Vec<f64> = body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|post| post["score"].as_f64().unwrap())
+        .collect();
+    assert!(scores[0] > scores[2]);
+    assert!(body["data"][0]["snippet"].as_str().unwrap().contains("<mark>Rust</mark>"));
+    assert_eq!(body["data"][0]["author"]["username"], "writer");
+
+    // Mots-clés SEO, préfixes et accents
+    let body: serde_json::Value = server.get("/posts/search?q=dessert").await.json();
+    assert_eq!(titles(&body), vec!["Cuisine"]);
+    let body: serde_json::Value = server.get("/posts/search?q=decouv").await.json();
+    assert_eq!(titles(&body), vec!["Découvrir Rust"]);
+}
+
+#[tokio::test]
+async fn test_search_posts_follows_updates_and_deletes() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+
+    let response = server
+        .post("/posts")
+        .authorization_bearer(&token)
+        .json(&json!({
+            "title": "Brouillon",
+            "content": "Contenu du post avec au moins 10 caractères"
+        }))
+        .await;
+    let post_id = response.json::<serde_json::Value>()["data"]["id"].as_i64().unwrap();
+
+    server
+        .put(&format!("/posts/{}", post_id))
+        .authorization_bearer(&token)
+        .json(&json!({ "title": "Kubernetes en production" }))
+        .await
+        .assert_status(StatusCode::OK);
+
+    let body: serde_json::Value = server.get("/posts/search?q=kubernetes").await.json();
+    assert_eq!(titles(&body), vec!["Kubernetes en production"]);
+    let body: serde_json::Value = server.get("/posts/search?q=brouillon").await.json();
+    assert!(titles(&body).is_empty());
+
+    server
+        .delete(&format!("/posts/{}", post_id))
+        .authorization_bearer(&token)
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+
+    let body: serde_json::Value = server.get("/posts/search?q=kubernetes").await.json();
+    assert!(titles(&body).is_empty());
+}
+
+#[tokio::test]
+async fn test_search_snippet_escapes_post_content() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    create_post_json(
+        &server,
+        &token,
+        json!({
+            "title": "Injection",
+            "content": "Avant <script>alert('rust')</script> après <b>rust</b>"
+        }),
+    )
+    .await;
+
+    let body: serde_json::Value = server.get("/posts/search?q=rust").await.json();
+
+    let snippet = body["data"][0]["snippet"].as_str().unwrap();
+    assert!(!snippet.contains("<script>"));
+    assert!(!snippet.contains("<b>"));
+    assert!(snippet.contains("&lt;script&gt;alert(&#x27;<mark>rust</mark>&#x27;)&lt;/script&gt;"));
+    assert!(snippet.contains("&lt;b&gt;<mark>rust</mark>&lt;/b&gt;"));
+}
+
+#[tokio::test]
+async fn test_search_posts_skips_post_with_missing_author() {
+    let (server, db) = common::create_test_server_with_db().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    create_post_json(
+        &server,
+        &token,
+        json!({ "title": "Kubernetes en production", "content": "Contenu du post" }),
+    )
+    .await;
+    insert_orphan_post(&db, "Kubernetes orphelin").await;
+
+    let response = server.get("/posts/search?q=kubernetes").await;
+
+    response.assert_status(StatusCode::OK);
+    let body: serde_json::Value = response.json();
+    assert_eq!(titles(&body), vec!["Kubernetes en production"]);
+    assert_eq!(body["meta"]["total"], 1);
+    assert_eq!(body["meta"]["total_pages"], 1);
+}
+
+#[tokio::test]
+async fn test_search_posts_rejects_empty_query() {
+    let server = common::create_test_server().await;
+
+    server.get("/posts/search").await.assert_status(StatusCode::BAD_REQUEST);
+    server
+        .get("/posts/search?q=")
+        .await
+        .assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    // Syntaxe FTS5 neutralisée: pas d'erreur SQL
+    server
+        .get("/posts/search?q=%22*%20-")
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+    server
+        .get("/posts/search?q=title%3A%22x%22%20OR%20NEAR(")
+        .await
+        .assert_status(StatusCode::OK);
+}
+
+// ============================================================================
+// SLUGS
+// ============================================================================
+
+/// Crée un post et retourne le body JSON de la réponse
+async fn create_post_json(
+    server: &axum_test::TestServer,
+    token: &str,
+    payload: serde_json::Value,
+) -> serde_json::Value {
+    let response = server.post("/posts").authorization_bearer(token).json(&payload).await;
+    response.assert_status(StatusCode::CREATED);
+    response.json()
+}
+
+#[tokio::test]
+async fn test_create_post_generates_unique_slugs() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+
+    let mut slugs = Vec::new();
+    for _ in 0..3 {
+        let body = create_post_json(
+            &server,
+            &token,
+            json!({ "title": "Découvrir Rust !", "content": "Contenu du post sur Rust" }),
+        )
+        .await;
+        slugs.push(body["data"]["slug"].as_str().unwrap().to_string());
+    }
+
+    assert_eq!(slugs, vec!["decouvrir-rust", "decouvrir-rust-2", "decouvrir-rust-3"]);
+
+    let response = server.get("/posts/by-slug/decouvrir-rust-2").await;
+    response.assert_status(StatusCode::OK);
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["data"]["slug"], "decouvrir-rust-2");
+
+    let list: serde_json::Value = server.get("/posts?per_page=1").await.json();
+    assert_eq!(list["data"][0]["slug"], "decouvrir-rust-3");
+}
+
+#[tokio::test]
+async fn test_suffixed_slug_of_long_title_stays_within_max_length() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    let title = "Titre très long ".repeat(12);
+
+    let mut slugs = Vec::new();
+    for _ in 0..2 {
+        let body = create_post_json(
+            &server,
+            &token,
+            json!({ "title": title, "content": "Contenu du post au long titre" }),
+        )
+        .await;
+        slugs.push(body["data"]["slug"].as_str().unwrap().to_string());
+    }
+
+    assert!(slugs.iter().all(|slug| slug.len() <= 100));
+    assert!(slugs[1].ends_with("-2"));
+    assert_ne!(slugs[0], slugs[1]);
+}
+
+#[tokio::test]
+async fn test_migration_backfills_long_duplicate_slugs_within_max_length() {
+    let db = Database::connect("sqlite::memory:").await.unwrap();
+
+    // Schéma d'avant la colonne slug (10 premières migrations)
+    Migrator::up(&db, Some(10)).await.unwrap();
+    let title = "Titre très long ".repeat(12);
+    db.execute_unprepared(&format!(
+        "INSERT INTO users (username, email, password_hash, role, created_at) \
+         VALUES ('legacy', 'legacy@test.com', 'x', 'user', '2024-01-01 00:00:00'); \
+         INSERT INTO posts (title, content, author_id, metadata, published, created_at) \
+         VALUES ('{title}', 'Contenu', 1, '{{}}', 1, '2024-01-01 00:00:00'), \
+         ('{title}', 'Contenu', 1, '{{}}', 1, '2024-01-02 00:00:00');"
+    ))
+    .await
+    .unwrap();
+
+    Migrator::up(&db, None).await.unwrap();
+
+    let rows = db
+        .query_all(sea_orm::Statement::from_string(
+            db.get_database_backend(),
+            "SELECT slug FROM posts ORDER BY id",
+        ))
+        .await
+        .unwrap();
+    let slugs: Vec<String> = rows.iter().map(|row| row.try_get("", "slug").unwrap()).collect();
+
+    assert!(slugs.iter().all(|slug| post::is_valid_slug(slug)));
+    assert!(slugs[1].ends_with("-2"));
+    assert_ne!(slugs[0], slugs[1]);
+}
+
+#[tokio::test]
+async fn test_create_post_with_explicit_slug() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+
+    let body = create_post_json(
+        &server,
+        &token,
+        json!({ "title": "Mon article", "content": "Contenu du post", "slug": "mon-slug" }),
+    )
+    .await;
+    assert_eq!(body["data"]["slug"], "mon-slug");
+
+    // Slug explicite déjà pris: pas de suffixe automatique
+    let response = server
+        .post("/posts")
+        .authorization_bearer(&token)
+        .json(&json!({ "title": "Autre article", "content": "Contenu du post", "slug": "mon-slug" }))
+        .await;
+    response.assert_status(StatusCode::CONFLICT);
+
+    let response = server
+        .post("/posts")
+        .authorization_bearer(&token)
+        .json(&json!({ "title": "Autre article", "content": "Contenu du post", "slug": "Mon Slug" }))
+        .await;
+    response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn test_title_change_keeps_old_slug_as_redirect() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+
+    let body = create_post_json(
+        &server,
+        &token,
+        json!({ "title": "Premier titre", "content": "Contenu du post" }),
+    )
+    .await;
+    let post_id = body["data"]["id"].as_i64().unwrap();
+
+    let response = server
+        .put(&format!("/posts/{}", post_id))
+        .authorization_bearer(&token)
+        .json(&json!({ "title": "Second titre" }))
+        .await;
+    response.assert_status(StatusCode::OK);
+    assert_eq!(response.json::<serde_json::Value>()["data"]["slug"], "second-titre");
+
+    let response = server.get("/posts/by-slug/premier-titre").await;
+    response.assert_status(StatusCode::MOVED_PERMANENTLY);
+    assert_eq!(response.header("location"), "/posts/by-slug/second-titre");
+
+    // L'ancien slug reste réservé au post
+    let body = create_post_json(
+        &server,
+        &token,
+        json!({ "title": "Premier titre", "content": "Contenu d'un autre post" }),
+    )
+    .await;
+    assert_eq!(body["data"]["slug"], "premier-titre-2");
+
+    // Revenir à l'ancien titre reprend l'ancien slug
+    let response = server
+        .put(&format!("/posts/{}", post_id))
+        .authorization_bearer(&token)
+        .json(&json!({ "title": "Premier titre" }))
+        .await;
+    assert_eq!(response.json::<serde_json::Value>()["data"]["slug"], "premier-titre");
+    server
+        .get("/posts/by-slug/premier-titre")
+        .await
+        .assert_status(StatusCode::OK);
+    let response = server.get("/posts/by-slug/second-titre").await;
+    response.assert_status(StatusCode::MOVED_PERMANENTLY);
+}
+
+#[tokio::test]
+async fn test_slug_redirect_is_dated_by_the_clock() {
+    let (server, db, clock) = common::create_test_server_with_clock().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    let body = create_post_json(
+        &server,
+        &token,
+        json!({ "title": "Premier titre", "content": "Contenu du post" }),
+    )
+    .await;
+    let post_id = body["data"]["id"].as_i64().unwrap();
+
+    clock.advance(chrono::TimeDelta::days(3));
+    server
+        .put(&format!("/posts/{}", post_id))
+        .authorization_bearer(&token)
+        .json(&json!({ "title": "Second titre" }))
+        .await
+        .assert_status(StatusCode::OK);
+
+    let redirect = post_slug_redirect::Entity::find_by_id("premier-titre")
+        .one(&db)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(redirect.created_at, clock.now());
+}
+
+#[tokio::test]
+async fn test_get_post_by_unknown_slug() {
+    let server = common::create_test_server().await;
+
+    let response = server.get("/posts/by-slug/inconnu").await;
+
+    response.assert_status(StatusCode::NOT_FOUND);
+}
+
+// ============================================================================
+// SCHEDULED PUBLISHING
+// ============================================================================
+
+/// Format attendu par les DTOs pour `publish_at`
+fn publish_at(at: chrono::NaiveDateTime) -> String {
+    at.format("%Y-%m-%dT%H:%M:%S").to_string()
+}
+
+#[tokio::test]
+async fn test_scheduled_post_is_published_when_due() {
+    let (server, db, clock) = common::create_test_server_with_clock().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    let due_at = publish_at(clock.now() + chrono::TimeDelta::hours(2));
+
+    let body = create_post_json(
+        &server,
+        &token,
+        json!({ "title": "Annonce", "content": "Contenu programmé", "publish_at": due_at }),
+    )
+    .await;
+    let post_id = body["data"]["id"].as_i64().unwrap();
+    assert_eq!(body["data"]["status"], "scheduled");
+    assert_eq!(body["data"]["published"], false);
+
+    let publisher = ScheduledPublisher::new(db, Arc::new(clock.clone()));
+
+    // Pas encore l'heure
+    clock.advance(chrono::TimeDelta::hours(1));
+    assert!(publisher.publish_due().await.unwrap().is_empty());
+    let list: serde_json::Value = server.get("/posts/published").await.json();
+    assert_eq!(list["meta"]["total"], 0);
+
+    clock.advance(chrono::TimeDelta::hours(1));
+    assert_eq!(publisher.publish_due().await.unwrap(), vec![post_id as i32]);
+    assert!(publisher.publish_due().await.unwrap().is_empty());
+
+    let list: serde_json::Value = server.get("/posts/published").await.json();
+    assert_eq!(list["meta"]["total"], 1);
+    assert_eq!(list["data"][0]["status"], "published");
+    assert_eq!(list["data"][0]["publish_at"], due_at);
+}
+
+#[tokio::test]
+async fn test_publisher_reports_only_the_posts_it_published() {
+    let (server, db, clock) = common::create_test_server_with_clock().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    let due_at = publish_at(clock.now() + chrono::TimeDelta::minutes(10));
+
+    let mut ids = Vec::new();
+    for title in ["Annonce", "Autre annonce"] {
+        let body = create_post_json(
+            &server,
+            &token,
+            json!({ "title": title, "content": "Contenu programmé", "publish_at": due_at }),
+        )
+        .await;
+        ids.push(body["data"]["id"].as_i64().unwrap() as i32);
+    }
+    clock.advance(chrono::TimeDelta::hours(1));
+
+    // Archivé une fois échu, avant le passage du publisher
+    post::ActiveModel {
+        id: Set(ids[1]),
+        status: Set(PostStatus::Archived),
+        ..Default::default()
+    }
+    .update(&db)
+    .await
+    .unwrap();
+
+    let publisher = ScheduledPublisher::new(db.clone(), Arc::new(clock.clone()));
+    assert_eq!(publisher.publish_due().await.unwrap(), vec![ids[0]]);
+
+    let archived = post::Entity::find_by_id(ids[1]).one(&db).await.unwrap().unwrap();
+    assert_eq!(archived.status, PostStatus::Archived);
+}
+
+#[tokio::test]
+async fn test_unscheduled_post_is_not_published() {
+    let (server, db, clock) = common::create_test_server_with_clock().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    let due_at = publish_at(clock.now() + chrono::TimeDelta::minutes(10));
+
+    let body = create_post_json(
+        &server,
+        &token,
+        json!({ "title": "Annonce", "content": "Contenu programmé", "publish_at": due_at }),
+    )
+    .await;
+    let post_id = body["data"]["id"].as_i64().unwrap();
+
+    // Repasse en brouillon avant l'échéance
+    let response = server
+        .put(&format!("/posts/{}", post_id))
+        .authorization_bearer(&token)
+        .json(&json!({ "status": "draft" }))
+        .await;
+    response.assert_status(StatusCode::OK);
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["data"]["status"], "draft");
+    assert!(body["data"]["publish_at"].is_null());
+
+    clock.advance(chrono::TimeDelta::hours(1));
+    let publisher = ScheduledPublisher::new(db, Arc::new(clock.clone()));
+    assert!(publisher.publish_due().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_create_post_rejects_inconsistent_schedule() {
+    let (server, _db, clock) = common::create_test_server_with_clock().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    let future = publish_at(clock.now() + chrono::TimeDelta::days(1));
+
+    for schedule in [
+        json!({ "status": "scheduled" }),
+        json!({ "status": "published", "publish_at": future }),
+        json!({ "status": "draft", "published": true }),
+    ] {
+        let mut payload = json!({ "title": "Annonce", "content": "Contenu du post" });
+        payload.as_object_mut().unwrap().extend(schedule.as_object().unwrap().clone());
+
+        let response = server.post("/posts").authorization_bearer(&token).json(&payload).await;
+        response.assert_status(StatusCode::BAD_REQUEST);
+    }
+}
+
+#[tokio::test]
+async fn test_background_publisher_publishes_due_posts() {
+    let (server, db, clock) = common::create_test_server_with_clock().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    let due_at = publish_at(clock.now() + chrono::TimeDelta::minutes(5));
+
+    create_post_json(
+        &server,
+        &token,
+        json!({ "title": "Annonce", "content": "Contenu programmé", "publish_at": due_at }),
+    )
+    .await;
+
+    let shutdown = Shutdown::new();
+    let handle = ScheduledPublisher::new(db, Arc::new(clock.clone()))
+        .spawn(std::time::Duration::from_millis(10), shutdown.clone());
+    clock.advance(chrono::TimeDelta::minutes(5));
+
+    let mut total = serde_json::Value::Null;
+    for _ in 0..100 {
+        let list: serde_json::Value = server.get("/posts/published").await.json();
+        total = list["meta"]["total"].clone();
+        if total == 1 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    assert_eq!(total, 1);
+
+    // Arrêt coopératif: la tâche se termine d'elle-même
+    shutdown.trigger();
+    tokio::time::timeout(std::time::Duration::from_secs(1), handle)
+        .await
+        .expect("publisher should stop on shutdown")
+        .unwrap();
+}
+
+// ============================================================================
+// ETAG / IF-MATCH
+// ============================================================================
+
+#[tokio::test]
+async fn test_concurrent_post_updates_do_not_clobber() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    let body = create_post_json(
+        &server,
+        &token,
+        json!({ "title": "Article partagé", "content": "Contenu du post" }),
+    )
+    .await;
+    let path = format!("/posts/{}", body["data"]["id"]);
+
+    // Deux éditeurs lisent la même version
+    let etag = server.get(&path).await.header("etag").to_str().unwrap().to_string();
+
+    let response = server
+        .put(&path)
+        .authorization_bearer(&token)
+        .add_header("If-Match", &etag)
+        .json(&json!({ "title": "Version du premier éditeur" }))
+        .await;
+    response.assert_status(StatusCode::OK);
+    let new_etag = response.header("etag").to_str().unwrap().to_string();
+    assert_ne!(new_etag, etag);
+
+    let response = server
+        .put(&path)
+        .authorization_bearer(&token)
+        .add_header("If-Match", &etag)
+        .json(&json!({ "title": "Version du second éditeur" }))
+        .await;
+    response.assert_status(StatusCode::PRECONDITION_FAILED);
+
+    let body: serde_json::Value = server.get(&path).await.json();
+    assert_eq!(body["data"]["title"], "Version du premier éditeur");
+
+    server
+        .delete(&path)
+        .authorization_bearer(&token)
+        .add_header("If-Match", &etag)
+        .await
+        .assert_status(StatusCode::PRECONDITION_FAILED);
+    server
+        .delete(&path)
+        .authorization_bearer(&token)
+        .add_header("If-Match", &new_etag)
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn test_get_post_if_none_match() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    let body = create_post_json(
+        &server,
+        &token,
+        json!({ "title": "Article en cache", "content": "Contenu du post" }),
+    )
+    .await;
+    let path = format!("/posts/{}", body["data"]["id"]);
+
+    let response = server.get(&path).add_header("If-None-Match", "W/\"1\"").await;
+    response.assert_status(StatusCode::NOT_MODIFIED);
+    assert_eq!(response.text(), "");
+
+    let response = server.get(&path).add_header("If-None-Match", "\"0\", \"7\"").await;
+    response.assert_status(StatusCode::OK);
+    assert_eq!(response.header("etag"), "\"1\"");
+
+    // Sans If-Match, la modification reste inconditionnelle
+    server
+        .put(&path)
+        .authorization_bearer(&token)
+        .json(&json!({ "content": "Contenu modifié" }))
+        .await
+        .assert_status(StatusCode::OK);
+    server
+        .get(&path)
+        .add_header("If-None-Match", "\"1\"")
+        .await
+        .assert_status(StatusCode::OK);
+}
+
+// ============================================================================
+// PATCH (JSON Merge Patch / JSON Patch)
+// ============================================================================
+
+#[tokio::test]
+async fn test_merge_patch_post_keeps_other_metadata() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    let body = create_post_json(
+        &server,
+        &token,
+        json!({
+            "title": "Article à patcher",
+            "content": "Contenu du post",
+            "metadata": {
+                "tags": [{ "name": "rust" }],
+                "seo": { "meta_title": "Titre SEO", "keywords": ["api"] }
+            }
+        }),
+    )
+    .await;
+    let path = format!("/posts/{}", body["data"]["id"]);
+
+    let response = server
+        .patch(&path)
+        .authorization_bearer(&token)
+        .json(&json!({
+            "metadata": {
+                "seo": { "meta_title": null },
+                "settings": { "featured": true }
+            }
+        }))
+        .content_type("application/merge-patch+json")
+        .await;
+
+    response.assert_status(StatusCode::OK);
+    assert_eq!(response.header("etag"), "\"2\"");
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["data"]["title"], "Article à patcher");
+    assert_eq!(body["data"]["metadata"]["tags"][0]["name"], "rust");
+    assert!(body["data"]["metadata"]["seo"]["meta_title"].is_null());
+    assert_eq!(body["data"]["metadata"]["seo"]["keywords"][0], "api");
+    assert_eq!(body["data"]["metadata"]["settings"]["featured"], true);
+}
+
+#[tokio::test]
+async fn test_json_patch_post_operations() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    let body = create_post_json(
+        &server,
+        &token,
+        json!({
+            "title": "Article à patcher",
+            "content": "Contenu du post",
+            "metadata": { "tags": [{ "name": "rust" }] }
+        }),
+    )
+    .await;
+    let path = format!("/posts/{}", body["data"]["id"]);
+
+    let response = server
+        .patch(&path)
+        .authorization_bearer(&token)
+        .json(&json!([
+            { "op": "test", "path": "/title", "value": "Article à patcher" },
+            { "op": "add", "path": "/metadata/tags/-", "value": { "name": "api" } }
+        ]))
+        .content_type("application/json-patch+json")
+        .await;
+
+    response.assert_status(StatusCode::OK);
+    let body: serde_json::Value = response.json();
+    let tags = body["data"]["metadata"]["tags"].as_array().unwrap();
+    assert_eq!(tags.len(), 2);
+    assert_eq!(tags[1]["name"], "api");
+
+    // Une opération `test` qui échoue n'applique rien
+    let response = server
+        .patch(&path)
+        .authorization_bearer(&token)
+        .json(&json!([
+            { "op": "test", "path": "/title", "value": "Autre titre" },
+            { "op": "replace", "path": "/title", "value": "Titre remplacé" }
+        ]))
+        .content_type("application/json-patch+json")
+        .await;
+    response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    let body: serde_json::Value = server.get(&path).await.json();
+    assert_eq!(body["data"]["title"], "Article à patcher");
+}
+
+#[tokio::test]
+async fn test_second_patch_with_same_etag_is_rejected() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    let body = create_post_json(
+        &server,
+        &token,
+        json!({ "title": "Article à patcher", "content": "Contenu du post" }),
+    )
+    .await;
+    let path = format!("/posts/{}", body["data"]["id"]);
+    let etag = server.get(&path).await.header("etag").to_str().unwrap().to_string();
+
+    // Deux clients patchent à partir de la même lecture: le second perd
+    let first = server
+        .patch(&path)
+        .authorization_bearer(&token)
+        .add_header("If-Match", &etag)
+        .json(&json!({ "content": "Contenu du premier client" }))
+        .content_type("application/merge-patch+json")
+        .await;
+    first.assert_status(StatusCode::OK);
+
+    let second = server
+        .patch(&path)
+        .authorization_bearer(&token)
+        .add_header("If-Match", &etag)
+        .json(&json!([{ "op": "replace", "path": "/content", "value": "Contenu du second client" }]))
+        .content_type("application/json-patch+json")
+        .await;
+    second.assert_status(StatusCode::PRECONDITION_FAILED);
+
+    let body: serde_json::Value = server.get(&path).await.json();
+    assert_eq!(body["data"]["content"], "Contenu du premier client");
+}
+
+#[tokio::test]
+async fn test_patch_post_is_validated_after_applying() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    let (_, other) = common::create_authenticated_user(&server, "other", "other@test.com").await;
+    let body = create_post_json(
+        &server,
+        &token,
+        json!({ "title": "Article à patcher", "content": "Contenu du post" }),
+    )
+    .await;
+    let path = format!("/posts/{}", body["data"]["id"]);
+
+    // Même règle que le PUT: titre de 3 caractères minimum
+    let response = server
+        .patch(&path)
+        .authorization_bearer(&token)
+        .json(&json!({ "title": "ab" }))
+        .content_type("application/merge-patch+json")
+        .await;
+    response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["violations"][0]["field"], "title");
+
+    // Un champ requis ne peut pas être supprimé, un champ inconnu pas ajouté
+    for patch in [json!({ "title": null }), json!({ "author_id": 2 })] {
+        server
+            .patch(&path)
+            .authorization_bearer(&token)
+            .json(&patch)
+            .content_type("application/merge-patch+json")
+            .await
+            .assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    server
+        .patch(&path)
+        .authorization_bearer(&token)
+        .json(&json!({ "op": "replace" }))
+        .content_type("application/json-patch+json")
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+    server
+        .patch(&path)
+        .authorization_bearer(&other)
+        .json(&json!({ "content": "Contenu d'un autre" }))
+        .content_type("application/merge-patch+json")
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+    server
+        .patch(&path)
+        .authorization_bearer(&token)
+        .add_header("If-Match", "\"7\"")
+        .json(&json!({ "content": "Contenu modifié" }))
+        .content_type("application/merge-patch+json")
+        .await
+        .assert_status(StatusCode::PRECONDITION_FAILED);
+}
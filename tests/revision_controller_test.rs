@@ -0,0 +1,223 @@
+// tests/revision_controller_test.rs
+// Tests pour le RevisionController (historique, diff et restauration des posts)
+
+use axum::http::StatusCode;
+use axum_test::TestServer;
+use serde_json::{json, Value};
+
+mod common;
+
+/// Crée un post et retourne son ID
+async fn create_post(server: &TestServer, token: &str) -> i64 {
+    let response = server
+        .post("/posts")
+        .authorization_bearer(token)
+        .json(&json!({
+            "title": "Premier titre",
+            "content": "Introduction\nParagraphe original\nConclusion",
+            "metadata": { "tags": [{ "name": "rust" }] }
+        }))
+        .await;
+
+    response.assert_status(StatusCode::CREATED);
+    let body: Value = response.json();
+    body["data"]["id"].as_i64().unwrap()
+}
+
+/// Modifie un post via PUT /posts/:id
+async fn update_post(server: &TestServer, token: &str, post_id: i64, payload: Value) {
+    server
+        .put(&format!("/posts/{}", post_id))
+        .authorization_bearer(token)
+        .json(&payload)
+        .await
+        .assert_status(StatusCode::OK);
+}
+
+/// Lignes du diff au format "op:ligne"
+fn diff_lines(diff: &Value) -> Vec<String> {
+    diff.as_array()
+        .unwrap()
+        .iter()
+        .map(|line| format!("{}:{}", line["op"].as_str().unwrap(), line["line"].as_str().unwrap()))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_update_records_revisions() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    let post_id = create_post(&server, &token).await;
+
+    let body: Value = server
+        .get(&format!("/posts/{}/revisions", post_id))
+        .authorization_bearer(&token)
+        .await
+        .json();
+    assert_eq!(body["meta"]["total"], 0);
+
+    update_post(&server, &token, post_id, json!({ "title": "Deuxième titre" })).await;
+    update_post(&server, &token, post_id, json!({ "content": "Nouveau contenu complet" })).await;
+    // Changement de statut seul: pas de révision
+    update_post(&server, &token, post_id, json!({ "published": true })).await;
+
+    let response = server
+        .get(&format!("/posts/{}/revisions", post_id))
+        .authorization_bearer(&token)
+        .await;
+    response.assert_status(StatusCode::OK);
+    let body: Value = response.json();
+
+    assert_eq!(body["meta"]["total"], 2);
+    assert_eq!(body["data"][0]["revision"], 2);
+    assert_eq!(body["data"][0]["title"], "Deuxième titre");
+    assert_eq!(body["data"][0]["edited_by"]["username"], "writer");
+    assert_eq!(body["data"][1]["revision"], 1);
+    assert_eq!(body["data"][1]["title"], "Premier titre");
+}
+
+#[tokio::test]
+async fn test_get_revision_with_line_diff() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    let post_id = create_post(&server, &token).await;
+
+    update_post(
+        &server,
+        &token,
+        post_id,
+        json!({ "content": "Introduction\nParagraphe réécrit\nConclusion\nPost-scriptum" }),
+    )
+    .await;
+
+    let response = server
+        .get(&format!("/posts/{}/revisions/1", post_id))
+        .authorization_bearer(&token)
+        .await;
+    response.assert_status(StatusCode::OK);
+    let body: Value = response.json();
+
+    assert_eq!(body["data"]["revision"], 1);
+    assert_eq!(body["data"]["content"], "Introduction\nParagraphe original\nConclusion");
+    assert_eq!(body["data"]["metadata"]["tags"][0]["name"], "rust");
+    assert_eq!(diff_lines(&body["data"]["diff"]["title"]), vec!["equal:Premier titre"]);
+    assert_eq!(
+        diff_lines(&body["data"]["diff"]["content"]),
+        vec![
+            "equal:Introduction",
+            "removed:Paragraphe original",
+            "added:Paragraphe réécrit",
+            "equal:Conclusion",
+            "added:Post-scriptum",
+        ]
+    );
+    assert!(diff_lines(&body["data"]["diff"]["metadata"])
+        .iter()
+        .all(|line| line.starts_with("equal:")));
+
+    let response = server
+        .get(&format!("/posts/{}/revisions/2", post_id))
+        .authorization_bearer(&token)
+        .await;
+    response.assert_status(StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_restore_revision() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    let post_id = create_post(&server, &token).await;
+
+    update_post(
+        &server,
+        &token,
+        post_id,
+        json!({
+            "title": "Titre erroné",
+            "content": "Contenu effacé par erreur",
+            "metadata": { "tags": [{ "name": "oops" }] }
+        }),
+    )
+    .await;
+
+    let response = server
+        .post(&format!("/posts/{}/revisions/1/restore", post_id))
+        .authorization_bearer(&token)
+        .await;
+    response.assert_status(StatusCode::OK);
+    let body: Value = response.json();
+
+    assert_eq!(body["data"]["title"], "Premier titre");
+    assert_eq!(body["data"]["slug"], "premier-titre");
+    assert_eq!(body["data"]["content"], "Introduction\nParagraphe original\nConclusion");
+    assert_eq!(body["data"]["metadata"]["tags"][0]["name"], "rust");
+
+    // La version erronée est conservée: la restauration peut être annulée
+    let body: Value = server
+        .get(&format!("/posts/{}/revisions", post_id))
+        .authorization_bearer(&token)
+        .await
+        .json();
+    assert_eq!(body["meta"]["total"], 2);
+    assert_eq!(body["data"][0]["title"], "Titre erroné");
+
+    // Les tags normalisés suivent la restauration
+    let tags: Value = server.get("/tags").await.json();
+    assert_eq!(tags["data"].as_array().unwrap().len(), 1);
+    assert_eq!(tags["data"][0]["name"], "rust");
+}
+
+#[tokio::test]
+async fn test_restore_revision_rejects_stale_if_match() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    let post_id = create_post(&server, &token).await;
+    let stale_etag = server
+        .get(&format!("/posts/{}", post_id))
+        .await
+        .header("etag")
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    update_post(&server, &token, post_id, json!({ "title": "Deuxième titre" })).await;
+
+    let response = server
+        .post(&format!("/posts/{}/revisions/1/restore", post_id))
+        .authorization_bearer(&token)
+        .add_header("If-Match", &stale_etag)
+        .await;
+    response.assert_status(StatusCode::PRECONDITION_FAILED);
+
+    let body: Value = server.get(&format!("/posts/{}", post_id)).await.json();
+    assert_eq!(body["data"]["title"], "Deuxième titre");
+}
+
+#[tokio::test]
+async fn test_revisions_require_edit_rights() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+    let (_, other) = common::create_authenticated_user(&server, "other", "other@test.com").await;
+    let post_id = create_post(&server, &token).await;
+    update_post(&server, &token, post_id, json!({ "title": "Deuxième titre" })).await;
+
+    server
+        .get(&format!("/posts/{}/revisions", post_id))
+        .await
+        .assert_status(StatusCode::UNAUTHORIZED);
+    server
+        .get(&format!("/posts/{}/revisions/1", post_id))
+        .authorization_bearer(&other)
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+    server
+        .post(&format!("/posts/{}/revisions/1/restore", post_id))
+        .authorization_bearer(&other)
+        .await
+        .assert_status(StatusCode::FORBIDDEN);
+    server
+        .get("/posts/999/revisions")
+        .authorization_bearer(&token)
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+}
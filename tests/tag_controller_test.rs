@@ -0,0 +1,178 @@
+// tests/tag_controller_test.rs
+// Tests pour le TagController et la navigation par tag
+
+use axum::http::StatusCode;
+use axum_test::TestServer;
+use migration::Migrator;
+use sea_orm::{ConnectionTrait, Database};
+use sea_orm_migration::MigratorTrait;
+use serde_json::{json, Value};
+
+mod common;
+
+/// Crée un post avec les tags donnés (nom, couleur) et retourne son ID
+async fn create_tagged_post(
+    server: &TestServer,
+    token: &str,
+    title: &str,
+    tags: &[(&str, Option<&str>)],
+) -> i64 {
+    let tags: Vec<Value> = tags
+        .iter()
+        .map(|(name, color)| json!({ "name": name, "color": color }))
+        .collect();
+
+    let response = server
+        .post("/posts")
+        .authorization_bearer(token)
+        .json(&json!({
+            "title": title,
+            "content": "Contenu du post avec au moins 10 caractères",
+            "metadata": { "tags": tags }
+        }))
+        .await;
+
+    response.assert_status(StatusCode::CREATED);
+    let body: Value = response.json();
+    body["data"]["id"].as_i64().unwrap()
+}
+
+/// (nom, nombre de posts) des tags de GET /tags
+async fn tag_counts(server: &TestServer) -> Vec<(String, u64)> {
+    let body: Value = server.get("/tags").await.json();
+    body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|tag| {
+            let name = tag["name"].as_str().unwrap().to_string();
+            (name, tag["post_count"].as_u64().unwrap())
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_list_tags_with_counts_and_colors() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+
+    create_tagged_post(&server, &token, "Premier post", &[("rust", Some("#DEA584"))]).await;
+    create_tagged_post(&server, &token, "Second post", &[("rust", None), ("api", None)]).await;
+
+    let response = server.get("/tags").await;
+
+    response.assert_status(StatusCode::OK);
+    let body: Value = response.json();
+    assert_eq!(body["data"][0]["name"], "rust");
+    assert_eq!(body["data"][0]["color"], "#DEA584");
+    assert_eq!(body["data"][0]["post_count"], 2);
+    assert_eq!(body["data"][1]["name"], "api");
+    assert!(body["data"][1]["color"].is_null());
+    assert_eq!(body["data"][1]["post_count"], 1);
+}
+
+#[tokio::test]
+async fn test_tags_follow_post_update_and_delete() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+
+    let post_id = create_tagged_post(&server, &token, "Post tagué", &[("rust", None)]).await;
+
+    server
+        .put(&format!("/posts/{}", post_id))
+        .authorization_bearer(&token)
+        .json(&json!({ "metadata": { "tags": [{ "name": "api" }] } }))
+        .await
+        .assert_status(StatusCode::OK);
+    assert_eq!(tag_counts(&server).await, vec![("api".to_string(), 1)]);
+
+    // Sans metadata, les tags ne changent pas
+    server
+        .put(&format!("/posts/{}", post_id))
+        .authorization_bearer(&token)
+        .json(&json!({ "title": "Nouveau titre" }))
+        .await
+        .assert_status(StatusCode::OK);
+    assert_eq!(tag_counts(&server).await, vec![("api".to_string(), 1)]);
+
+    server
+        .delete(&format!("/posts/{}", post_id))
+        .authorization_bearer(&token)
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+    assert!(tag_counts(&server).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_list_tag_posts() {
+    let server = common::create_test_server().await;
+    let (_, token) = common::create_authenticated_user(&server, "writer", "writer@test.com").await;
+
+    create_tagged_post(&server, &token, "Post rust", &[("rust", None)]).await;
+    create_tagged_post(&server, &token, "Post api", &[("api", None)]).await;
+    create_tagged_post(&server, &token, "Post rust et api", &[("rust", None), ("api", None)])
+        .await;
+
+    let response = server.get("/tags/rust/posts").await;
+
+    response.assert_status(StatusCode::OK);
+    let body: Value = response.json();
+    let titles: Vec<&str> = body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|post| post["title"].as_str().unwrap())
+        .collect();
+    assert_eq!(titles, vec!["Post rust et api", "Post rust"]);
+    assert_eq!(body["meta"]["total"], 2);
+}
+
+#[tokio::test]
+async fn test_list_tag_posts_unknown_tag() {
+    let server = common::create_test_server().await;
+
+    let response = server.get("/tags/inconnu/posts").await;
+
+    response.assert_status(StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_migration_backfills_tags_from_metadata() {
+    let db = Database::connect("sqlite::memory:").await.unwrap();
+
+    // Schéma d'avant les tables de tags (7 premières migrations)
+    Migrator::up(&db, Some(7)).await.unwrap();
+    db.execute_unprepared(
+        "INSERT INTO users (username, email, password_hash, role, created_at) \
+         VALUES ('legacy', 'legacy@test.com', 'x', 'user', '2024-01-01 00:00:00'); \
+         INSERT INTO posts (title, content, author_id, metadata, published, created_at) \
+         VALUES ('Ancien post', 'Contenu', 1, \
+         '{\"tags\":[{\"name\":\"rust\",\"color\":\"#DEA584\"},{\"name\":\"api\"}]}', \
+         1, '2024-01-01 00:00:00');",
+    )
+    .await
+    .unwrap();
+
+    Migrator::up(&db, None).await.unwrap();
+
+    let rows = db
+        .query_all(sea_orm::Statement::from_string(
+            db.get_database_backend(),
+            "SELECT tags.name, tags.color FROM post_tags \
+             JOIN tags ON tags.id = post_tags.tag_id ORDER BY tags.name",
+        ))
+        .await
+        .unwrap();
+    let tags: Vec<(String, Option<String>)> = rows
+        .iter()
+        .map(|row| (row.try_get("", "name").unwrap(), row.try_get("", "color").unwrap()))
+        .collect();
+
+    assert_eq!(
+        tags,
+        vec![
+            ("api".to_string(), None),
+            ("rust".to_string(), Some("#DEA584".to_string())),
+        ]
+    );
+}
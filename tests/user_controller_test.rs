@@ -4,6 +4,7 @@
 mod common;
 
 use axum::http::StatusCode;
+use rust_api::entities::user::Role;
 use serde_json::{json, Value};
 
 // ============================================================
@@ -42,7 +43,8 @@ async fn test_list_users_returns_created_users() {
         .post("/users")
         .json(&json!({
             "username": "testuser",
-            "email": "test@example.com"
+            "email": "test@example.com",
+            "password": "S3cure-password"
         }))
         .await;
 
@@ -76,7 +78,8 @@ async fn test_list_users_with_pagination() {
             .post("/users")
             .json(&json!({
                 "username": format!("user{}", i),
-                "email": format!("user{}@example.com", i)
+                "email": format!("user{}@example.com", i),
+                "password": "S3cure-password"
             }))
             .await;
     }
@@ -106,6 +109,55 @@ async fn test_list_users_with_pagination() {
     assert_eq!(body["meta"]["page"], 3);
 }
 
+/// Test GET /users filters by role and username
+#[tokio::test]
+async fn test_list_users_with_filters() {
+    let (server, db) = common::create_test_server_with_db().await;
+    common::create_user(&server, "john", "john@example.com").await;
+    common::create_user(&server, "johnny", "johnny@example.com").await;
+    common::create_user_with_role(&server, &db, Role::Editor, "jane", "jane@example.com").await;
+
+    let body: Value = server.get("/users?username=john").await.json();
+    assert_eq!(body["meta"]["total"], 2);
+
+    let body: Value = server.get("/users?role=editor").await.json();
+    assert_eq!(body["meta"]["total"], 1);
+    assert_eq!(body["data"][0]["username"], "jane");
+}
+
+/// Test GET /users sorts by a whitelisted column
+#[tokio::test]
+async fn test_list_users_with_sort() {
+    let server = common::create_test_server().await;
+    for username in ["bob", "alice", "carol"] {
+        common::create_user(&server, username, &format!("{}@example.com", username)).await;
+    }
+
+    let response = server.get("/users?sort=-username").await;
+
+    response.assert_status(StatusCode::OK);
+    let body: Value = response.json();
+    let usernames: Vec<&str> = body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|user| user["username"].as_str().unwrap())
+        .collect();
+    assert_eq!(usernames, ["carol", "bob", "alice"]);
+}
+
+/// Test GET /users rejects sorting on a column outside the whitelist
+#[tokio::test]
+async fn test_list_users_rejects_unknown_sort_field() {
+    let server = common::create_test_server().await;
+
+    let response = server.get("/users?sort=password_hash").await;
+
+    response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    let body: Value = response.json();
+    assert_eq!(body["violations"][0]["field"], "sort");
+}
+
 // ============================================================
 // GET /users/:id - Get single user
 // ============================================================
@@ -115,11 +167,21 @@ async fn test_list_users_with_pagination() {
 async fn test_get_user_not_found() {
     let server = common::create_test_server().await;
 
-    let response = server.get("/users/999").await;
+    let response = server
+        .get("/users/999")
+        .add_header("X-Request-Id", "client-request-1")
+        .await;
 
     response.assert_status(StatusCode::NOT_FOUND);
+    assert_eq!(response.header("content-type"), "application/problem+json");
+    assert_eq!(response.header("x-request-id"), "client-request-1");
     let body: Value = response.json();
-    assert_eq!(body["error"], "Resource not found");
+    assert_eq!(body["request_id"], "client-request-1");
+    assert_eq!(body["type"], "/problems/not_found");
+    assert_eq!(body["title"], "Resource not found");
+    assert_eq!(body["status"], 404);
+    assert_eq!(body["instance"], "/users/999");
+    assert_eq!(body["code"], "not_found");
 }
 
 /// Test GET /users/:id returns user when exists
@@ -132,7 +194,8 @@ async fn test_get_user_success() {
         .post("/users")
         .json(&json!({
             "username": "johndoe",
-            "email": "john@example.com"
+            "email": "john@example.com",
+            "password": "S3cure-password"
         }))
         .await;
 
@@ -164,7 +227,8 @@ async fn test_create_user_success() {
         .post("/users")
         .json(&json!({
             "username": "newuser",
-            "email": "new@example.com"
+            "email": "new@example.com",
+            "password": "S3cure-password"
         }))
         .await;
 
@@ -176,10 +240,30 @@ async fn test_create_user_success() {
     assert_eq!(body["data"]["username"], "newuser");
     assert_eq!(body["data"]["email"], "new@example.com");
     assert!(body["data"]["created_at"].as_str().is_some());
+    // Password (and its hash) is never exposed
+    assert!(body["data"].get("password").is_none());
+    assert!(body["data"].get("password_hash").is_none());
     // No meta for single item
     assert!(body["meta"].is_null());
 }
 
+/// Test POST /users returns 422 when password is too short
+#[tokio::test]
+async fn test_create_user_validation_error_password_too_short() {
+    let server = common::create_test_server().await;
+
+    let response = server
+        .post("/users")
+        .json(&json!({
+            "username": "validuser",
+            "email": "valid@example.com",
+            "password": "short"
+        }))
+        .await;
+
+    response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+}
+
 /// Test POST /users returns 422 for invalid username (too short)
 #[tokio::test]
 async fn test_create_user_validation_error_username_too_short() {
@@ -189,13 +273,14 @@ async fn test_create_user_validation_error_username_too_short() {
         .post("/users")
         .json(&json!({
             "username": "ab",
-            "email": "valid@example.com"
+            "email": "valid@example.com",
+            "password": "S3cure-password"
         }))
         .await;
 
     response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
     let body: Value = response.json();
-    assert_eq!(body["error"], "Validation failed");
+    assert_eq!(body["code"], "validation_failed");
 }
 
 /// Test POST /users returns 422 for invalid email
@@ -207,13 +292,106 @@ async fn test_create_user_validation_error_invalid_email() {
         .post("/users")
         .json(&json!({
             "username": "validuser",
-            "email": "not-an-email"
+            "email": "not-an-email",
+            "password": "S3cure-password"
         }))
         .await;
 
     response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(response.header("content-type"), "application/problem+json");
+    let body: Value = response.json();
+    assert_eq!(body["code"], "validation_failed");
+    assert_eq!(body["status"], 422);
+    assert_eq!(body["instance"], "/users");
+    assert_eq!(body["violations"][0]["field"], "email");
+}
+
+/// Test POST /users returns 422 for a password without digit/symbol/upper case
+#[tokio::test]
+async fn test_create_user_validation_error_weak_password() {
+    let server = common::create_test_server().await;
+
+    let response = server
+        .post("/users")
+        .json(&json!({
+            "username": "validuser",
+            "email": "valid@example.com",
+            "password": "onlylowercase"
+        }))
+        .await;
+
+    response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    let body: Value = response.json();
+    assert_eq!(body["violations"][0]["field"], "password");
+}
+
+/// Test validation messages follow Accept-Language, falling back to English
+#[tokio::test]
+async fn test_validation_messages_are_localized() {
+    let server = common::create_test_server().await;
+    let payload = json!({
+        "username": "ab",
+        "email": "valid@example.com",
+        "password": "S3cure-password"
+    });
+
+    let response = server
+        .post("/users")
+        .add_header("Accept-Language", "fr-CA, fr;q=0.9, en;q=0.5")
+        .json(&payload)
+        .await;
+    response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(response.header("content-language"), "fr");
+    let body: Value = response.json();
+    assert_eq!(body["title"], "Échec de la validation");
+    assert_eq!(
+        body["violations"][0]["messages"][0],
+        "Le nom d'utilisateur doit faire entre 3 et 50 caractères"
+    );
+
+    let response = server
+        .post("/users")
+        .add_header("Accept-Language", "de-DE")
+        .json(&payload)
+        .await;
+    assert_eq!(response.header("content-language"), "en");
+    let body: Value = response.json();
+    assert_eq!(body["title"], "Validation failed");
+    assert_eq!(
+        body["violations"][0]["messages"][0],
+        "Username must be between 3 and 50 characters"
+    );
+
+    let response = server.get("/users/999").add_header("Accept-Language", "fr").await;
+    let body: Value = response.json();
+    assert_eq!(body["title"], "Ressource introuvable");
+    assert_eq!(body["code"], "not_found");
+}
+
+/// Test the detail of business errors follows Accept-Language too
+#[tokio::test]
+async fn test_error_detail_is_localized() {
+    let server = common::create_test_server().await;
+    common::create_user(&server, "user1", "same@example.com").await;
+
+    let response = server
+        .post("/users")
+        .add_header("Accept-Language", "fr")
+        .json(&json!({
+            "username": "user2",
+            "email": "same@example.com",
+            "password": "S3cure-password"
+        }))
+        .await;
+    response.assert_status(StatusCode::CONFLICT);
+    let body: Value = response.json();
+    assert_eq!(body["title"], "Conflit");
+    assert_eq!(body["detail"], "Cet email est déjà utilisé");
+
+    let response = server.delete("/users/1").add_header("Accept-Language", "fr").await;
+    response.assert_status(StatusCode::UNAUTHORIZED);
     let body: Value = response.json();
-    assert_eq!(body["error"], "Validation failed");
+    assert_eq!(body["detail"], "Jeton bearer ou clé d'API manquant");
 }
 
 /// Test POST /users returns 409 for duplicate email
@@ -226,7 +404,8 @@ async fn test_create_user_duplicate_email() {
         .post("/users")
         .json(&json!({
             "username": "user1",
-            "email": "same@example.com"
+            "email": "same@example.com",
+            "password": "S3cure-password"
         }))
         .await;
 
@@ -235,14 +414,15 @@ async fn test_create_user_duplicate_email() {
         .post("/users")
         .json(&json!({
             "username": "user2",
-            "email": "same@example.com"
+            "email": "same@example.com",
+            "password": "S3cure-password"
         }))
         .await;
 
     response.assert_status(StatusCode::CONFLICT);
     let body: Value = response.json();
-    assert_eq!(body["error"], "Conflict");
-    assert_eq!(body["details"], "Email already exists");
+    assert_eq!(body["code"], "conflict");
+    assert_eq!(body["detail"], "Email already exists");
 }
 
 // ============================================================
@@ -259,16 +439,19 @@ async fn test_update_user_username() {
         .post("/users")
         .json(&json!({
             "username": "original",
-            "email": "update@example.com"
+            "email": "update@example.com",
+            "password": "S3cure-password"
         }))
         .await;
 
     let created: Value = create_response.json();
     let user_id = created["data"]["id"].as_i64().unwrap();
+    let token = common::login(&server, "update@example.com").await;
 
     // Update the user
     let response = server
         .put(&format!("/users/{}", user_id))
+        .authorization_bearer(&token)
         .json(&json!({
             "username": "updated"
         }))
@@ -285,10 +468,13 @@ async fn test_update_user_username() {
 /// Test PUT /users/:id returns 404 for non-existent user
 #[tokio::test]
 async fn test_update_user_not_found() {
-    let server = common::create_test_server().await;
+    let (server, db) = common::create_test_server_with_db().await;
+    let (_, token) =
+        common::create_user_with_role(&server, &db, Role::Admin, "admin", "admin@example.com").await;
 
     let response = server
         .put("/users/999")
+        .authorization_bearer(&token)
         .json(&json!({
             "username": "updated"
         }))
@@ -307,7 +493,8 @@ async fn test_update_user_duplicate_email() {
         .post("/users")
         .json(&json!({
             "username": "user1",
-            "email": "user1@example.com"
+            "email": "user1@example.com",
+            "password": "S3cure-password"
         }))
         .await;
 
@@ -315,16 +502,19 @@ async fn test_update_user_duplicate_email() {
         .post("/users")
         .json(&json!({
             "username": "user2",
-            "email": "user2@example.com"
+            "email": "user2@example.com",
+            "password": "S3cure-password"
         }))
         .await;
 
     let user2: Value = create_response.json();
     let user2_id = user2["data"]["id"].as_i64().unwrap();
+    let token = common::login(&server, "user2@example.com").await;
 
     // Try to update user2's email to user1's email
     let response = server
         .put(&format!("/users/{}", user2_id))
+        .authorization_bearer(&token)
         .json(&json!({
             "email": "user1@example.com"
         }))
@@ -333,21 +523,104 @@ async fn test_update_user_duplicate_email() {
     response.assert_status(StatusCode::CONFLICT);
 }
 
+/// Test PUT /users/:id returns 403 when updating someone else
+#[tokio::test]
+async fn test_update_other_user_forbidden() {
+    let server = common::create_test_server().await;
+    let victim_id = common::create_user(&server, "victim", "victim@example.com").await;
+    let (_, token) = common::create_authenticated_user(&server, "intruder", "intruder@example.com").await;
+
+    let response = server
+        .put(&format!("/users/{}", victim_id))
+        .authorization_bearer(&token)
+        .json(&json!({
+            "username": "hacked"
+        }))
+        .await;
+
+    response.assert_status(StatusCode::FORBIDDEN);
+    let body: Value = response.json();
+    assert_eq!(body["code"], "forbidden");
+}
+
+/// Test PUT /users/:id lets an admin update any user
+#[tokio::test]
+async fn test_update_other_user_as_admin() {
+    let (server, db) = common::create_test_server_with_db().await;
+    let user_id = common::create_user(&server, "member", "member@example.com").await;
+    let (_, token) =
+        common::create_user_with_role(&server, &db, Role::Admin, "admin", "admin@example.com").await;
+
+    let response = server
+        .put(&format!("/users/{}", user_id))
+        .authorization_bearer(&token)
+        .json(&json!({
+            "username": "renamed"
+        }))
+        .await;
+
+    response.assert_status(StatusCode::OK);
+    let body: Value = response.json();
+    assert_eq!(body["data"]["username"], "renamed");
+}
+
+// ============================================================
+// PUT /users/:id/role - Change role
+// ============================================================
+
+/// Test PUT /users/:id/role lets an admin promote a user
+#[tokio::test]
+async fn test_update_user_role_as_admin() {
+    let (server, db) = common::create_test_server_with_db().await;
+    let user_id = common::create_user(&server, "member", "member@example.com").await;
+    let (_, token) =
+        common::create_user_with_role(&server, &db, Role::Admin, "admin", "admin@example.com").await;
+
+    let response = server
+        .put(&format!("/users/{}/role", user_id))
+        .authorization_bearer(&token)
+        .json(&json!({ "role": "editor" }))
+        .await;
+
+    response.assert_status(StatusCode::OK);
+    let body: Value = response.json();
+    assert_eq!(body["data"]["role"], "editor");
+}
+
+/// Test PUT /users/:id/role returns 403 for non-admins (no self-promotion)
+#[tokio::test]
+async fn test_update_user_role_forbidden_for_author() {
+    let server = common::create_test_server().await;
+    let (user_id, token) =
+        common::create_authenticated_user(&server, "member", "member@example.com").await;
+
+    let response = server
+        .put(&format!("/users/{}/role", user_id))
+        .authorization_bearer(&token)
+        .json(&json!({ "role": "admin" }))
+        .await;
+
+    response.assert_status(StatusCode::FORBIDDEN);
+}
+
 // ============================================================
 // DELETE /users/:id - Delete user
 // ============================================================
 
-/// Test DELETE /users/:id deletes user successfully
+/// Test DELETE /users/:id deletes user successfully (admin)
 #[tokio::test]
 async fn test_delete_user_success() {
-    let server = common::create_test_server().await;
+    let (server, db) = common::create_test_server_with_db().await;
+    let (_, token) =
+        common::create_user_with_role(&server, &db, Role::Admin, "admin", "admin@example.com").await;
 
     // Create a user
     let create_response = server
         .post("/users")
         .json(&json!({
             "username": "todelete",
-            "email": "delete@example.com"
+            "email": "delete@example.com",
+            "password": "S3cure-password"
         }))
         .await;
 
@@ -355,7 +628,10 @@ async fn test_delete_user_success() {
     let user_id = created["data"]["id"].as_i64().unwrap();
 
     // Delete the user
-    let response = server.delete(&format!("/users/{}", user_id)).await;
+    let response = server
+        .delete(&format!("/users/{}", user_id))
+        .authorization_bearer(&token)
+        .await;
     response.assert_status(StatusCode::NO_CONTENT);
 
     // Verify user is gone
@@ -366,9 +642,222 @@ async fn test_delete_user_success() {
 /// Test DELETE /users/:id returns 404 for non-existent user
 #[tokio::test]
 async fn test_delete_user_not_found() {
-    let server = common::create_test_server().await;
+    let (server, db) = common::create_test_server_with_db().await;
+    let (_, token) =
+        common::create_user_with_role(&server, &db, Role::Admin, "admin", "admin@example.com").await;
 
-    let response = server.delete("/users/999").await;
+    let response = server
+        .delete("/users/999")
+        .authorization_bearer(&token)
+        .await;
 
     response.assert_status(StatusCode::NOT_FOUND);
 }
+
+/// Test DELETE /users/:id returns 401 without a bearer token
+#[tokio::test]
+async fn test_delete_user_requires_authentication() {
+    let server = common::create_test_server().await;
+    let user_id = common::create_user(&server, "victim", "victim@example.com").await;
+
+    let response = server.delete(&format!("/users/{}", user_id)).await;
+
+    response.assert_status(StatusCode::UNAUTHORIZED);
+
+    // User is still there
+    server
+        .get(&format!("/users/{}", user_id))
+        .await
+        .assert_status(StatusCode::OK);
+}
+
+/// Test DELETE /users/:id returns 403 for non-admins, even on themselves
+#[tokio::test]
+async fn test_delete_user_forbidden_for_author() {
+    let server = common::create_test_server().await;
+    let (user_id, token) =
+        common::create_authenticated_user(&server, "member", "member@example.com").await;
+
+    let response = server
+        .delete(&format!("/users/{}", user_id))
+        .authorization_bearer(&token)
+        .await;
+
+    response.assert_status(StatusCode::FORBIDDEN);
+}
+
+// ============================================================
+// ETag / If-Match - Optimistic concurrency
+// ============================================================
+
+/// Test GET /users/:id returns an ETag and honors If-None-Match (304)
+#[tokio::test]
+async fn test_get_user_etag_and_not_modified() {
+    let server = common::create_test_server().await;
+    let (user_id, token) =
+        common::create_authenticated_user(&server, "member", "member@example.com").await;
+
+    let response = server.get(&format!("/users/{}", user_id)).await;
+    response.assert_status(StatusCode::OK);
+    let etag = response.header("etag").to_str().unwrap().to_string();
+    assert_eq!(etag, "\"1\"");
+
+    let response = server
+        .get(&format!("/users/{}", user_id))
+        .add_header("If-None-Match", &etag)
+        .await;
+    response.assert_status(StatusCode::NOT_MODIFIED);
+    assert_eq!(response.header("etag"), etag.as_str());
+
+    // Une modification change l'ETag: le cache n'est plus valide
+    server
+        .put(&format!("/users/{}", user_id))
+        .authorization_bearer(&token)
+        .json(&json!({ "username": "renamed" }))
+        .await
+        .assert_status(StatusCode::OK);
+    let response = server
+        .get(&format!("/users/{}", user_id))
+        .add_header("If-None-Match", &etag)
+        .await;
+    response.assert_status(StatusCode::OK);
+    assert_eq!(response.header("etag"), "\"2\"");
+}
+
+/// Test PUT /users/:id/role and DELETE /users/:id reject a stale If-Match with 412
+#[tokio::test]
+async fn test_user_if_match_rejects_stale_version() {
+    let (server, db) = common::create_test_server_with_db().await;
+    let user_id = common::create_user(&server, "member", "member@example.com").await;
+    let (_, token) =
+        common::create_user_with_role(&server, &db, Role::Admin, "admin", "admin@example.com").await;
+
+    let response = server
+        .put(&format!("/users/{}/role", user_id))
+        .authorization_bearer(&token)
+        .add_header("If-Match", "\"1\"")
+        .json(&json!({ "role": "editor" }))
+        .await;
+    response.assert_status(StatusCode::OK);
+    assert_eq!(response.header("etag"), "\"2\"");
+
+    let response = server
+        .put(&format!("/users/{}/role", user_id))
+        .authorization_bearer(&token)
+        .add_header("If-Match", "\"1\"")
+        .json(&json!({ "role": "admin" }))
+        .await;
+    response.assert_status(StatusCode::PRECONDITION_FAILED);
+
+    server
+        .delete(&format!("/users/{}", user_id))
+        .authorization_bearer(&token)
+        .add_header("If-Match", "\"1\"")
+        .await
+        .assert_status(StatusCode::PRECONDITION_FAILED);
+    server
+        .delete(&format!("/users/{}", user_id))
+        .authorization_bearer(&token)
+        .add_header("If-Match", "\"2\"")
+        .await
+        .assert_status(StatusCode::NO_CONTENT);
+}
+
+// ============================================================
+// PATCH - JSON Merge Patch / JSON Patch
+// ============================================================
+
+/// Test PATCH /users/:id with a merge patch only changes the given fields
+#[tokio::test]
+async fn test_merge_patch_user_email() {
+    let server = common::create_test_server().await;
+    let (user_id, token) =
+        common::create_authenticated_user(&server, "member", "member@example.com").await;
+
+    let response = server
+        .patch(&format!("/users/{}", user_id))
+        .authorization_bearer(&token)
+        .json(&json!({ "email": "member.new@example.com" }))
+        .content_type("application/merge-patch+json")
+        .await;
+
+    response.assert_status(StatusCode::OK);
+    assert_eq!(response.header("etag"), "\"2\"");
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["data"]["username"], "member");
+    assert_eq!(body["data"]["email"], "member.new@example.com");
+}
+
+/// Test two PATCH /users/:id sent with the same ETag: the second one gets 412
+#[tokio::test]
+async fn test_second_patch_user_with_same_etag_is_rejected() {
+    let server = common::create_test_server().await;
+    let (user_id, token) =
+        common::create_authenticated_user(&server, "member", "member@example.com").await;
+    let path = format!("/users/{}", user_id);
+    let etag = server
+        .get(&path)
+        .authorization_bearer(&token)
+        .await
+        .header("etag")
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    server
+        .patch(&path)
+        .authorization_bearer(&token)
+        .add_header("If-Match", &etag)
+        .json(&json!({ "email": "first@example.com" }))
+        .content_type("application/merge-patch+json")
+        .await
+        .assert_status(StatusCode::OK);
+
+    server
+        .patch(&path)
+        .authorization_bearer(&token)
+        .add_header("If-Match", &etag)
+        .json(&json!({ "email": "second@example.com" }))
+        .content_type("application/merge-patch+json")
+        .await
+        .assert_status(StatusCode::PRECONDITION_FAILED);
+
+    let body: serde_json::Value = server.get(&path).authorization_bearer(&token).await.json();
+    assert_eq!(body["data"]["email"], "first@example.com");
+}
+
+/// Test PATCH /users/:id with JSON Patch operations and post-patch validation
+#[tokio::test]
+async fn test_json_patch_user_validated() {
+    let server = common::create_test_server().await;
+    let (user_id, token) =
+        common::create_authenticated_user(&server, "member", "member@example.com").await;
+
+    let response = server
+        .patch(&format!("/users/{}", user_id))
+        .authorization_bearer(&token)
+        .json(&json!([{ "op": "replace", "path": "/username", "value": "renamed" }]))
+        .content_type("application/json-patch+json")
+        .await;
+    response.assert_status(StatusCode::OK);
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["data"]["username"], "renamed");
+
+    let response = server
+        .patch(&format!("/users/{}", user_id))
+        .authorization_bearer(&token)
+        .json(&json!([{ "op": "replace", "path": "/email", "value": "not-an-email" }]))
+        .content_type("application/json-patch+json")
+        .await;
+    response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["violations"][0]["field"], "email");
+
+    server
+        .patch(&format!("/users/{}", user_id))
+        .authorization_bearer(&token)
+        .json(&json!([{ "op": "remove", "path": "/email" }]))
+        .content_type("application/json-patch+json")
+        .await
+        .assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+}